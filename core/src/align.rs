@@ -12,7 +12,7 @@ pub enum Align {
 }
 
 /// The horizontal alignment of some resource.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum HorizontalAlignment {
     /// Align left
     Left,
@@ -25,7 +25,7 @@ pub enum HorizontalAlignment {
 }
 
 /// The vertical alignment of some resource.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum VerticalAlignment {
     /// Align top
     Top,
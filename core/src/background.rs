@@ -1,3 +1,4 @@
+use crate::gradient::{self, Gradient};
 use crate::Color;
 
 /// The background of some element.
@@ -5,7 +6,9 @@ use crate::Color;
 pub enum Background {
     /// A solid color
     Color(Color),
-    // TODO: Add gradient and image variants
+    /// A gradient of colors
+    Gradient(Gradient),
+    // TODO: Add image variant
 }
 
 impl From<Color> for Background {
@@ -13,3 +16,15 @@ impl From<Color> for Background {
         Background::Color(color)
     }
 }
+
+impl From<Gradient> for Background {
+    fn from(gradient: Gradient) -> Self {
+        Background::Gradient(gradient)
+    }
+}
+
+impl From<gradient::Linear> for Background {
+    fn from(linear: gradient::Linear) -> Self {
+        Background::Gradient(Gradient::Linear(linear))
+    }
+}
@@ -0,0 +1,62 @@
+/// The radius of each corner of a rectangle, in units.
+///
+/// It can be created from a single value, rounding every corner equally.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BorderRadius {
+    /// The radius of the top left corner.
+    pub top_left: u16,
+    /// The radius of the top right corner.
+    pub top_right: u16,
+    /// The radius of the bottom right corner.
+    pub bottom_right: u16,
+    /// The radius of the bottom left corner.
+    pub bottom_left: u16,
+}
+
+impl BorderRadius {
+    /// Creates a new [`BorderRadius`] with the same radius for every corner.
+    ///
+    /// [`BorderRadius`]: struct.BorderRadius.html
+    pub const fn all(radius: u16) -> Self {
+        BorderRadius {
+            top_left: radius,
+            top_right: radius,
+            bottom_right: radius,
+            bottom_left: radius,
+        }
+    }
+}
+
+impl From<u16> for BorderRadius {
+    fn from(radius: u16) -> Self {
+        BorderRadius::all(radius)
+    }
+}
+
+impl From<[u16; 4]> for BorderRadius {
+    /// Creates a [`BorderRadius`] from the radii of its corners in clockwise
+    /// order, starting from the top left corner.
+    ///
+    /// [`BorderRadius`]: struct.BorderRadius.html
+    fn from(
+        [top_left, top_right, bottom_right, bottom_left]: [u16; 4],
+    ) -> Self {
+        BorderRadius {
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        }
+    }
+}
+
+impl From<BorderRadius> for [f32; 4] {
+    fn from(radius: BorderRadius) -> Self {
+        [
+            f32::from(radius.top_left),
+            f32::from(radius.top_right),
+            f32::from(radius.bottom_right),
+            f32::from(radius.bottom_left),
+        ]
+    }
+}
@@ -0,0 +1,75 @@
+/// The width of each side of the border of a rectangle, in units.
+///
+/// It can be created from a single value, giving every side the same width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BorderWidth {
+    /// The width of the top side.
+    pub top: u16,
+    /// The width of the right side.
+    pub right: u16,
+    /// The width of the bottom side.
+    pub bottom: u16,
+    /// The width of the left side.
+    pub left: u16,
+}
+
+impl BorderWidth {
+    /// Creates a new [`BorderWidth`] with the same width for every side.
+    ///
+    /// [`BorderWidth`]: struct.BorderWidth.html
+    pub const fn all(width: u16) -> Self {
+        BorderWidth {
+            top: width,
+            right: width,
+            bottom: width,
+            left: width,
+        }
+    }
+
+    /// Returns true if no side of the [`BorderWidth`] is visible.
+    ///
+    /// [`BorderWidth`]: struct.BorderWidth.html
+    pub fn is_zero(&self) -> bool {
+        self.to_array().iter().all(|width| *width == 0)
+    }
+
+    /// Returns the widths of the [`BorderWidth`] in clockwise order,
+    /// starting from the top side.
+    ///
+    /// [`BorderWidth`]: struct.BorderWidth.html
+    pub fn to_array(&self) -> [u16; 4] {
+        [self.top, self.right, self.bottom, self.left]
+    }
+}
+
+impl From<u16> for BorderWidth {
+    fn from(width: u16) -> Self {
+        BorderWidth::all(width)
+    }
+}
+
+impl From<[u16; 4]> for BorderWidth {
+    /// Creates a [`BorderWidth`] from the widths of its sides in clockwise
+    /// order, starting from the top side.
+    ///
+    /// [`BorderWidth`]: struct.BorderWidth.html
+    fn from([top, right, bottom, left]: [u16; 4]) -> Self {
+        BorderWidth {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+}
+
+impl From<BorderWidth> for [f32; 4] {
+    fn from(width: BorderWidth) -> Self {
+        [
+            f32::from(width.top),
+            f32::from(width.right),
+            f32::from(width.bottom),
+            f32::from(width.left),
+        ]
+    }
+}
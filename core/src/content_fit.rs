@@ -0,0 +1,132 @@
+use crate::Size;
+
+/// The strategy used to fit the contents of a widget, like an image, into
+/// its bounds.
+///
+/// Modeled after the [`object-fit`] CSS property.
+///
+/// [`object-fit`]: https://developer.mozilla.org/en-US/docs/Web/CSS/object-fit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContentFit {
+    /// Scale the contents as big as possible while fitting entirely inside
+    /// the bounds, keeping their aspect ratio.
+    ///
+    /// This is the default.
+    Contain,
+
+    /// Scale the contents to cover the bounds entirely, keeping their aspect
+    /// ratio and cropping whatever overflows.
+    Cover,
+
+    /// Stretch the contents to fill the bounds, ignoring their aspect ratio.
+    Fill,
+
+    /// Do not scale the contents, cropping whatever overflows.
+    None,
+
+    /// Behave like [`None`] if the contents fit in the bounds, and like
+    /// [`Contain`] otherwise.
+    ///
+    /// [`None`]: #variant.None
+    /// [`Contain`]: #variant.Contain
+    ScaleDown,
+}
+
+impl ContentFit {
+    /// Returns the size of some contents of the given size once fitted into
+    /// the given bounds.
+    pub fn fit(&self, content: Size, bounds: Size) -> Size {
+        let content_ratio = content.width / content.height;
+        let bounds_ratio = bounds.width / bounds.height;
+
+        match self {
+            ContentFit::Contain => {
+                if bounds_ratio > content_ratio {
+                    Size::new(bounds.height * content_ratio, bounds.height)
+                } else {
+                    Size::new(bounds.width, bounds.width / content_ratio)
+                }
+            }
+            ContentFit::Cover => {
+                if bounds_ratio < content_ratio {
+                    Size::new(bounds.height * content_ratio, bounds.height)
+                } else {
+                    Size::new(bounds.width, bounds.width / content_ratio)
+                }
+            }
+            ContentFit::Fill => bounds,
+            ContentFit::None => content,
+            ContentFit::ScaleDown => {
+                if content.width <= bounds.width
+                    && content.height <= bounds.height
+                {
+                    content
+                } else {
+                    ContentFit::Contain.fit(content, bounds)
+                }
+            }
+        }
+    }
+}
+
+impl Default for ContentFit {
+    fn default() -> Self {
+        ContentFit::Contain
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WIDE: Size = Size {
+        width: 200.0,
+        height: 100.0,
+    };
+
+    const SQUARE: Size = Size {
+        width: 100.0,
+        height: 100.0,
+    };
+
+    #[test]
+    fn contain() {
+        assert_eq!(
+            ContentFit::Contain.fit(WIDE, SQUARE),
+            Size::new(100.0, 50.0)
+        );
+        assert_eq!(
+            ContentFit::Contain.fit(SQUARE, WIDE),
+            Size::new(100.0, 100.0)
+        );
+    }
+
+    #[test]
+    fn cover() {
+        assert_eq!(
+            ContentFit::Cover.fit(WIDE, SQUARE),
+            Size::new(200.0, 100.0)
+        );
+        assert_eq!(
+            ContentFit::Cover.fit(SQUARE, WIDE),
+            Size::new(200.0, 200.0)
+        );
+    }
+
+    #[test]
+    fn fill_and_none() {
+        assert_eq!(ContentFit::Fill.fit(WIDE, SQUARE), SQUARE);
+        assert_eq!(ContentFit::None.fit(WIDE, SQUARE), WIDE);
+    }
+
+    #[test]
+    fn scale_down() {
+        let small = Size::new(50.0, 20.0);
+
+        assert_eq!(ContentFit::ScaleDown.fit(small, SQUARE), small);
+        assert_eq!(
+            ContentFit::ScaleDown.fit(WIDE, SQUARE),
+            Size::new(100.0, 50.0)
+        );
+    }
+}
@@ -1,5 +1,8 @@
+//! Describe fonts by their family and attributes.
+use std::borrow::Cow;
+
 /// A font.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub enum Font {
     /// The default font.
     ///
@@ -15,6 +18,14 @@ pub enum Font {
         /// The bytes of the external font
         bytes: &'static [u8],
     },
+
+    /// A font matching a [`Descriptor`].
+    ///
+    /// It is looked up in the fonts known by a renderer first, including
+    /// any external font, and in the fonts of the system afterwards.
+    ///
+    /// [`Descriptor`]: struct.Descriptor.html
+    Descriptor(Descriptor),
 }
 
 impl Default for Font {
@@ -22,3 +33,233 @@ impl Default for Font {
         Font::Default
     }
 }
+
+impl From<Descriptor> for Font {
+    fn from(descriptor: Descriptor) -> Font {
+        Font::Descriptor(descriptor)
+    }
+}
+
+/// The description of a font, made of a family and some attributes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Descriptor {
+    /// The family of the font
+    pub family: Family,
+
+    /// The weight of the font
+    pub weight: Weight,
+
+    /// The style of the font
+    pub style: Style,
+
+    /// The stretch of the font
+    pub stretch: Stretch,
+}
+
+impl Descriptor {
+    /// Creates a new [`Descriptor`] of a font of the given [`Family`], with
+    /// normal attributes.
+    ///
+    /// [`Descriptor`]: struct.Descriptor.html
+    /// [`Family`]: enum.Family.html
+    pub const fn new(family: Family) -> Self {
+        Descriptor {
+            family,
+            weight: Weight::Normal,
+            style: Style::Normal,
+            stretch: Stretch::Normal,
+        }
+    }
+
+    /// Sets the [`Weight`] of the [`Descriptor`].
+    ///
+    /// [`Weight`]: enum.Weight.html
+    /// [`Descriptor`]: struct.Descriptor.html
+    pub fn weight(mut self, weight: Weight) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// Sets the [`Style`] of the [`Descriptor`].
+    ///
+    /// [`Style`]: enum.Style.html
+    /// [`Descriptor`]: struct.Descriptor.html
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the [`Stretch`] of the [`Descriptor`].
+    ///
+    /// [`Stretch`]: enum.Stretch.html
+    /// [`Descriptor`]: struct.Descriptor.html
+    pub fn stretch(mut self, stretch: Stretch) -> Self {
+        self.stretch = stretch;
+        self
+    }
+}
+
+/// The family of a font.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Family {
+    /// The name of a specific family, like `"Fira Sans"`.
+    ///
+    /// The name can be known at compile time or only at runtime, like the
+    /// family of a font loaded from a file.
+    Name(Cow<'static, str>),
+
+    /// A family with serifs.
+    Serif,
+
+    /// A family without serifs.
+    SansSerif,
+
+    /// A family where every glyph has the same width.
+    Monospace,
+
+    /// A family resembling handwriting.
+    Cursive,
+
+    /// A decorative family.
+    Fantasy,
+}
+
+impl Family {
+    /// Creates a [`Family`] with the given name.
+    ///
+    /// [`Family`]: enum.Family.html
+    pub fn name(name: impl Into<Cow<'static, str>>) -> Family {
+        Family::Name(name.into())
+    }
+}
+
+/// The weight of a font, from the thinnest to the thickest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[allow(missing_docs)]
+pub enum Weight {
+    Thin,
+    ExtraLight,
+    Light,
+    Normal,
+    Medium,
+    Semibold,
+    Bold,
+    ExtraBold,
+    Black,
+}
+
+impl Weight {
+    /// Returns the numeric value of the [`Weight`], between 100 and 900.
+    ///
+    /// [`Weight`]: enum.Weight.html
+    pub fn to_number(self) -> u16 {
+        match self {
+            Weight::Thin => 100,
+            Weight::ExtraLight => 200,
+            Weight::Light => 300,
+            Weight::Normal => 400,
+            Weight::Medium => 500,
+            Weight::Semibold => 600,
+            Weight::Bold => 700,
+            Weight::ExtraBold => 800,
+            Weight::Black => 900,
+        }
+    }
+
+    /// Returns the [`Weight`] closest to the given numeric value.
+    ///
+    /// [`Weight`]: enum.Weight.html
+    pub fn from_number(number: u16) -> Weight {
+        match number.saturating_add(50) / 100 {
+            0 | 1 => Weight::Thin,
+            2 => Weight::ExtraLight,
+            3 => Weight::Light,
+            4 => Weight::Normal,
+            5 => Weight::Medium,
+            6 => Weight::Semibold,
+            7 => Weight::Bold,
+            8 => Weight::ExtraBold,
+            _ => Weight::Black,
+        }
+    }
+}
+
+impl Default for Weight {
+    fn default() -> Weight {
+        Weight::Normal
+    }
+}
+
+/// The style of a font.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Style {
+    /// An upright font.
+    Normal,
+
+    /// A cursive font, designed to be slanted.
+    Italic,
+
+    /// An upright font slanted artificially.
+    Oblique,
+}
+
+impl Default for Style {
+    fn default() -> Style {
+        Style::Normal
+    }
+}
+
+/// The stretch of a font, from the narrowest to the widest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[allow(missing_docs)]
+pub enum Stretch {
+    UltraCondensed,
+    ExtraCondensed,
+    Condensed,
+    SemiCondensed,
+    Normal,
+    SemiExpanded,
+    Expanded,
+    ExtraExpanded,
+    UltraExpanded,
+}
+
+impl Stretch {
+    /// Returns the width of the [`Stretch`] as a percentage of the normal
+    /// width, between 50 and 200.
+    ///
+    /// [`Stretch`]: enum.Stretch.html
+    pub fn to_percentage(self) -> f32 {
+        match self {
+            Stretch::UltraCondensed => 50.0,
+            Stretch::ExtraCondensed => 62.5,
+            Stretch::Condensed => 75.0,
+            Stretch::SemiCondensed => 87.5,
+            Stretch::Normal => 100.0,
+            Stretch::SemiExpanded => 112.5,
+            Stretch::Expanded => 125.0,
+            Stretch::ExtraExpanded => 150.0,
+            Stretch::UltraExpanded => 200.0,
+        }
+    }
+}
+
+impl Default for Stretch {
+    fn default() -> Stretch {
+        Stretch::Normal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weight_from_number() {
+        assert_eq!(Weight::from_number(0), Weight::Thin);
+        assert_eq!(Weight::from_number(400), Weight::Normal);
+        assert_eq!(Weight::from_number(649), Weight::Semibold);
+        assert_eq!(Weight::from_number(650), Weight::Bold);
+        assert_eq!(Weight::from_number(1000), Weight::Black);
+    }
+}
@@ -0,0 +1,187 @@
+//! Fill shapes with progressive transitions between colors.
+use crate::Color;
+
+/// The maximum number of color stops a [`Gradient`] can have.
+///
+/// [`Gradient`]: enum.Gradient.html
+pub const MAX_STOPS: usize = 4;
+
+/// A fill which transitions progressively between multiple colors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gradient {
+    /// A linear gradient, interpolating colors along a direction.
+    Linear(Linear),
+}
+
+impl Gradient {
+    /// Returns the color stops of the [`Gradient`], sorted by offset.
+    ///
+    /// [`Gradient`]: enum.Gradient.html
+    pub fn stops(&self) -> impl Iterator<Item = ColorStop> {
+        match self {
+            Gradient::Linear(linear) => linear.stops(),
+        }
+    }
+
+    /// Applies the given function to every color of the [`Gradient`].
+    ///
+    /// [`Gradient`]: enum.Gradient.html
+    pub fn map_colors(self, f: impl Fn(Color) -> Color) -> Self {
+        match self {
+            Gradient::Linear(mut linear) => {
+                for stop in linear.stops.iter_mut().flatten() {
+                    stop.color = f(stop.color);
+                }
+
+                Gradient::Linear(linear)
+            }
+        }
+    }
+}
+
+impl From<Linear> for Gradient {
+    fn from(linear: Linear) -> Self {
+        Gradient::Linear(linear)
+    }
+}
+
+/// A linear [`Gradient`].
+///
+/// The colors are interpolated along a line that goes through the center of
+/// the filled bounds, following the given angle. The line is long enough for
+/// the first and last stops to touch the corners of the bounds.
+///
+/// [`Gradient`]: enum.Gradient.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Linear {
+    /// The angle of the direction of the [`Linear`] gradient, in radians.
+    ///
+    /// An angle of `0.0` goes from left to right, and it increases
+    /// clockwise. For instance, `std::f32::consts::FRAC_PI_2` goes from top
+    /// to bottom.
+    ///
+    /// [`Linear`]: struct.Linear.html
+    pub angle: f32,
+
+    /// The color stops of the [`Linear`] gradient.
+    ///
+    /// [`Linear`]: struct.Linear.html
+    pub stops: [Option<ColorStop>; MAX_STOPS],
+}
+
+impl Linear {
+    /// Creates a new [`Linear`] gradient with the given angle, in radians,
+    /// and no color stops.
+    ///
+    /// [`Linear`]: struct.Linear.html
+    pub fn new(angle: f32) -> Self {
+        Self {
+            angle,
+            stops: [None; MAX_STOPS],
+        }
+    }
+
+    /// Adds a new [`ColorStop`] to the [`Linear`] gradient.
+    ///
+    /// The offset must be on [0, 1]. Any stops past [`MAX_STOPS`] are
+    /// ignored.
+    ///
+    /// [`ColorStop`]: struct.ColorStop.html
+    /// [`Linear`]: struct.Linear.html
+    /// [`MAX_STOPS`]: constant.MAX_STOPS.html
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        debug_assert!(
+            (0.0..=1.0).contains(&offset),
+            "Stop offset must be on [0, 1]"
+        );
+
+        if let Some(slot) = self.stops.iter_mut().find(|stop| stop.is_none()) {
+            *slot = Some(ColorStop { offset, color });
+        }
+
+        self
+    }
+
+    /// Returns the color stops of the [`Linear`] gradient, sorted by offset.
+    ///
+    /// [`Linear`]: struct.Linear.html
+    pub fn stops(&self) -> impl Iterator<Item = ColorStop> {
+        let mut stops = self.stops;
+
+        stops.sort_by(|a, b| match (a, b) {
+            (Some(a), Some(b)) => a
+                .offset
+                .partial_cmp(&b.offset)
+                .unwrap_or(std::cmp::Ordering::Equal),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        });
+
+        (0..MAX_STOPS).filter_map(move |i| stops[i])
+    }
+}
+
+/// A point along a [`Gradient`] with a specific color.
+///
+/// [`Gradient`]: enum.Gradient.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorStop {
+    /// The offset of the [`ColorStop`] along the [`Gradient`], on [0, 1].
+    ///
+    /// [`ColorStop`]: struct.ColorStop.html
+    /// [`Gradient`]: enum.Gradient.html
+    pub offset: f32,
+
+    /// The color of the [`ColorStop`].
+    ///
+    /// [`ColorStop`]: struct.ColorStop.html
+    pub color: Color,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stops_are_sorted() {
+        let linear = Linear::new(0.0)
+            .add_stop(1.0, Color::BLACK)
+            .add_stop(0.0, Color::WHITE)
+            .add_stop(0.5, Color::from_rgb(1.0, 0.0, 0.0));
+
+        let offsets: Vec<f32> =
+            linear.stops().map(|stop| stop.offset).collect();
+
+        assert_eq!(offsets, vec![0.0, 0.5, 1.0]);
+        assert_eq!(
+            linear.stops().next().map(|stop| stop.color),
+            Some(Color::WHITE)
+        );
+    }
+
+    #[test]
+    fn extra_stops_are_ignored() {
+        let linear = (0..MAX_STOPS + 2).fold(Linear::new(0.0), |linear, i| {
+            linear.add_stop(1.0 - i as f32 * 0.1, Color::BLACK)
+        });
+
+        assert_eq!(linear.stops().count(), MAX_STOPS);
+        assert_eq!(linear.stops().next().map(|stop| stop.offset), Some(0.7));
+    }
+
+    #[test]
+    fn map_colors() {
+        let gradient = Gradient::from(
+            Linear::new(0.0)
+                .add_stop(0.0, Color::WHITE)
+                .add_stop(1.0, Color::BLACK),
+        );
+
+        let transparent =
+            gradient.map_colors(|color| Color { a: 0.0, ..color });
+
+        assert!(transparent.stops().all(|stop| stop.color.a == 0.0));
+        assert_eq!(transparent.stops().count(), 2);
+    }
+}
@@ -28,4 +28,10 @@ pub enum Event {
 
     /// A unicode character was received.
     CharacterReceived(char),
+
+    /// The state of the modifier keys changed.
+    ///
+    /// Shells also produce this event with no modifiers pressed when the
+    /// window loses focus, since the keys may be released elsewhere.
+    ModifiersChanged(ModifiersState),
 }
@@ -14,25 +14,41 @@
 #![deny(unused_results)]
 #![forbid(unsafe_code)]
 #![forbid(rust_2018_idioms)]
+pub mod font;
+pub mod gradient;
 pub mod keyboard;
 pub mod mouse;
 
 mod align;
 mod background;
+mod border_radius;
+mod border_width;
 mod color;
-mod font;
+mod content_fit;
 mod length;
+mod line_height;
+mod overflow;
 mod point;
 mod rectangle;
+mod shadow;
 mod size;
 mod vector;
+mod wrapping;
 
 pub use align::{Align, HorizontalAlignment, VerticalAlignment};
 pub use background::Background;
+pub use border_radius::BorderRadius;
+pub use border_width::BorderWidth;
 pub use color::Color;
+pub use content_fit::ContentFit;
 pub use font::Font;
+pub use gradient::Gradient;
 pub use length::Length;
+pub use line_height::LineHeight;
+pub use overflow::Overflow;
 pub use point::Point;
 pub use rectangle::Rectangle;
+pub use shadow::Shadow;
 pub use size::Size;
 pub use vector::Vector;
+pub use wrapping::Wrapping;
@@ -0,0 +1,41 @@
+/// The distance between the baselines of consecutive lines of text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineHeight {
+    /// The line height defined by the metrics of the font.
+    ///
+    /// This is the default.
+    Natural,
+
+    /// A line height relative to the size of the text.
+    ///
+    /// For instance, `Relative(1.5)` spaces the lines of a text of size `20`
+    /// by `30` units.
+    Relative(f32),
+
+    /// A line height in logical pixels, regardless of the size of the text.
+    Absolute(f32),
+}
+
+impl LineHeight {
+    /// Returns the line height in logical pixels for text of the given size,
+    /// or `None` if it is defined by the metrics of the font.
+    pub fn to_absolute(self, size: f32) -> Option<f32> {
+        match self {
+            LineHeight::Natural => None,
+            LineHeight::Relative(factor) => Some(factor * size),
+            LineHeight::Absolute(height) => Some(height),
+        }
+    }
+}
+
+impl Default for LineHeight {
+    fn default() -> Self {
+        LineHeight::Natural
+    }
+}
+
+impl From<f32> for LineHeight {
+    fn from(factor: f32) -> Self {
+        LineHeight::Relative(factor)
+    }
+}
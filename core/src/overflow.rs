@@ -0,0 +1,19 @@
+/// The strategy used to display the part of a paragraph of text that does
+/// not fit in its bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Overflow {
+    /// Hide the lines and glyphs past the bounds.
+    ///
+    /// This is the default.
+    Clip,
+
+    /// Hide the lines and glyphs past the bounds, ending the last visible
+    /// line with an ellipsis (…) if anything was hidden.
+    Ellipsis,
+}
+
+impl Default for Overflow {
+    fn default() -> Self {
+        Overflow::Clip
+    }
+}
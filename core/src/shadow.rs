@@ -0,0 +1,46 @@
+use crate::{Color, Vector};
+
+/// A blurred shadow cast by a rectangle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Shadow {
+    /// The color of the [`Shadow`].
+    ///
+    /// [`Shadow`]: struct.Shadow.html
+    pub color: Color,
+
+    /// The offset of the [`Shadow`] from the rectangle casting it.
+    ///
+    /// [`Shadow`]: struct.Shadow.html
+    pub offset: Vector,
+
+    /// The blur radius of the [`Shadow`].
+    ///
+    /// A blur radius of `0.0` produces a shadow with sharp edges.
+    ///
+    /// [`Shadow`]: struct.Shadow.html
+    pub blur_radius: f32,
+}
+
+impl Shadow {
+    /// A [`Shadow`] that is not visible.
+    ///
+    /// [`Shadow`]: struct.Shadow.html
+    pub const NONE: Shadow = Shadow {
+        color: Color::TRANSPARENT,
+        offset: Vector::new(0.0, 0.0),
+        blur_radius: 0.0,
+    };
+
+    /// Returns true if the [`Shadow`] can be seen.
+    ///
+    /// [`Shadow`]: struct.Shadow.html
+    pub fn is_visible(&self) -> bool {
+        self.color.a > 0.0
+    }
+}
+
+impl Default for Shadow {
+    fn default() -> Self {
+        Shadow::NONE
+    }
+}
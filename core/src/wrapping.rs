@@ -0,0 +1,21 @@
+/// The strategy used to break the lines of a paragraph of text that does not
+/// fit in its bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Wrapping {
+    /// Break lines between words.
+    ///
+    /// This is the default.
+    Word,
+
+    /// Break lines between any glyphs.
+    Glyph,
+
+    /// Only break lines at explicit line breaks.
+    None,
+}
+
+impl Default for Wrapping {
+    fn default() -> Self {
+        Wrapping::Word
+    }
+}
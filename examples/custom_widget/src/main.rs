@@ -12,7 +12,7 @@ mod circle {
     use iced_graphics::{Backend, Defaults, Primitive, Renderer};
     use iced_native::{
         layout, mouse, Background, Color, Element, Hasher, Layout, Length,
-        Point, Size, Widget,
+        Point, Shadow, Size, Widget,
     };
 
     pub struct Circle {
@@ -65,9 +65,10 @@ mod circle {
                 Primitive::Quad {
                     bounds: layout.bounds(),
                     background: Background::Color(Color::BLACK),
-                    border_radius: self.radius,
-                    border_width: 0,
+                    border_radius: self.radius.into(),
+                    border_width: 0.into(),
                     border_color: Color::TRANSPARENT,
+                    shadow: Shadow::NONE,
                 },
                 mouse::Interaction::default(),
             )
@@ -225,7 +225,7 @@ impl Content {
 }
 
 mod style {
-    use iced::{button, container, Background, Color, Vector};
+    use iced::{button, container, Background, Color, Theme, Vector};
 
     const SURFACE: Color = Color::from_rgb(
         0xF2 as f32 / 255.0,
@@ -250,10 +250,10 @@ mod style {
     }
 
     impl container::StyleSheet for Pane {
-        fn style(&self) -> container::Style {
+        fn style(&self, _theme: &Theme) -> container::Style {
             container::Style {
                 background: Some(Background::Color(SURFACE)),
-                border_width: 2,
+                border_width: 2.into(),
                 border_color: Color {
                     a: if self.is_focused { 1.0 } else { 0.3 },
                     ..Color::BLACK
@@ -269,7 +269,7 @@ mod style {
     }
 
     impl button::StyleSheet for Button {
-        fn active(&self) -> button::Style {
+        fn active(&self, _theme: &Theme) -> button::Style {
             let (background, text_color) = match self {
                 Button::Primary => (Some(ACTIVE), Color::WHITE),
                 Button::Destructive => {
@@ -280,14 +280,14 @@ mod style {
             button::Style {
                 text_color,
                 background: background.map(Background::Color),
-                border_radius: 5,
+                border_radius: 5.into(),
                 shadow_offset: Vector::new(0.0, 0.0),
                 ..button::Style::default()
             }
         }
 
-        fn hovered(&self) -> button::Style {
-            let active = self.active();
+        fn hovered(&self, theme: &Theme) -> button::Style {
+            let active = self.active(theme);
 
             let background = match self {
                 Button::Primary => Some(HOVERED),
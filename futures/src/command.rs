@@ -1,15 +1,21 @@
-use crate::BoxFuture;
+use crate::{BoxAction, BoxFuture};
 use futures::future::{Future, FutureExt};
+use std::any::Any;
 
 /// A collection of async operations.
 ///
 /// You should be able to turn a future easily into a [`Command`], either by
 /// using the `From` trait or [`Command::perform`].
 ///
+/// A [`Command`] can also hold actions performed with the renderer of an
+/// application, created with [`Command::with_renderer`].
+///
 /// [`Command`]: struct.Command.html
 /// [`Command::perform`]: #method.perform
+/// [`Command::with_renderer`]: #method.with_renderer
 pub struct Command<T> {
     futures: Vec<BoxFuture<T>>,
+    actions: Vec<BoxAction<T>>,
 }
 
 impl<T> Command<T> {
@@ -21,6 +27,7 @@ impl<T> Command<T> {
     pub fn none() -> Self {
         Self {
             futures: Vec::new(),
+            actions: Vec::new(),
         }
     }
 
@@ -34,6 +41,26 @@ impl<T> Command<T> {
     ) -> Command<A> {
         Command {
             futures: vec![Box::pin(future.map(f))],
+            actions: Vec::new(),
+        }
+    }
+
+    /// Creates a [`Command`] that performs the given action with the
+    /// renderer of the application.
+    ///
+    /// The renderer is given as [`Any`], so the action can downcast it to
+    /// the renderer it expects. The action is performed by the shell as soon
+    /// as the [`Command`] is run, before any future.
+    ///
+    /// [`Command`]: struct.Command.html
+    /// [`Any`]: https://doc.rust-lang.org/std/any/trait.Any.html
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_renderer(
+        action: impl FnOnce(&mut dyn Any) -> T + 'static + Send,
+    ) -> Self {
+        Self {
+            futures: Vec::new(),
+            actions: vec![Box::new(action)],
         }
     }
 
@@ -47,6 +74,7 @@ impl<T> Command<T> {
     ) -> Command<A> {
         Command {
             futures: vec![Box::pin(future.map(f))],
+            actions: Vec::new(),
         }
     }
 
@@ -74,6 +102,16 @@ impl<T> Command<T> {
                         as BoxFuture<A>
                 })
                 .collect(),
+            actions: self
+                .actions
+                .drain(..)
+                .map(|action| {
+                    let f = f.clone();
+
+                    Box::new(move |renderer: &mut dyn Any| f(action(renderer)))
+                        as BoxAction<A>
+                })
+                .collect(),
         }
     }
 
@@ -98,6 +136,16 @@ impl<T> Command<T> {
                         as BoxFuture<A>
                 })
                 .collect(),
+            actions: self
+                .actions
+                .drain(..)
+                .map(|action| {
+                    let f = f.clone();
+
+                    Box::new(move |renderer: &mut dyn Any| f(action(renderer)))
+                        as BoxAction<A>
+                })
+                .collect(),
         }
     }
 
@@ -108,17 +156,50 @@ impl<T> Command<T> {
     ///
     /// [`Command`]: struct.Command.html
     pub fn batch(commands: impl IntoIterator<Item = Command<T>>) -> Self {
-        Self {
-            futures: commands
-                .into_iter()
-                .flat_map(|command| command.futures)
-                .collect(),
+        let mut futures = Vec::new();
+        let mut actions = Vec::new();
+
+        for command in commands {
+            futures.extend(command.futures);
+            actions.extend(command.actions);
         }
+
+        Self { futures, actions }
+    }
+
+    /// Returns whether the [`Command`] has actions to perform with the
+    /// renderer of the application.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn has_actions(&self) -> bool {
+        !self.actions.is_empty()
+    }
+
+    /// Performs the actions of the [`Command`] with the given renderer,
+    /// turning their results into futures that are ready right away.
+    ///
+    /// [`Command`]: struct.Command.html
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn perform_actions(mut self, renderer: &mut dyn Any) -> Self
+    where
+        T: 'static + Send,
+    {
+        for action in self.actions.drain(..) {
+            let result = action(renderer);
+
+            self.futures.push(futures::future::ready(result).boxed());
+        }
+
+        self
     }
 
     /// Converts a [`Command`] into its underlying list of futures.
     ///
+    /// Any action left to perform with a renderer is dropped. Use
+    /// [`perform_actions`] first to keep their results.
+    ///
     /// [`Command`]: struct.Command.html
+    /// [`perform_actions`]: #method.perform_actions
     pub fn futures(self) -> Vec<BoxFuture<T>> {
         self.futures
     }
@@ -132,6 +213,7 @@ where
     fn from(future: A) -> Self {
         Self {
             futures: vec![future.boxed()],
+            actions: Vec::new(),
         }
     }
 }
@@ -144,6 +226,7 @@ where
     fn from(future: A) -> Self {
         Self {
             futures: vec![future.boxed_local()],
+            actions: Vec::new(),
         }
     }
 }
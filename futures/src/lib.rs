@@ -40,6 +40,21 @@ pub type BoxFuture<T> = futures::future::BoxFuture<'static, T>;
 #[cfg(target_arch = "wasm32")]
 pub type BoxFuture<T> = futures::future::LocalBoxFuture<'static, T>;
 
+/// A boxed static action, performed with the renderer of an application.
+///
+/// - On native platforms, it needs a `Send` requirement.
+/// - On the Web platform, it does not need a `Send` requirement.
+#[cfg(not(target_arch = "wasm32"))]
+pub type BoxAction<T> =
+    Box<dyn FnOnce(&mut dyn std::any::Any) -> T + 'static + Send>;
+
+/// A boxed static action, performed with the renderer of an application.
+///
+/// - On native platforms, it needs a `Send` requirement.
+/// - On the Web platform, it does not need a `Send` requirement.
+#[cfg(target_arch = "wasm32")]
+pub type BoxAction<T> = Box<dyn FnOnce(&mut dyn std::any::Any) -> T + 'static>;
+
 /// A boxed static stream.
 ///
 /// - On native platforms, it needs a `Send` requirement.
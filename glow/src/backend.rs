@@ -1,13 +1,20 @@
+#[cfg(any(feature = "image", feature = "svg"))]
+use crate::image;
 use crate::quad;
 use crate::text;
 use crate::triangle;
 use crate::{Settings, Transformation, Viewport};
 use iced_graphics::backend;
 use iced_graphics::font;
+use iced_graphics::text::Cluster;
 use iced_graphics::Layer;
-use iced_graphics::Primitive;
+use iced_graphics::{Primitive, Span};
 use iced_native::mouse;
-use iced_native::{Font, HorizontalAlignment, Size, VerticalAlignment};
+use iced_native::{
+    Font, HorizontalAlignment, LineHeight, Point, Rectangle, Size,
+    VerticalAlignment, Wrapping,
+};
+use std::borrow::Cow;
 
 /// A [`glow`] graphics backend for [`iced`].
 ///
@@ -18,6 +25,9 @@ pub struct Backend {
     quad_pipeline: quad::Pipeline,
     text_pipeline: text::Pipeline,
     triangle_pipeline: triangle::Pipeline,
+
+    #[cfg(any(feature = "image", feature = "svg"))]
+    image_pipeline: image::Pipeline,
 }
 
 impl Backend {
@@ -27,12 +37,19 @@ impl Backend {
     pub fn new(gl: &glow::Context, settings: Settings) -> Self {
         let text_pipeline = text::Pipeline::new(gl, settings.default_font);
         let quad_pipeline = quad::Pipeline::new(gl);
-        let triangle_pipeline = triangle::Pipeline::new(gl);
+        let triangle_pipeline =
+            triangle::Pipeline::new(gl, settings.antialiasing);
+
+        #[cfg(any(feature = "image", feature = "svg"))]
+        let image_pipeline = image::Pipeline::new(gl);
 
         Self {
             quad_pipeline,
             text_pipeline,
             triangle_pipeline,
+
+            #[cfg(any(feature = "image", feature = "svg"))]
+            image_pipeline,
         }
     }
 
@@ -60,10 +77,14 @@ impl Backend {
                 scale_factor,
                 projection,
                 &layer,
+                viewport_size.width,
                 viewport_size.height,
             );
         }
 
+        #[cfg(any(feature = "image", feature = "svg"))]
+        self.image_pipeline.trim_cache();
+
         *mouse_interaction
     }
 
@@ -73,6 +94,7 @@ impl Backend {
         scale_factor: f32,
         transformation: Transformation,
         layer: &Layer<'_>,
+        target_width: u32,
         target_height: u32,
     ) {
         let mut bounds = (layer.bounds * scale_factor).snap();
@@ -95,6 +117,7 @@ impl Backend {
 
             self.triangle_pipeline.draw(
                 gl,
+                target_width,
                 target_height,
                 scaled,
                 scale_factor,
@@ -102,10 +125,27 @@ impl Backend {
             );
         }
 
-        if !layer.text.is_empty() {
+        #[cfg(any(feature = "image", feature = "svg"))]
+        {
+            if !layer.images.is_empty() {
+                let scaled = transformation
+                    * Transformation::scale(scale_factor, scale_factor);
+
+                self.image_pipeline.draw(
+                    gl,
+                    target_height,
+                    &layer.images,
+                    scaled,
+                    bounds,
+                    scale_factor,
+                );
+            }
+        }
+
+        if !layer.text.is_empty() || !layer.rich_text.is_empty() {
             for text in layer.text.iter() {
                 // Target physical coordinates directly to avoid blurry text
-                let text = glow_glyph::Section {
+                let section = glow_glyph::Section {
                     // TODO: We `round` here to avoid rerasterizing text when
                     // its position changes slightly. This can make text feel a
                     // bit "jumpy". We may be able to do better once we improve
@@ -133,39 +173,66 @@ impl Backend {
                             x: text.size * scale_factor,
                             y: text.size * scale_factor,
                         },
-                        font_id: self.text_pipeline.find_font(text.font),
+                        font_id: self.text_pipeline.find_font(&text.font),
                         extra: glow_glyph::Extra {
                             color: text.color,
                             z: 0.0,
                         },
                     }],
-                    layout: glow_glyph::Layout::default()
-                        .h_align(match text.horizontal_alignment {
-                            HorizontalAlignment::Left => {
-                                glow_glyph::HorizontalAlign::Left
-                            }
-                            HorizontalAlignment::Center => {
-                                glow_glyph::HorizontalAlign::Center
-                            }
-                            HorizontalAlignment::Right => {
-                                glow_glyph::HorizontalAlign::Right
-                            }
+                    ..Default::default()
+                };
+
+                let paragraph = text::Paragraph {
+                    wrapping: text.wrapping,
+                    overflow: text.overflow,
+                    line_height: text
+                        .line_height
+                        .to_absolute(text.size)
+                        .map(|line_height| line_height * scale_factor),
+                    ..text::Paragraph::new(
+                        text.horizontal_alignment,
+                        text.vertical_alignment,
+                    )
+                };
+
+                self.text_pipeline.queue(section, &paragraph);
+            }
+
+            for text in layer.rich_text.iter() {
+                let section = glow_glyph::Section {
+                    screen_position: (
+                        (text.bounds.x * scale_factor).round(),
+                        (text.bounds.y * scale_factor).round(),
+                    ),
+                    bounds: (
+                        (text.bounds.width * scale_factor).ceil(),
+                        (text.bounds.height * scale_factor).ceil(),
+                    ),
+                    text: text
+                        .spans
+                        .iter()
+                        .map(|span| glow_glyph::Text {
+                            text: &span.content,
+                            scale: glow_glyph::ab_glyph::PxScale {
+                                x: span.size * scale_factor,
+                                y: span.size * scale_factor,
+                            },
+                            font_id: self.text_pipeline.find_font(&span.font),
+                            extra: glow_glyph::Extra {
+                                color: span.color.into_linear(),
+                                z: 0.0,
+                            },
                         })
-                        .v_align(match text.vertical_alignment {
-                            VerticalAlignment::Top => {
-                                glow_glyph::VerticalAlign::Top
-                            }
-                            VerticalAlignment::Center => {
-                                glow_glyph::VerticalAlign::Center
-                            }
-                            VerticalAlignment::Bottom => {
-                                glow_glyph::VerticalAlign::Bottom
-                            }
-                        }),
+                        .collect(),
                     ..Default::default()
                 };
 
-                self.text_pipeline.queue(text);
+                let paragraph = text::Paragraph::new(
+                    text.horizontal_alignment,
+                    text.vertical_alignment,
+                );
+
+                self.text_pipeline.queue(section, &paragraph);
             }
 
             self.text_pipeline.draw_queued(
@@ -197,16 +264,64 @@ impl backend::Text for Backend {
         contents: &str,
         size: f32,
         font: Font,
+        line_height: LineHeight,
+        wrapping: Wrapping,
         bounds: Size,
     ) -> (f32, f32) {
-        self.text_pipeline.measure(contents, size, font, bounds)
+        self.text_pipeline.measure(
+            contents,
+            size,
+            font,
+            line_height,
+            wrapping,
+            bounds,
+        )
+    }
+
+    fn measure_spans(&self, spans: &[Span], bounds: Size) -> (f32, f32) {
+        self.text_pipeline.measure_spans(spans, bounds)
+    }
+
+    fn hit_test(
+        &self,
+        spans: &[Span],
+        bounds: Rectangle,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+        point: Point,
+    ) -> Option<usize> {
+        self.text_pipeline.hit_test(
+            spans,
+            bounds,
+            horizontal_alignment,
+            vertical_alignment,
+            point,
+        )
+    }
+
+    fn clusters(&self, contents: &str, size: f32, font: Font) -> Vec<Cluster> {
+        self.text_pipeline.clusters(contents, size, font)
+    }
+
+    fn load_font(
+        &mut self,
+        bytes: Cow<'static, [u8]>,
+    ) -> Result<iced_native::font::Descriptor, font::Error> {
+        self.text_pipeline.load_font(bytes)
     }
 }
 
 #[cfg(feature = "image")]
 impl backend::Image for Backend {
-    fn dimensions(&self, _handle: &iced_native::image::Handle) -> (u32, u32) {
-        (50, 50)
+    fn dimensions(&self, handle: &iced_native::image::Handle) -> (u32, u32) {
+        self.image_pipeline.dimensions(handle)
+    }
+
+    fn frame_delays(
+        &self,
+        handle: &iced_native::image::Handle,
+    ) -> Vec<std::time::Duration> {
+        self.image_pipeline.frame_delays(handle)
     }
 }
 
@@ -214,8 +329,8 @@ impl backend::Image for Backend {
 impl backend::Svg for Backend {
     fn viewport_dimensions(
         &self,
-        _handle: &iced_native::svg::Handle,
+        handle: &iced_native::svg::Handle,
     ) -> (u32, u32) {
-        (50, 50)
+        self.image_pipeline.viewport_dimensions(handle)
     }
 }
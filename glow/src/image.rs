@@ -0,0 +1,455 @@
+mod atlas;
+
+use crate::program;
+use crate::Transformation;
+use atlas::Atlas;
+
+use glow::HasContext;
+use iced_graphics::layer;
+use iced_native::image;
+use iced_native::Rectangle;
+use std::cell::RefCell;
+
+#[cfg(feature = "image")]
+use iced_graphics::image::raster;
+
+#[cfg(feature = "image")]
+use std::time::Duration;
+
+#[cfg(feature = "svg")]
+use iced_graphics::image::vector;
+
+#[cfg(feature = "svg")]
+use iced_native::svg;
+
+const MAX_INSTANCES: usize = 1_000;
+
+#[derive(Debug)]
+pub struct Pipeline {
+    #[cfg(feature = "image")]
+    raster_cache: RefCell<raster::Cache>,
+    #[cfg(feature = "svg")]
+    vector_cache: RefCell<vector::Cache>,
+
+    program: <glow::Context as HasContext>::Program,
+    vertex_array: <glow::Context as HasContext>::VertexArray,
+    instances: <glow::Context as HasContext>::Buffer,
+    transform_location: <glow::Context as HasContext>::UniformLocation,
+    current_transform: Transformation,
+    texture_atlas: Atlas,
+    atlas_textures: atlas::Textures,
+}
+
+impl Pipeline {
+    pub fn new(gl: &glow::Context) -> Pipeline {
+        let program = unsafe {
+            program::create(
+                gl,
+                &[
+                    (glow::VERTEX_SHADER, include_str!("shader/image.vert")),
+                    (glow::FRAGMENT_SHADER, include_str!("shader/image.frag")),
+                ],
+            )
+        };
+
+        let transform_location =
+            unsafe { gl.get_uniform_location(program, "u_Transform") }
+                .expect("Get transform location");
+
+        let texture_location =
+            unsafe { gl.get_uniform_location(program, "u_Texture") }
+                .expect("Get texture location");
+
+        unsafe {
+            gl.use_program(Some(program));
+
+            let matrix: [f32; 16] = Transformation::identity().into();
+            gl.uniform_matrix_4_f32_slice(
+                Some(transform_location),
+                false,
+                &matrix,
+            );
+
+            // Atlas layers are always bound to the first texture unit
+            gl.uniform_1_i32(Some(texture_location), 0);
+
+            gl.use_program(None);
+        }
+
+        let (vertex_array, instances) =
+            unsafe { create_instance_buffer(gl, MAX_INSTANCES) };
+
+        Pipeline {
+            #[cfg(feature = "image")]
+            raster_cache: RefCell::new(raster::Cache::new()),
+
+            #[cfg(feature = "svg")]
+            vector_cache: RefCell::new(vector::Cache::new()),
+
+            program,
+            vertex_array,
+            instances,
+            transform_location,
+            current_transform: Transformation::identity(),
+            texture_atlas: Atlas::new(),
+            atlas_textures: atlas::Textures::new(gl),
+        }
+    }
+
+    #[cfg(feature = "image")]
+    pub fn dimensions(&self, handle: &image::Handle) -> (u32, u32) {
+        let mut cache = self.raster_cache.borrow_mut();
+        let memory = cache.load(&handle);
+
+        memory.dimensions()
+    }
+
+    #[cfg(feature = "image")]
+    pub fn frame_delays(&self, handle: &image::Handle) -> Vec<Duration> {
+        let mut cache = self.raster_cache.borrow_mut();
+        let memory = cache.load(&handle);
+
+        memory.frame_delays()
+    }
+
+    #[cfg(feature = "svg")]
+    pub fn viewport_dimensions(&self, handle: &svg::Handle) -> (u32, u32) {
+        let mut cache = self.vector_cache.borrow_mut();
+        let svg = cache.load(&handle);
+
+        svg.viewport_dimensions()
+    }
+
+    pub fn draw(
+        &mut self,
+        gl: &glow::Context,
+        target_height: u32,
+        images: &[layer::Image],
+        transformation: Transformation,
+        bounds: Rectangle<u32>,
+        _scale: f32,
+    ) {
+        let mut instances = Instances::default();
+
+        #[cfg(feature = "image")]
+        let mut raster_cache = self.raster_cache.borrow_mut();
+
+        #[cfg(feature = "svg")]
+        let mut vector_cache = self.vector_cache.borrow_mut();
+
+        let mut uploader = self.atlas_textures.uploader(gl);
+
+        for image in images {
+            match &image {
+                #[cfg(feature = "image")]
+                layer::Image::Raster {
+                    handle,
+                    frame,
+                    filter_method,
+                    bounds,
+                } => {
+                    if let Some(atlas_entry) = raster_cache.upload(
+                        handle,
+                        *frame,
+                        &mut self.texture_atlas,
+                        &mut uploader,
+                    ) {
+                        instances.add(
+                            [bounds.x, bounds.y],
+                            [bounds.width, bounds.height],
+                            atlas_entry,
+                            *filter_method,
+                        );
+                    }
+                }
+                #[cfg(not(feature = "image"))]
+                layer::Image::Raster { .. } => {}
+
+                #[cfg(feature = "svg")]
+                layer::Image::Vector {
+                    handle,
+                    color,
+                    bounds,
+                } => {
+                    let size = [bounds.width, bounds.height];
+
+                    if let Some(atlas_entry) = vector_cache.upload(
+                        handle,
+                        *color,
+                        size,
+                        _scale,
+                        &mut self.texture_atlas,
+                        &mut uploader,
+                    ) {
+                        instances.add(
+                            [bounds.x, bounds.y],
+                            size,
+                            atlas_entry,
+                            image::FilterMethod::Linear,
+                        );
+                    }
+                }
+                #[cfg(not(feature = "svg"))]
+                layer::Image::Vector { .. } => {}
+            }
+        }
+
+        if instances.raw.is_empty() {
+            return;
+        }
+
+        unsafe {
+            gl.enable(glow::SCISSOR_TEST);
+            gl.scissor(
+                bounds.x as i32,
+                (target_height - (bounds.y + bounds.height)) as i32,
+                bounds.width as i32,
+                bounds.height as i32,
+            );
+
+            gl.use_program(Some(self.program));
+            gl.bind_vertex_array(Some(self.vertex_array));
+            gl.bind_buffer(glow::ARRAY_BUFFER, Some(self.instances));
+            gl.active_texture(glow::TEXTURE0);
+        }
+
+        if transformation != self.current_transform {
+            unsafe {
+                let matrix: [f32; 16] = transformation.into();
+                gl.uniform_matrix_4_f32_slice(
+                    Some(self.transform_location),
+                    false,
+                    &matrix,
+                );
+
+                self.current_transform = transformation;
+            }
+        }
+
+        // Batches keep the order of the images, so overlapping images are
+        // drawn correctly
+        let mut start = 0;
+
+        for batch in &instances.batches {
+            let filter = match batch.filter_method {
+                image::FilterMethod::Linear => glow::LINEAR,
+                image::FilterMethod::Nearest => glow::NEAREST,
+            };
+
+            unsafe {
+                gl.bind_texture(
+                    glow::TEXTURE_2D,
+                    Some(self.atlas_textures.get(batch.layer)),
+                );
+                gl.tex_parameter_i32(
+                    glow::TEXTURE_2D,
+                    glow::TEXTURE_MIN_FILTER,
+                    filter as i32,
+                );
+                gl.tex_parameter_i32(
+                    glow::TEXTURE_2D,
+                    glow::TEXTURE_MAG_FILTER,
+                    filter as i32,
+                );
+            }
+
+            let mut i = start;
+
+            while i < batch.end {
+                let end = (i + MAX_INSTANCES).min(batch.end);
+                let amount = end - i;
+
+                unsafe {
+                    gl.buffer_sub_data_u8_slice(
+                        glow::ARRAY_BUFFER,
+                        0,
+                        bytemuck::cast_slice(&instances.raw[i..end]),
+                    );
+
+                    gl.draw_arrays_instanced(
+                        glow::TRIANGLE_STRIP,
+                        0,
+                        4,
+                        amount as i32,
+                    );
+                }
+
+                i += MAX_INSTANCES;
+            }
+
+            start = batch.end;
+        }
+
+        unsafe {
+            gl.bind_texture(glow::TEXTURE_2D, None);
+            gl.bind_vertex_array(None);
+            gl.use_program(None);
+            gl.disable(glow::SCISSOR_TEST);
+        }
+    }
+
+    pub fn trim_cache(&mut self) {
+        #[cfg(feature = "image")]
+        self.raster_cache.borrow_mut().trim(&mut self.texture_atlas);
+
+        #[cfg(feature = "svg")]
+        self.vector_cache.borrow_mut().trim(&mut self.texture_atlas);
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct Instance {
+    position: [f32; 2],
+    size: [f32; 2],
+    position_in_atlas: [f32; 2],
+    size_in_atlas: [f32; 2],
+}
+
+unsafe impl bytemuck::Zeroable for Instance {}
+unsafe impl bytemuck::Pod for Instance {}
+
+/// A run of instances sampling the same atlas layer in the same way.
+#[derive(Debug)]
+struct Batch {
+    layer: usize,
+    filter_method: image::FilterMethod,
+    end: usize,
+}
+
+#[derive(Debug, Default)]
+struct Instances {
+    raw: Vec<Instance>,
+    batches: Vec<Batch>,
+}
+
+impl Instances {
+    fn add(
+        &mut self,
+        image_position: [f32; 2],
+        image_size: [f32; 2],
+        entry: &atlas::Entry,
+        filter_method: image::FilterMethod,
+    ) {
+        match entry {
+            atlas::Entry::Contiguous(allocation) => {
+                self.add_allocation(
+                    image_position,
+                    image_size,
+                    allocation,
+                    filter_method,
+                );
+            }
+            atlas::Entry::Fragmented { fragments, size } => {
+                let scaling_x = image_size[0] / size.0 as f32;
+                let scaling_y = image_size[1] / size.1 as f32;
+
+                for fragment in fragments {
+                    let allocation = &fragment.allocation;
+
+                    let [x, y] = image_position;
+                    let (fragment_x, fragment_y) = fragment.position;
+                    let (fragment_width, fragment_height) = allocation.size();
+
+                    let position = [
+                        x + fragment_x as f32 * scaling_x,
+                        y + fragment_y as f32 * scaling_y,
+                    ];
+
+                    let size = [
+                        fragment_width as f32 * scaling_x,
+                        fragment_height as f32 * scaling_y,
+                    ];
+
+                    self.add_allocation(
+                        position,
+                        size,
+                        allocation,
+                        filter_method,
+                    );
+                }
+            }
+        }
+    }
+
+    fn add_allocation(
+        &mut self,
+        position: [f32; 2],
+        size: [f32; 2],
+        allocation: &atlas::Allocation,
+        filter_method: image::FilterMethod,
+    ) {
+        let (x, y) = allocation.position();
+        let (width, height) = allocation.size();
+        let layer = allocation.layer();
+
+        self.raw.push(Instance {
+            position,
+            size,
+            position_in_atlas: [
+                (x as f32 + 0.5) / atlas::SIZE as f32,
+                (y as f32 + 0.5) / atlas::SIZE as f32,
+            ],
+            size_in_atlas: [
+                (width as f32 - 1.0) / atlas::SIZE as f32,
+                (height as f32 - 1.0) / atlas::SIZE as f32,
+            ],
+        });
+
+        let end = self.raw.len();
+
+        match self.batches.last_mut() {
+            Some(batch)
+                if batch.layer == layer
+                    && batch.filter_method == filter_method =>
+            {
+                batch.end = end;
+            }
+            _ => self.batches.push(Batch {
+                layer,
+                filter_method,
+                end,
+            }),
+        }
+    }
+}
+
+unsafe fn create_instance_buffer(
+    gl: &glow::Context,
+    size: usize,
+) -> (
+    <glow::Context as HasContext>::VertexArray,
+    <glow::Context as HasContext>::Buffer,
+) {
+    let vertex_array = gl.create_vertex_array().expect("Create vertex array");
+    let buffer = gl.create_buffer().expect("Create instance buffer");
+
+    gl.bind_vertex_array(Some(vertex_array));
+    gl.bind_buffer(glow::ARRAY_BUFFER, Some(buffer));
+    gl.buffer_data_size(
+        glow::ARRAY_BUFFER,
+        (size * std::mem::size_of::<Instance>()) as i32,
+        glow::DYNAMIC_DRAW,
+    );
+
+    let stride = std::mem::size_of::<Instance>() as i32;
+
+    // Position, size, position in atlas and size in atlas
+    for index in 0..4 {
+        gl.enable_vertex_attrib_array(index);
+        gl.vertex_attrib_pointer_f32(
+            index,
+            2,
+            glow::FLOAT,
+            false,
+            stride,
+            4 * 2 * index as i32,
+        );
+        gl.vertex_attrib_divisor(index, 1);
+    }
+
+    gl.bind_vertex_array(None);
+    gl.bind_buffer(glow::ARRAY_BUFFER, None);
+
+    (vertex_array, buffer)
+}
@@ -0,0 +1,122 @@
+pub use iced_graphics::image::atlas::{Allocation, Atlas, Entry, Layer, SIZE};
+
+use glow::HasContext;
+use iced_graphics::image::atlas::Storage;
+
+/// The textures holding the layers of an [`Atlas`].
+///
+/// Unlike `iced_wgpu`, every layer is a separate texture. This way, the atlas
+/// can grow without copying its contents, which is expensive in OpenGL 3.3.
+///
+/// [`Atlas`]: struct.Atlas.html
+#[derive(Debug)]
+pub struct Textures {
+    raw: Vec<<glow::Context as HasContext>::Texture>,
+}
+
+impl Textures {
+    pub fn new(gl: &glow::Context) -> Self {
+        let mut textures = Textures { raw: Vec::new() };
+
+        textures.uploader(gl).grow(Atlas::new().layers());
+        textures
+    }
+
+    pub fn get(&self, layer: usize) -> <glow::Context as HasContext>::Texture {
+        self.raw[layer]
+    }
+
+    pub fn uploader<'a>(&'a mut self, gl: &'a glow::Context) -> Uploader<'a> {
+        Uploader { textures: self, gl }
+    }
+}
+
+/// Uploads the images of an [`Atlas`] to some [`Textures`].
+///
+/// [`Atlas`]: struct.Atlas.html
+/// [`Textures`]: struct.Textures.html
+#[derive(Debug)]
+pub struct Uploader<'a> {
+    textures: &'a mut Textures,
+    gl: &'a glow::Context,
+}
+
+impl Storage for Uploader<'_> {
+    fn grow(&mut self, layers: &[Layer]) {
+        let gl = self.gl;
+
+        while self.textures.raw.len() < layers.len() {
+            let texture = unsafe {
+                let texture = gl.create_texture().expect("Create texture");
+
+                gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+
+                gl.tex_image_2d(
+                    glow::TEXTURE_2D,
+                    0,
+                    glow::SRGB8_ALPHA8 as i32,
+                    SIZE as i32,
+                    SIZE as i32,
+                    0,
+                    glow::BGRA,
+                    glow::UNSIGNED_BYTE,
+                    None,
+                );
+
+                gl.tex_parameter_i32(
+                    glow::TEXTURE_2D,
+                    glow::TEXTURE_WRAP_S,
+                    glow::CLAMP_TO_EDGE as i32,
+                );
+                gl.tex_parameter_i32(
+                    glow::TEXTURE_2D,
+                    glow::TEXTURE_WRAP_T,
+                    glow::CLAMP_TO_EDGE as i32,
+                );
+
+                gl.bind_texture(glow::TEXTURE_2D, None);
+
+                texture
+            };
+
+            self.textures.raw.push(texture);
+        }
+    }
+
+    fn upload(&mut self, entry: &Entry, width: u32, height: u32, data: &[u8]) {
+        debug_assert_eq!(data.len(), (width * height * 4) as usize);
+
+        let gl = self.gl;
+
+        for (offset, allocation) in entry.allocations(width) {
+            let (x, y) = allocation.position();
+            let (allocation_width, allocation_height) = allocation.size();
+            let texture = self.textures.raw[allocation.layer()];
+
+            // The last row of the allocation does not need a full image row
+            let end = offset
+                + ((allocation_height - 1) * width + allocation_width) as usize
+                    * 4;
+
+            unsafe {
+                gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+                gl.pixel_store_i32(glow::UNPACK_ROW_LENGTH, width as i32);
+
+                gl.tex_sub_image_2d_u8_slice(
+                    glow::TEXTURE_2D,
+                    0,
+                    x as i32,
+                    y as i32,
+                    allocation_width as i32,
+                    allocation_height as i32,
+                    glow::BGRA,
+                    glow::UNSIGNED_BYTE,
+                    Some(&data[offset..end]),
+                );
+
+                gl.pixel_store_i32(glow::UNPACK_ROW_LENGTH, 0);
+                gl.bind_texture(glow::TEXTURE_2D, None);
+            }
+        }
+    }
+}
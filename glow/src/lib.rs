@@ -14,6 +14,9 @@ mod quad;
 mod text;
 mod triangle;
 
+#[cfg(any(feature = "image", feature = "svg"))]
+mod image;
+
 pub mod settings;
 pub mod widget;
 pub mod window;
@@ -26,9 +29,10 @@ pub(crate) use iced_graphics::Transformation;
 #[doc(no_inline)]
 pub use widget::*;
 
-pub use iced_graphics::Viewport;
+pub use iced_graphics::{font, theme, Theme, Viewport};
 pub use iced_native::{
-    Background, Color, Command, HorizontalAlignment, Length, Vector,
+    gradient, Background, BorderRadius, BorderWidth, Color, Command,
+    ContentFit, Gradient, HorizontalAlignment, Length, Shadow, Vector,
     VerticalAlignment,
 };
 
@@ -183,50 +183,49 @@ unsafe fn create_instance_buffer(
 
     let stride = std::mem::size_of::<layer::Quad>() as i32;
 
-    gl.enable_vertex_attrib_array(0);
-    gl.vertex_attrib_pointer_f32(0, 2, glow::FLOAT, false, stride, 0);
-    gl.vertex_attrib_divisor(0, 1);
-
-    gl.enable_vertex_attrib_array(1);
-    gl.vertex_attrib_pointer_f32(1, 2, glow::FLOAT, false, stride, 4 * 2);
-    gl.vertex_attrib_divisor(1, 1);
-
-    gl.enable_vertex_attrib_array(2);
-    gl.vertex_attrib_pointer_f32(2, 4, glow::FLOAT, false, stride, 4 * (2 + 2));
-    gl.vertex_attrib_divisor(2, 1);
-
-    gl.enable_vertex_attrib_array(3);
-    gl.vertex_attrib_pointer_f32(
-        3,
-        4,
-        glow::FLOAT,
-        false,
-        stride,
-        4 * (2 + 2 + 4),
-    );
-    gl.vertex_attrib_divisor(3, 1);
-
-    gl.enable_vertex_attrib_array(4);
-    gl.vertex_attrib_pointer_f32(
-        4,
-        1,
-        glow::FLOAT,
-        false,
-        stride,
-        4 * (2 + 2 + 4 + 4),
-    );
-    gl.vertex_attrib_divisor(4, 1);
-
-    gl.enable_vertex_attrib_array(5);
-    gl.vertex_attrib_pointer_f32(
-        5,
-        1,
-        glow::FLOAT,
-        false,
-        stride,
-        4 * (2 + 2 + 4 + 4 + 1),
-    );
-    gl.vertex_attrib_divisor(5, 1);
+    // (components, offset) of every attribute of a `layer::Quad`
+    let attributes: [(i32, i32); 14] = [
+        // Position
+        (2, 0),
+        // Size
+        (2, 4 * 2),
+        // Background colors
+        (4, 4 * 4),
+        (4, 4 * 8),
+        (4, 4 * 12),
+        (4, 4 * 16),
+        // Background offsets
+        (4, 4 * 20),
+        // Border color
+        (4, 4 * 24),
+        // Border radius
+        (4, 4 * 28),
+        // Border width
+        (4, 4 * 32),
+        // Background angle
+        (1, 4 * 36),
+        // Shadow color
+        (4, 4 * 37),
+        // Shadow offset
+        (2, 4 * 41),
+        // Shadow blur radius
+        (1, 4 * 43),
+    ];
+
+    for (index, (size, offset)) in attributes.iter().enumerate() {
+        let index = index as u32;
+
+        gl.enable_vertex_attrib_array(index);
+        gl.vertex_attrib_pointer_f32(
+            index,
+            *size,
+            glow::FLOAT,
+            false,
+            stride,
+            *offset,
+        );
+        gl.vertex_attrib_divisor(index, 1);
+    }
 
     gl.bind_vertex_array(None);
     gl.bind_buffer(glow::ARRAY_BUFFER, None);
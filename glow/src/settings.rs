@@ -1,15 +1,19 @@
 //! Configure a renderer.
 pub use iced_graphics::Antialiasing;
+use std::borrow::Cow;
 
 /// The settings of a [`Renderer`].
 ///
 /// [`Renderer`]: ../struct.Renderer.html
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Settings {
     /// The bytes of the font that will be used by default.
     ///
+    /// The bytes can be borrowed for the whole program or owned, like when
+    /// they are read from a file at runtime.
+    ///
     /// If `None` is provided, a default system font will be chosen.
-    pub default_font: Option<&'static [u8]>,
+    pub default_font: Option<Cow<'static, [u8]>>,
 
     /// The antialiasing strategy that will be used for triangle primitives.
     pub antialiasing: Option<Antialiasing>,
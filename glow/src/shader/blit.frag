@@ -0,0 +1,11 @@
+#version 330
+
+uniform sampler2D u_Texture;
+
+in vec2 v_Uv;
+
+out vec4 o_Color;
+
+void main() {
+    o_Color = texture(u_Texture, v_Uv);
+}
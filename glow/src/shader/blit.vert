@@ -0,0 +1,17 @@
+#version 330
+
+out vec2 v_Uv;
+
+const vec2 positions[4] = vec2[](
+    vec2(-1.0, -1.0),
+    vec2(1.0, -1.0),
+    vec2(-1.0, 1.0),
+    vec2(1.0, 1.0)
+);
+
+void main() {
+    vec2 position = positions[gl_VertexID];
+
+    v_Uv = (position + vec2(1.0)) * 0.5;
+    gl_Position = vec4(position, 0.0, 1.0);
+}
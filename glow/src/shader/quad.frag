@@ -2,12 +2,20 @@
 
 uniform float u_ScreenHeight;
 
-in vec4 v_Color;
+in vec4 v_Color0;
+in vec4 v_Color1;
+in vec4 v_Color2;
+in vec4 v_Color3;
+in vec4 v_Offsets;
 in vec4 v_BorderColor;
 in vec2 v_Pos;
 in vec2 v_Scale;
-in float v_BorderRadius;
-in float v_BorderWidth;
+in vec4 v_BorderRadius;
+in vec4 v_BorderWidth;
+in float v_Angle;
+in vec4 v_ShadowColor;
+in vec2 v_ShadowOffset;
+in float v_ShadowBlurRadius;
 
 out vec4 o_Color;
 
@@ -29,19 +37,85 @@ float distance(in vec2 frag_coord, in vec2 position, in vec2 size, float radius)
     return sqrt(distance.x * distance.x + distance.y * distance.y);
 }
 
+// The radii are ordered clockwise, starting from the top left corner
+float select_radius(in vec4 radii, in vec2 frag_coord, in vec2 position, in vec2 size)
+{
+    vec2 center = position + size * 0.5;
+    vec2 radius = frag_coord.x < center.x ? radii.xw : radii.yz;
+
+    return frag_coord.y < center.y ? radius.x : radius.y;
+}
+
+float rounded_box_sdf(in vec2 to_center, in vec2 half_size, float radius)
+{
+    vec2 q = abs(to_center) - half_size + vec2(radius);
+
+    return length(max(q, vec2(0.0))) + min(max(q.x, q.y), 0.0) - radius;
+}
+
+float stop_mix(float t, float start, float end)
+{
+    return clamp((t - start) / max(end - start, 0.0001), 0.0, 1.0);
+}
+
+vec4 background(in vec2 frag_coord)
+{
+    vec2 direction = vec2(cos(v_Angle), sin(v_Angle));
+    vec2 center = v_Pos + v_Scale * 0.5;
+
+    // The gradient line is long enough to touch the corners of the quad
+    float half_length =
+        abs(v_Scale.x * direction.x) * 0.5 + abs(v_Scale.y * direction.y) * 0.5;
+
+    float t = dot(frag_coord - center, direction) / max(half_length * 2.0, 1.0)
+        + 0.5;
+
+    vec4 color = v_Color0;
+    color = mix(color, v_Color1, stop_mix(t, v_Offsets.x, v_Offsets.y));
+    color = mix(color, v_Color2, stop_mix(t, v_Offsets.y, v_Offsets.z));
+    color = mix(color, v_Color3, stop_mix(t, v_Offsets.z, v_Offsets.w));
+
+    return color;
+}
+
 void main() {
     vec4 mixed_color;
 
     vec2 fragCoord = vec2(gl_FragCoord.x, u_ScreenHeight - gl_FragCoord.y);
+    vec4 color = background(fragCoord);
+
+    float border_radius =
+        select_radius(v_BorderRadius, fragCoord, v_Pos, v_Scale);
+
+    // The widths are ordered clockwise, starting from the top side
+    vec4 w = v_BorderWidth;
 
     // TODO: Remove branching (?)
-    if(v_BorderWidth > 0) {
-        float internal_border = max(v_BorderRadius - v_BorderWidth, 0.0);
+    if(max(max(w.x, w.y), max(w.z, w.w)) > 0.0) {
+        vec2 internal_position = v_Pos + w.wx;
+        vec2 internal_scale = v_Scale - vec2(w.w + w.y, w.x + w.z);
+
+        vec4 internal_radii = max(
+            v_BorderRadius - vec4(
+                max(w.w, w.x),
+                max(w.x, w.y),
+                max(w.y, w.z),
+                max(w.z, w.w)
+            ),
+            vec4(0.0)
+        );
+
+        float internal_border = select_radius(
+            internal_radii,
+            fragCoord,
+            internal_position,
+            internal_scale
+        );
 
         float internal_distance = distance(
             fragCoord,
-            v_Pos + vec2(v_BorderWidth),
-            v_Scale - vec2(v_BorderWidth * 2.0),
+            internal_position,
+            internal_scale,
             internal_border
         );
 
@@ -51,20 +125,52 @@ void main() {
             internal_distance
         );
 
-        mixed_color = mix(v_Color, v_BorderColor, border_mix);
+        mixed_color = mix(color, v_BorderColor, border_mix);
     } else {
-        mixed_color = v_Color;
+        mixed_color = color;
     }
 
     float d = distance(
         fragCoord,
         v_Pos,
         v_Scale,
-        v_BorderRadius
+        border_radius
     );
 
     float radius_alpha =
-        1.0 - smoothstep(max(v_BorderRadius - 0.5, 0.0), v_BorderRadius + 0.5, d);
+        1.0 - smoothstep(max(border_radius - 0.5, 0.0), border_radius + 0.5, d);
+
+    vec4 quad_color = vec4(mixed_color.xyz, mixed_color.w * radius_alpha);
 
-    o_Color = vec4(mixed_color.xyz, mixed_color.w * radius_alpha);
+    if(v_ShadowColor.a > 0.0) {
+        vec2 shadow_position = v_Pos + v_ShadowOffset;
+        vec2 half_size = v_Scale * 0.5;
+
+        float shadow_radius = min(
+            select_radius(v_BorderRadius, fragCoord, shadow_position, v_Scale),
+            min(half_size.x, half_size.y)
+        );
+
+        float shadow_distance = rounded_box_sdf(
+            fragCoord - (shadow_position + half_size),
+            half_size,
+            shadow_radius
+        );
+
+        float blur = max(v_ShadowBlurRadius, 0.5);
+        float shadow_alpha =
+            v_ShadowColor.a * (1.0 - smoothstep(-blur, blur, shadow_distance));
+
+        // Draw the quad over its shadow
+        float alpha = quad_color.a + shadow_alpha * (1.0 - quad_color.a);
+
+        vec3 rgb = (
+            quad_color.rgb * quad_color.a
+                + v_ShadowColor.rgb * shadow_alpha * (1.0 - quad_color.a)
+        ) / max(alpha, 0.0001);
+
+        o_Color = vec4(rgb, alpha);
+    } else {
+        o_Color = quad_color;
+    }
 }
@@ -5,17 +5,33 @@ uniform float u_Scale;
 
 layout(location = 0) in vec2 i_Pos;
 layout(location = 1) in vec2 i_Scale;
-layout(location = 2) in vec4 i_Color;
-layout(location = 3) in vec4 i_BorderColor;
-layout(location = 4) in float i_BorderRadius;
-layout(location = 5) in float i_BorderWidth;
+layout(location = 2) in vec4 i_Color0;
+layout(location = 3) in vec4 i_Color1;
+layout(location = 4) in vec4 i_Color2;
+layout(location = 5) in vec4 i_Color3;
+layout(location = 6) in vec4 i_Offsets;
+layout(location = 7) in vec4 i_BorderColor;
+layout(location = 8) in vec4 i_BorderRadius;
+layout(location = 9) in vec4 i_BorderWidth;
+layout(location = 10) in float i_Angle;
+layout(location = 11) in vec4 i_ShadowColor;
+layout(location = 12) in vec2 i_ShadowOffset;
+layout(location = 13) in float i_ShadowBlurRadius;
 
-out vec4 v_Color;
+out vec4 v_Color0;
+out vec4 v_Color1;
+out vec4 v_Color2;
+out vec4 v_Color3;
+out vec4 v_Offsets;
 out vec4 v_BorderColor;
 out vec2 v_Pos;
 out vec2 v_Scale;
-out float v_BorderRadius;
-out float v_BorderWidth;
+out vec4 v_BorderRadius;
+out vec4 v_BorderWidth;
+out float v_Angle;
+out vec4 v_ShadowColor;
+out vec2 v_ShadowOffset;
+out float v_ShadowBlurRadius;
 
 const vec2 positions[4] = vec2[](
     vec2(0.0, 0.0),
@@ -28,20 +44,43 @@ void main() {
     vec2 q_Pos = positions[gl_VertexID];
     vec2 p_Pos = i_Pos * u_Scale;
     vec2 p_Scale = i_Scale  * u_Scale;
+    vec2 p_ShadowOffset = i_ShadowOffset * u_Scale;
+    float p_ShadowBlurRadius = i_ShadowBlurRadius * u_Scale;
+
+    // Grow the drawn area to fit the shadow, if visible
+    vec2 p_Min = p_Pos;
+    vec2 p_Max = p_Pos + p_Scale;
+
+    if(i_ShadowColor.a > 0.0) {
+        vec2 shadow_spread = vec2(max(p_ShadowBlurRadius, 0.5));
+
+        p_Min = min(p_Min, p_Pos + p_ShadowOffset - shadow_spread);
+        p_Max = max(p_Max, p_Pos + p_Scale + p_ShadowOffset + shadow_spread);
+    }
+
+    vec2 p_Size = p_Max - p_Min;
 
     mat4 i_Transform = mat4(
-        vec4(p_Scale.x + 1.0, 0.0, 0.0, 0.0),
-        vec4(0.0, p_Scale.y + 1.0, 0.0, 0.0),
+        vec4(p_Size.x + 1.0, 0.0, 0.0, 0.0),
+        vec4(0.0, p_Size.y + 1.0, 0.0, 0.0),
         vec4(0.0, 0.0, 1.0, 0.0),
-        vec4(p_Pos - vec2(0.5, 0.5), 0.0, 1.0)
+        vec4(p_Min - vec2(0.5, 0.5), 0.0, 1.0)
     );
 
-    v_Color = i_Color;
+    v_Color0 = i_Color0;
+    v_Color1 = i_Color1;
+    v_Color2 = i_Color2;
+    v_Color3 = i_Color3;
+    v_Offsets = i_Offsets;
     v_BorderColor = i_BorderColor;
     v_Pos = p_Pos;
     v_Scale = p_Scale;
     v_BorderRadius = i_BorderRadius * u_Scale;
     v_BorderWidth = i_BorderWidth * u_Scale;
+    v_Angle = i_Angle;
+    v_ShadowColor = i_ShadowColor;
+    v_ShadowOffset = p_ShadowOffset;
+    v_ShadowBlurRadius = p_ShadowBlurRadius;
 
     gl_Position = u_Transform * i_Transform * vec4(q_Pos, 0.0, 1.0);
 }
@@ -1,18 +1,42 @@
 use crate::Transformation;
 use glow_glyph::ab_glyph;
-use iced_graphics::font;
-use std::{cell::RefCell, collections::HashMap};
+use iced_graphics::font::{self, Attributes};
+use iced_graphics::text::{self, shaping};
+use iced_graphics::Span;
+use iced_native::{
+    HorizontalAlignment, LineHeight, Point, Rectangle, Size, VerticalAlignment,
+    Wrapping,
+};
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+pub use iced_graphics::text::{Cluster, Paragraph};
 
 #[derive(Debug)]
 pub struct Pipeline {
     draw_brush: RefCell<glow_glyph::GlyphBrush>,
     draw_font_map: RefCell<HashMap<String, glow_glyph::FontId>>,
     measure_brush: RefCell<glyph_brush::GlyphBrush<()>>,
+    measurement_cache: RefCell<text::Cache>,
+    font_data: RefCell<Vec<shaping::Data>>,
+    font_attributes: RefCell<Vec<Option<Attributes>>>,
+    descriptor_map:
+        RefCell<HashMap<iced_native::font::Descriptor, glow_glyph::FontId>>,
+    external_fonts: RefCell<Vec<glow_glyph::FontId>>,
+    system_fonts: RefCell<Vec<glow_glyph::FontId>>,
+    #[cfg(feature = "default_system_font")]
+    next_fallback: std::cell::Cell<usize>,
 }
 
 impl Pipeline {
-    pub fn new(gl: &glow::Context, default_font: Option<&[u8]>) -> Self {
-        let default_font = default_font.map(|slice| slice.to_vec());
+    pub fn new(
+        gl: &glow::Context,
+        default_font: Option<Cow<'static, [u8]>>,
+    ) -> Self {
+        use ab_glyph::Font as _;
+
+        let default_font = default_font.map(|bytes| (bytes, 0));
 
         // TODO: Font customization
         #[cfg(feature = "default_system_font")]
@@ -21,22 +45,34 @@ impl Pipeline {
                 font::Source::new()
                     .load(&[font::Family::SansSerif, font::Family::Serif])
                     .ok()
+                    .map(|face| (Cow::Owned(face.data), face.index))
             })
         };
 
-        let default_font =
-            default_font.unwrap_or_else(|| font::FALLBACK.to_vec());
+        let fallback = || {
+            (
+                ab_glyph::FontArc::try_from_slice(font::FALLBACK)
+                    .expect("Load fallback font"),
+                0,
+            )
+        };
 
-        let font = ab_glyph::FontArc::try_from_vec(default_font)
-            .unwrap_or_else(|_| {
-                log::warn!(
-                    "System font failed to load. Falling back to \
-                    embedded font..."
-                );
+        let (font, index) = match default_font {
+            Some((bytes, index)) => match load(bytes, index) {
+                Ok(font) => (font, index),
+                Err(_) => {
+                    log::warn!(
+                        "System font failed to load. Falling back to \
+                        embedded font..."
+                    );
 
-                ab_glyph::FontArc::try_from_slice(font::FALLBACK)
-                    .expect("Load fallback font")
-            });
+                    fallback()
+                }
+            },
+            None => fallback(),
+        };
+
+        let attributes = Attributes::parse(font.font_data(), index);
 
         let draw_brush =
             glow_glyph::GlyphBrushBuilder::using_font(font.clone())
@@ -51,11 +87,31 @@ impl Pipeline {
             draw_brush: RefCell::new(draw_brush),
             draw_font_map: RefCell::new(HashMap::new()),
             measure_brush: RefCell::new(measure_brush),
+            measurement_cache: RefCell::new(text::Cache::new()),
+            font_attributes: RefCell::new(vec![attributes]),
+            font_data: RefCell::new(vec![shaping::Data { index }]),
+            descriptor_map: RefCell::new(HashMap::new()),
+            external_fonts: RefCell::new(Vec::new()),
+            system_fonts: RefCell::new(Vec::new()),
+            #[cfg(feature = "default_system_font")]
+            next_fallback: std::cell::Cell::new(0),
         }
     }
 
-    pub fn queue(&mut self, section: glow_glyph::Section<'_>) {
-        self.draw_brush.borrow_mut().queue(section);
+    pub fn queue(
+        &mut self,
+        section: glow_glyph::Section<'_>,
+        paragraph: &Paragraph,
+    ) {
+        self.add_fallbacks(&section.text);
+
+        let font_data = self.font_data.borrow();
+        let fallbacks = self.fallbacks();
+
+        self.draw_brush.borrow_mut().queue_custom_layout(
+            section,
+            &paragraph.layout(&font_data, &fallbacks),
+        );
     }
 
     pub fn draw_queued(
@@ -79,33 +135,170 @@ impl Pipeline {
         content: &str,
         size: f32,
         font: iced_native::Font,
+        line_height: LineHeight,
+        wrapping: Wrapping,
         bounds: iced_native::Size,
     ) -> (f32, f32) {
         use glow_glyph::GlyphCruncher;
 
-        let glow_glyph::FontId(font_id) = self.find_font(font);
+        let paragraph = Paragraph {
+            wrapping,
+            line_height: line_height.to_absolute(size),
+            ..Paragraph::new(HorizontalAlignment::Left, VerticalAlignment::Top)
+        };
+
+        let text = [glow_glyph::Text {
+            text: content,
+            scale: size.into(),
+            font_id: self.find_font(&font),
+            extra: glow_glyph::Extra::default(),
+        }];
+
+        self.add_fallbacks(&text);
+
+        let font_data = self.font_data.borrow();
+        let fallbacks = self.fallbacks();
+        let bounds = (bounds.width, bounds.height);
+
+        let (width, height) = self.measurement_cache.borrow_mut().measure(
+            &paragraph.layout(&font_data, &fallbacks),
+            bounds,
+            &text,
+            || {
+                paragraph.measure(
+                    self.measure_brush.borrow().fonts(),
+                    &font_data,
+                    &fallbacks,
+                    bounds,
+                    &text,
+                )
+            },
+        );
+
+        (width.ceil(), height.ceil())
+    }
+
+    pub fn measure_spans(&self, spans: &[Span], bounds: Size) -> (f32, f32) {
+        use glow_glyph::GlyphCruncher;
+
+        let paragraph =
+            Paragraph::new(HorizontalAlignment::Left, VerticalAlignment::Top);
+
+        let text = self.spans(spans);
+
+        self.add_fallbacks(&text);
+
+        let font_data = self.font_data.borrow();
+        let fallbacks = self.fallbacks();
+        let bounds = (bounds.width, bounds.height);
+
+        let (width, height) = self.measurement_cache.borrow_mut().measure(
+            &paragraph.layout(&font_data, &fallbacks),
+            bounds,
+            &text,
+            || {
+                paragraph.measure(
+                    self.measure_brush.borrow().fonts(),
+                    &font_data,
+                    &fallbacks,
+                    bounds,
+                    &text,
+                )
+            },
+        );
+
+        (width.ceil(), height.ceil())
+    }
+
+    pub fn clusters(
+        &self,
+        content: &str,
+        size: f32,
+        font: iced_native::Font,
+    ) -> Vec<Cluster> {
+        use glow_glyph::GlyphCruncher;
+
+        let paragraph =
+            Paragraph::new(HorizontalAlignment::Left, VerticalAlignment::Top);
+
+        let text = [glow_glyph::Text {
+            text: content,
+            scale: size.into(),
+            font_id: self.find_font(&font),
+            extra: glow_glyph::Extra::default(),
+        }];
+
+        self.add_fallbacks(&text);
+
+        paragraph.clusters(
+            self.measure_brush.borrow().fonts(),
+            &self.font_data.borrow(),
+            &self.fallbacks(),
+            &text,
+        )
+    }
+
+    pub fn hit_test(
+        &self,
+        spans: &[Span],
+        bounds: Rectangle,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+        point: Point,
+    ) -> Option<usize> {
+        use glow_glyph::ab_glyph::{Font as _, ScaleFont as _};
+        use glow_glyph::GlyphCruncher;
 
         let section = glow_glyph::Section {
+            screen_position: (bounds.x, bounds.y),
             bounds: (bounds.width, bounds.height),
-            text: vec![glow_glyph::Text {
-                text: content,
-                scale: size.into(),
-                font_id: glow_glyph::FontId(font_id),
-                extra: glow_glyph::Extra::default(),
-            }],
+            text: self.spans(spans),
             ..Default::default()
         };
 
-        if let Some(bounds) =
-            self.measure_brush.borrow_mut().glyph_bounds(section)
-        {
-            (bounds.width().ceil(), bounds.height().ceil())
-        } else {
-            (0.0, 0.0)
-        }
+        self.add_fallbacks(&section.text);
+
+        let paragraph =
+            Paragraph::new(horizontal_alignment, vertical_alignment);
+
+        let font_data = self.font_data.borrow();
+        let fallbacks = self.fallbacks();
+        let mut measure_brush = self.measure_brush.borrow_mut();
+
+        let glyphs: Vec<glow_glyph::SectionGlyph> = measure_brush
+            .glyphs_custom_layout(
+                section,
+                &paragraph.layout(&font_data, &fallbacks),
+            )
+            .cloned()
+            .collect();
+
+        let fonts = measure_brush.fonts();
+
+        glyphs
+            .iter()
+            .find(|section_glyph| {
+                let glyph = &section_glyph.glyph;
+                let font =
+                    fonts[section_glyph.font_id.0].as_scaled(glyph.scale);
+
+                // Glyphs are positioned at the left of their baseline
+                let left = glyph.position.x;
+                let right = left + font.h_advance(glyph.id);
+                let top = glyph.position.y - font.ascent();
+                let bottom = glyph.position.y - font.descent();
+
+                point.x >= left
+                    && point.x < right
+                    && point.y >= top
+                    && point.y < bottom
+            })
+            .map(|section_glyph| section_glyph.section_index)
     }
 
     pub fn trim_measurement_cache(&mut self) {
+        self.measurement_cache.get_mut().trim();
+
         // TODO: We should probably use a `GlyphCalculator` for this. However,
         // it uses a lifetimed `GlyphCalculatorGuard` with side-effects on drop.
         // This makes stuff quite inconvenient. A manual method for trimming the
@@ -129,28 +322,225 @@ impl Pipeline {
         }
     }
 
-    pub fn find_font(&self, font: iced_native::Font) -> glow_glyph::FontId {
+    /// Loads the given font bytes, using the font as a fallback and for the
+    /// descriptors matching it from now on.
+    ///
+    /// The descriptor of the font is returned.
+    pub fn load_font(
+        &mut self,
+        bytes: Cow<'static, [u8]>,
+    ) -> Result<iced_native::font::Descriptor, font::Error> {
+        use ab_glyph::Font as _;
+
+        let font = load(bytes, 0).map_err(|_| font::Error::Invalid)?;
+
+        let attributes = Attributes::parse(font.font_data(), 0)
+            .ok_or(font::Error::Invalid)?;
+        let descriptor = attributes.descriptor().ok_or(font::Error::Invalid)?;
+
+        let font_id = self.add_font(font, shaping::Data::default());
+
+        self.external_fonts.borrow_mut().push(font_id);
+
+        // The new font may match some descriptors resolved before
+        self.descriptor_map
+            .borrow_mut()
+            .retain(|descriptor, _| !attributes.matches(descriptor));
+
+        Ok(descriptor)
+    }
+
+    pub fn find_font(&self, font: &iced_native::Font) -> glow_glyph::FontId {
         match font {
             iced_native::Font::Default => glow_glyph::FontId(0),
             iced_native::Font::External { name, bytes } => {
-                if let Some(font_id) = self.draw_font_map.borrow().get(name) {
+                if let Some(font_id) = self.draw_font_map.borrow().get(*name) {
                     return *font_id;
                 }
 
-                let font = ab_glyph::FontArc::try_from_slice(bytes)
+                let font = ab_glyph::FontArc::try_from_slice(*bytes)
                     .expect("Load font");
 
-                let _ = self.measure_brush.borrow_mut().add_font(font.clone());
+                let font_id = self.add_font(font, shaping::Data::default());
 
-                let font_id = self.draw_brush.borrow_mut().add_font(font);
+                self.external_fonts.borrow_mut().push(font_id);
 
                 let _ = self
                     .draw_font_map
                     .borrow_mut()
-                    .insert(String::from(name), font_id);
+                    .insert(String::from(*name), font_id);
+
+                font_id
+            }
+            iced_native::Font::Descriptor(descriptor) => {
+                if let Some(font_id) =
+                    self.descriptor_map.borrow().get(descriptor)
+                {
+                    return *font_id;
+                }
+
+                let index = Attributes::find(
+                    &self.font_attributes.borrow(),
+                    descriptor,
+                );
+
+                let font_id = index
+                    .map(glow_glyph::FontId)
+                    .or_else(|| self.load_system_font(descriptor))
+                    .unwrap_or(glow_glyph::FontId(0));
+
+                let _ = self
+                    .descriptor_map
+                    .borrow_mut()
+                    .insert(descriptor.clone(), font_id);
 
                 font_id
             }
         }
     }
+
+    #[cfg(feature = "default_system_font")]
+    fn load_system_font(
+        &self,
+        descriptor: &iced_native::font::Descriptor,
+    ) -> Option<glow_glyph::FontId> {
+        let face = font::Source::new().load_descriptor(descriptor).ok()?;
+        let font = load(Cow::Owned(face.data), face.index).ok()?;
+
+        // Fonts loaded for a descriptor are only used when requested, never
+        // as a fallback
+        Some(self.add_font(font, shaping::Data { index: face.index }))
+    }
+
+    #[cfg(not(feature = "default_system_font"))]
+    fn load_system_font(
+        &self,
+        _descriptor: &iced_native::font::Descriptor,
+    ) -> Option<glow_glyph::FontId> {
+        None
+    }
+
+    fn add_font(
+        &self,
+        font: ab_glyph::FontArc,
+        data: shaping::Data,
+    ) -> glow_glyph::FontId {
+        use ab_glyph::Font as _;
+
+        let _ = self.measure_brush.borrow_mut().add_font(font.clone());
+        self.font_attributes
+            .borrow_mut()
+            .push(Attributes::parse(font.font_data(), data.index));
+        self.font_data.borrow_mut().push(data);
+
+        self.draw_brush.borrow_mut().add_font(font)
+    }
+
+    /// Returns the fonts used for the glyphs missing in the font of some
+    /// text, in order of priority: the default font, the fonts requested or
+    /// loaded by the application and the system fonts.
+    fn fallbacks(&self) -> Vec<glow_glyph::FontId> {
+        std::iter::once(glow_glyph::FontId(0))
+            .chain(self.external_fonts.borrow().iter().copied())
+            .chain(self.system_fonts.borrow().iter().copied())
+            .collect()
+    }
+
+    /// Loads the system fonts needed for the characters of the given text
+    /// that are missing in its fonts and in the current fallbacks.
+    ///
+    /// The fallback families are tried in order of priority, once each, and
+    /// only while some character is still missing.
+    #[cfg(feature = "default_system_font")]
+    fn add_fallbacks(&self, text: &[glow_glyph::Text<'_>]) {
+        use ab_glyph::Font as _;
+        use glow_glyph::GlyphCruncher;
+
+        if self.next_fallback.get() >= font::FALLBACK_FAMILIES.len() {
+            return;
+        }
+
+        let mut missing: Vec<char> = {
+            let measure_brush = self.measure_brush.borrow();
+            let fonts = measure_brush.fonts();
+            let fallbacks = self.fallbacks();
+
+            text.iter()
+                .flat_map(|text| {
+                    text.text.chars().map(move |c| (c, text.font_id))
+                })
+                .filter(|(c, _)| !c.is_control())
+                .filter(|(c, font_id)| {
+                    std::iter::once(font_id)
+                        .chain(fallbacks.iter())
+                        .all(|font_id| fonts[font_id.0].glyph_id(*c).0 == 0)
+                })
+                .map(|(c, _)| c)
+                .collect()
+        };
+
+        while !missing.is_empty() {
+            let family =
+                match font::FALLBACK_FAMILIES.get(self.next_fallback.get()) {
+                    Some(family) => family,
+                    None => break,
+                };
+
+            self.next_fallback.set(self.next_fallback.get() + 1);
+
+            let face = match font::Source::new()
+                .load(&[font::Family::Title(String::from(*family))])
+            {
+                Ok(face) => face,
+                Err(_) => continue,
+            };
+
+            let font = match load(Cow::Owned(face.data), face.index) {
+                Ok(font) => font,
+                Err(_) => continue,
+            };
+
+            missing.retain(|c| font.glyph_id(*c).0 == 0);
+
+            // The font is kept even if it covers none of the missing
+            // characters, since it may cover the ones of later text
+            let font_id =
+                self.add_font(font, shaping::Data { index: face.index });
+
+            self.system_fonts.borrow_mut().push(font_id);
+        }
+    }
+
+    #[cfg(not(feature = "default_system_font"))]
+    fn add_fallbacks(&self, _text: &[glow_glyph::Text<'_>]) {}
+
+    fn spans<'a>(&self, spans: &'a [Span]) -> Vec<glow_glyph::Text<'a>> {
+        spans
+            .iter()
+            .map(|span| glow_glyph::Text {
+                text: &span.content,
+                scale: span.size.into(),
+                font_id: self.find_font(&span.font),
+                extra: glow_glyph::Extra::default(),
+            })
+            .collect()
+    }
+}
+
+/// Parses the font with the given index in the given bytes, without copying
+/// them.
+fn load(
+    bytes: Cow<'static, [u8]>,
+    index: u32,
+) -> Result<ab_glyph::FontArc, ab_glyph::InvalidFont> {
+    match bytes {
+        Cow::Borrowed(bytes) => {
+            ab_glyph::FontRef::try_from_slice_and_index(bytes, index)
+                .map(ab_glyph::FontArc::new)
+        }
+        Cow::Owned(bytes) => {
+            ab_glyph::FontVec::try_from_vec_and_index(bytes, index)
+                .map(ab_glyph::FontArc::new)
+        }
+    }
 }
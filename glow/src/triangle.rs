@@ -1,5 +1,6 @@
 //! Draw meshes of triangles.
 use crate::program;
+use crate::settings;
 use crate::Transformation;
 use glow::HasContext;
 use iced_graphics::layer;
@@ -7,6 +8,8 @@ use std::marker::PhantomData;
 
 pub use iced_graphics::triangle::{Mesh2D, Vertex2D};
 
+mod msaa;
+
 const VERTEX_BUFFER_SIZE: usize = 10_000;
 const INDEX_BUFFER_SIZE: usize = 10_000;
 
@@ -18,10 +21,14 @@ pub(crate) struct Pipeline {
     indices: Buffer<u32>,
     transform_location: <glow::Context as HasContext>::UniformLocation,
     current_transform: Transformation,
+    blit: Option<msaa::Blit>,
 }
 
 impl Pipeline {
-    pub fn new(gl: &glow::Context) -> Pipeline {
+    pub fn new(
+        gl: &glow::Context,
+        antialiasing: Option<settings::Antialiasing>,
+    ) -> Pipeline {
         let program = unsafe {
             program::create(
                 gl,
@@ -103,17 +110,23 @@ impl Pipeline {
             indices,
             transform_location,
             current_transform: Transformation::identity(),
+            blit: antialiasing.map(|a| msaa::Blit::new(gl, a)),
         }
     }
 
     pub fn draw(
         &mut self,
         gl: &glow::Context,
+        target_width: u32,
         target_height: u32,
         transformation: Transformation,
         scale_factor: f32,
         meshes: &[layer::Mesh<'_>],
     ) {
+        if let Some(blit) = &mut self.blit {
+            blit.bind(gl, target_width, target_height);
+        }
+
         unsafe {
             gl.enable(glow::MULTISAMPLE);
             gl.enable(glow::SCISSOR_TEST);
@@ -217,6 +230,10 @@ impl Pipeline {
             gl.disable(glow::SCISSOR_TEST);
             gl.disable(glow::MULTISAMPLE);
         }
+
+        if let Some(blit) = &self.blit {
+            blit.draw(gl);
+        }
     }
 }
 
@@ -0,0 +1,243 @@
+use crate::program;
+use crate::settings;
+use glow::HasContext;
+
+/// Renders meshes into a multisampled framebuffer, and then resolves and
+/// blends the result into the default framebuffer.
+#[derive(Debug)]
+pub struct Blit {
+    program: <glow::Context as HasContext>::Program,
+    vertex_array: <glow::Context as HasContext>::VertexArray,
+    sample_count: u32,
+    targets: Option<Targets>,
+}
+
+impl Blit {
+    pub fn new(
+        gl: &glow::Context,
+        antialiasing: settings::Antialiasing,
+    ) -> Blit {
+        let program = unsafe {
+            program::create(
+                gl,
+                &[
+                    (glow::VERTEX_SHADER, include_str!("../shader/blit.vert")),
+                    (
+                        glow::FRAGMENT_SHADER,
+                        include_str!("../shader/blit.frag"),
+                    ),
+                ],
+            )
+        };
+
+        let texture_location =
+            unsafe { gl.get_uniform_location(program, "u_Texture") }
+                .expect("Get texture location");
+
+        unsafe {
+            gl.use_program(Some(program));
+            gl.uniform_1_i32(Some(texture_location), 0);
+            gl.use_program(None);
+        }
+
+        // The blit shader has no inputs, but a vertex array must be bound
+        // to draw anything
+        let vertex_array =
+            unsafe { gl.create_vertex_array().expect("Create vertex array") };
+
+        Blit {
+            program,
+            vertex_array,
+            sample_count: antialiasing.sample_count(),
+            targets: None,
+        }
+    }
+
+    /// Binds a cleared multisampled framebuffer of the given size, where
+    /// meshes can be drawn.
+    pub fn bind(&mut self, gl: &glow::Context, width: u32, height: u32) {
+        let is_outdated = match &self.targets {
+            Some(targets) => targets.width != width || targets.height != height,
+            None => true,
+        };
+
+        if is_outdated {
+            if let Some(targets) = self.targets.take() {
+                unsafe { targets.destroy(gl) };
+            }
+
+            self.targets = Some(unsafe {
+                Targets::new(gl, self.sample_count, width, height)
+            });
+        }
+
+        let targets = self.targets.as_ref().unwrap();
+
+        unsafe {
+            gl.bind_framebuffer(glow::FRAMEBUFFER, Some(targets.framebuffer));
+
+            gl.clear_color(0.0, 0.0, 0.0, 0.0);
+            gl.clear(glow::COLOR_BUFFER_BIT);
+
+            // Keep track of the coverage, so the result can be blended
+            // correctly into the default framebuffer
+            gl.blend_func_separate(
+                glow::SRC_ALPHA,
+                glow::ONE_MINUS_SRC_ALPHA,
+                glow::ONE,
+                glow::ONE_MINUS_SRC_ALPHA,
+            );
+        }
+    }
+
+    /// Resolves the multisampled framebuffer and blends it into the default
+    /// framebuffer.
+    pub fn draw(&self, gl: &glow::Context) {
+        let targets = match &self.targets {
+            Some(targets) => targets,
+            None => return,
+        };
+
+        let (width, height) = (targets.width as i32, targets.height as i32);
+
+        unsafe {
+            gl.bind_framebuffer(
+                glow::READ_FRAMEBUFFER,
+                Some(targets.framebuffer),
+            );
+            gl.bind_framebuffer(
+                glow::DRAW_FRAMEBUFFER,
+                Some(targets.resolve_framebuffer),
+            );
+
+            gl.blit_framebuffer(
+                0,
+                0,
+                width,
+                height,
+                0,
+                0,
+                width,
+                height,
+                glow::COLOR_BUFFER_BIT,
+                glow::NEAREST,
+            );
+
+            gl.bind_framebuffer(glow::FRAMEBUFFER, None);
+
+            // The resolved colors are already multiplied by their alpha
+            gl.blend_func(glow::ONE, glow::ONE_MINUS_SRC_ALPHA);
+
+            gl.use_program(Some(self.program));
+            gl.bind_vertex_array(Some(self.vertex_array));
+            gl.active_texture(glow::TEXTURE0);
+            gl.bind_texture(glow::TEXTURE_2D, Some(targets.texture));
+
+            gl.draw_arrays(glow::TRIANGLE_STRIP, 0, 4);
+
+            gl.bind_texture(glow::TEXTURE_2D, None);
+            gl.bind_vertex_array(None);
+            gl.use_program(None);
+
+            gl.blend_func(glow::SRC_ALPHA, glow::ONE_MINUS_SRC_ALPHA);
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Targets {
+    framebuffer: <glow::Context as HasContext>::Framebuffer,
+    renderbuffer: <glow::Context as HasContext>::Renderbuffer,
+    resolve_framebuffer: <glow::Context as HasContext>::Framebuffer,
+    texture: <glow::Context as HasContext>::Texture,
+    width: u32,
+    height: u32,
+}
+
+impl Targets {
+    unsafe fn new(
+        gl: &glow::Context,
+        sample_count: u32,
+        width: u32,
+        height: u32,
+    ) -> Targets {
+        let renderbuffer =
+            gl.create_renderbuffer().expect("Create renderbuffer");
+
+        gl.bind_renderbuffer(glow::RENDERBUFFER, Some(renderbuffer));
+        gl.renderbuffer_storage_multisample(
+            glow::RENDERBUFFER,
+            sample_count as i32,
+            glow::SRGB8_ALPHA8,
+            width as i32,
+            height as i32,
+        );
+        gl.bind_renderbuffer(glow::RENDERBUFFER, None);
+
+        let framebuffer = gl.create_framebuffer().expect("Create framebuffer");
+
+        gl.bind_framebuffer(glow::FRAMEBUFFER, Some(framebuffer));
+        gl.framebuffer_renderbuffer(
+            glow::FRAMEBUFFER,
+            glow::COLOR_ATTACHMENT0,
+            glow::RENDERBUFFER,
+            Some(renderbuffer),
+        );
+
+        let texture = gl.create_texture().expect("Create texture");
+
+        gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+        gl.tex_image_2d(
+            glow::TEXTURE_2D,
+            0,
+            glow::SRGB8_ALPHA8 as i32,
+            width as i32,
+            height as i32,
+            0,
+            glow::RGBA,
+            glow::UNSIGNED_BYTE,
+            None,
+        );
+        gl.tex_parameter_i32(
+            glow::TEXTURE_2D,
+            glow::TEXTURE_MIN_FILTER,
+            glow::NEAREST as i32,
+        );
+        gl.tex_parameter_i32(
+            glow::TEXTURE_2D,
+            glow::TEXTURE_MAG_FILTER,
+            glow::NEAREST as i32,
+        );
+        gl.bind_texture(glow::TEXTURE_2D, None);
+
+        let resolve_framebuffer =
+            gl.create_framebuffer().expect("Create framebuffer");
+
+        gl.bind_framebuffer(glow::FRAMEBUFFER, Some(resolve_framebuffer));
+        gl.framebuffer_texture_2d(
+            glow::FRAMEBUFFER,
+            glow::COLOR_ATTACHMENT0,
+            glow::TEXTURE_2D,
+            Some(texture),
+            0,
+        );
+
+        gl.bind_framebuffer(glow::FRAMEBUFFER, None);
+
+        Targets {
+            framebuffer,
+            renderbuffer,
+            resolve_framebuffer,
+            texture,
+            width,
+            height,
+        }
+    }
+
+    unsafe fn destroy(self, gl: &glow::Context) {
+        gl.delete_framebuffer(self.framebuffer);
+        gl.delete_renderbuffer(self.renderbuffer);
+        gl.delete_framebuffer(self.resolve_framebuffer);
+        gl.delete_texture(self.texture);
+    }
+}
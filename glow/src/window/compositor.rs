@@ -2,7 +2,7 @@ use crate::{Backend, Renderer, Settings, Viewport};
 
 use core::ffi::c_void;
 use glow::HasContext;
-use iced_graphics::{Antialiasing, Size};
+use iced_graphics::Size;
 use iced_native::mouse;
 
 /// A window graphics backend for iced powered by `glow`.
@@ -21,8 +21,6 @@ impl iced_graphics::window::GLCompositor for Compositor {
     ) -> (Self, Self::Renderer) {
         let gl = glow::Context::from_loader_function(loader_function);
 
-        gl.clear_color(1.0, 1.0, 1.0, 1.0);
-
         // Enable auto-conversion from/to sRGB
         gl.enable(glow::FRAMEBUFFER_SRGB);
 
@@ -38,11 +36,10 @@ impl iced_graphics::window::GLCompositor for Compositor {
         (Self { gl }, renderer)
     }
 
-    fn sample_count(settings: &Settings) -> u32 {
-        settings
-            .antialiasing
-            .map(Antialiasing::sample_count)
-            .unwrap_or(0)
+    fn sample_count(_settings: &Settings) -> u32 {
+        // Meshes are antialiased in their own multisampled framebuffer, so
+        // the default framebuffer does not need any samples
+        0
     }
 
     fn resize_viewport(&mut self, physical_size: Size<u32>) {
@@ -65,7 +62,10 @@ impl iced_graphics::window::GLCompositor for Compositor {
     ) -> mouse::Interaction {
         let gl = &self.gl;
 
+        let [r, g, b, a] = renderer.theme().palette.background.into_linear();
+
         unsafe {
+            gl.clear_color(r, g, b, a);
             gl.clear(glow::COLOR_BUFFER_BIT);
         }
 
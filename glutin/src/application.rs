@@ -1,11 +1,14 @@
 //! Create interactive, native cross-platform applications.
 use crate::{mouse, Executor, Runtime, Size};
-use iced_graphics::window;
+use iced_graphics::theme::Themed;
+use iced_graphics::window::{self, Redraw};
 use iced_graphics::Viewport;
 use iced_winit::application;
 use iced_winit::conversion;
 use iced_winit::{Clipboard, Debug, Proxy, Settings};
 
+use std::time::Instant;
+
 pub use iced_winit::Application;
 pub use iced_winit::{program, Program};
 
@@ -20,6 +23,7 @@ pub fn run<A, E, C>(
     A: Application + 'static,
     E: Executor + 'static,
     C: window::GLCompositor<Renderer = A::Renderer> + 'static,
+    A::Renderer: Themed + Redraw,
 {
     use glutin::{
         event,
@@ -40,13 +44,13 @@ pub fn run<A, E, C>(
 
     let flags = settings.flags;
     let (application, init_command) = runtime.enter(|| A::new(flags));
-    runtime.spawn(init_command);
 
     let subscription = application.subscription();
     runtime.track(subscription);
 
     let mut title = application.title();
     let mut mode = application.mode();
+    let mut theme = application.theme();
 
     let context = {
         let builder = settings.window.into_builder(
@@ -84,6 +88,11 @@ pub fn run<A, E, C>(
             context.get_proc_address(address)
         })
     };
+    renderer.set_theme(theme);
+
+    // The initial command is run once the renderer exists, so it can
+    // perform actions with it
+    runtime.spawn(init_command.perform_actions(&mut renderer));
 
     let mut state = program::State::new(
         application,
@@ -93,55 +102,104 @@ pub fn run<A, E, C>(
     );
     debug.startup_finished();
 
+    let mut next_frame: Option<Instant> = None;
+
     event_loop.run(move |event, _, control_flow| match event {
         event::Event::MainEventsCleared => {
             if state.is_queue_empty() {
                 return;
             }
 
-            let command = runtime.enter(|| {
-                state.update(
-                    clipboard.as_ref().map(|c| c as _),
-                    viewport.logical_size(),
-                    &mut renderer,
-                    &mut debug,
-                )
-            });
+            // The user interface is updated again when the theme changes,
+            // so it is redrawn with the new theme
+            loop {
+                let mut is_theme_changed = false;
+
+                let (events, command) = runtime.enter(|| {
+                    state.update(
+                        clipboard.as_ref().map(|c| c as _),
+                        viewport.logical_size(),
+                        &mut renderer,
+                        &mut debug,
+                    )
+                });
+
+                for event in events {
+                    runtime.broadcast(event);
+                }
 
-            // If the application was updated
-            if let Some(command) = command {
-                runtime.spawn(command);
+                // If the application was updated
+                if let Some(command) = command {
+                    // Actions performed with the renderer, like loading a
+                    // font, may change the layout of the widgets
+                    if command.has_actions() {
+                        state.invalidate_layout();
+                    }
 
-                let program = state.program();
+                    runtime.spawn(command.perform_actions(&mut renderer));
 
-                // Update subscriptions
-                let subscription = program.subscription();
-                runtime.track(subscription);
+                    let program = state.program();
 
-                // Update window title
-                let new_title = program.title();
+                    // Update subscriptions
+                    let subscription = program.subscription();
+                    runtime.track(subscription);
 
-                if title != new_title {
-                    context.window().set_title(&new_title);
+                    // Update window title
+                    let new_title = program.title();
 
-                    title = new_title;
-                }
+                    if title != new_title {
+                        context.window().set_title(&new_title);
+
+                        title = new_title;
+                    }
+
+                    // Update window mode
+                    let new_mode = program.mode();
+
+                    if mode != new_mode {
+                        context.window().set_fullscreen(
+                            conversion::fullscreen(
+                                context.window().current_monitor(),
+                                new_mode,
+                            ),
+                        );
+
+                        mode = new_mode;
+                    }
 
-                // Update window mode
-                let new_mode = program.mode();
+                    // Update theme
+                    let new_theme = program.theme();
 
-                if mode != new_mode {
-                    context.window().set_fullscreen(conversion::fullscreen(
-                        context.window().current_monitor(),
-                        new_mode,
-                    ));
+                    if theme != new_theme {
+                        renderer.set_theme(new_theme);
 
-                    mode = new_mode;
+                        theme = new_theme;
+                        is_theme_changed = true;
+                    }
+                }
+
+                if !is_theme_changed {
+                    break;
                 }
             }
 
+            // Keep drawing frames while a widget is animating, when it asks
+            // for them but no more often than every animation frame
+            next_frame = renderer.take_redraw_request().map(|requested| {
+                requested.max(Instant::now() + application::ANIMATION_FRAME)
+            });
+
             context.window().request_redraw();
         }
+        event::Event::NewEvents(event::StartCause::ResumeTimeReached {
+            ..
+        }) => {
+            if next_frame.take().is_some() {
+                state.queue_event(iced_native::Event::Window(
+                    iced_native::window::Event::RedrawRequested(Instant::now()),
+                ));
+            }
+        }
         event::Event::UserEvent(message) => {
             state.queue_message(message);
         }
@@ -179,9 +237,6 @@ pub fn run<A, E, C>(
 
                 mouse_interaction = new_mouse_interaction;
             }
-
-            // TODO: Handle animations!
-            // Maybe we can use `ControlFlow::WaitUntil` for this.
         }
         event::Event::WindowEvent {
             event: window_event,
@@ -202,12 +257,14 @@ pub fn run<A, E, C>(
                 viewport.scale_factor(),
                 modifiers,
             ) {
-                state.queue_event(event.clone());
-                runtime.broadcast(event);
+                state.queue_event(event);
             }
         }
         _ => {
-            *control_flow = ControlFlow::Wait;
+            *control_flow = match next_frame {
+                Some(next_frame) => ControlFlow::WaitUntil(next_frame),
+                None => ControlFlow::Wait,
+            };
         }
     })
 }
@@ -1,7 +1,16 @@
 //! Write a graphics backend.
 use iced_native::image;
 use iced_native::svg;
-use iced_native::{Font, Size};
+
+use crate::font;
+use crate::text::Cluster;
+use crate::Span;
+use iced_native::{
+    Font, HorizontalAlignment, LineHeight, Point, Rectangle, Size,
+    VerticalAlignment, Wrapping,
+};
+use std::borrow::Cow;
+use std::time::Duration;
 
 /// The graphics backend of a [`Renderer`].
 ///
@@ -25,22 +34,96 @@ pub trait Text {
     /// [`ICON_FONT`]: #associatedconst.ICON_FONt
     const CHECKMARK_ICON: char;
 
-    /// Measures the text contents with the given size and font,
-    /// returning the size of a laid out paragraph that fits in the provided
-    /// bounds.
+    /// Measures the text contents with the given size, font, line height and
+    /// wrapping strategy, returning the size of a laid out paragraph that
+    /// fits in the provided bounds.
     fn measure(
         &self,
         contents: &str,
         size: f32,
         font: Font,
+        line_height: LineHeight,
+        wrapping: Wrapping,
         bounds: Size,
     ) -> (f32, f32);
+
+    /// Measures a paragraph made of the given spans, returning the size of
+    /// the laid out paragraph that fits in the provided bounds.
+    fn measure_spans(&self, spans: &[Span], bounds: Size) -> (f32, f32);
+
+    /// Returns the index of the span found at the given point of a
+    /// paragraph positioned in the provided bounds, if any.
+    ///
+    /// The bounds are interpreted like the bounds of a [`Primitive::RichText`].
+    ///
+    /// [`Primitive::RichText`]: ../enum.Primitive.html#variant.RichText
+    fn hit_test(
+        &self,
+        spans: &[Span],
+        bounds: Rectangle,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+        point: Point,
+    ) -> Option<usize>;
+
+    /// Returns the [`Cluster`] of every glyph of the given text, laid out in
+    /// a single line, in visual order.
+    ///
+    /// By default, every character is a cluster laid out from left to right.
+    ///
+    /// [`Cluster`]: ../text/struct.Cluster.html
+    fn clusters(&self, contents: &str, size: f32, font: Font) -> Vec<Cluster> {
+        let mut x = 0.0;
+
+        contents
+            .char_indices()
+            .map(|(index, c)| {
+                let (right, _) = self.measure(
+                    &contents[..index + c.len_utf8()],
+                    size,
+                    font.clone(),
+                    LineHeight::default(),
+                    Wrapping::None,
+                    Size::INFINITY,
+                );
+
+                let cluster = Cluster {
+                    index,
+                    x,
+                    width: right - x,
+                    is_rtl: false,
+                };
+
+                x = right;
+
+                cluster
+            })
+            .collect()
+    }
+
+    /// Loads the given font bytes, making the font available to the text of
+    /// the backend, and returns its font descriptor.
+    ///
+    /// By default, fonts cannot be loaded.
+    fn load_font(
+        &mut self,
+        _bytes: Cow<'static, [u8]>,
+    ) -> Result<iced_native::font::Descriptor, font::Error> {
+        Err(font::Error::Unsupported)
+    }
 }
 
 /// A graphics backend that supports image rendering.
 pub trait Image {
     /// Returns the dimensions of the provided image.
     fn dimensions(&self, handle: &image::Handle) -> (u32, u32);
+
+    /// Returns the delays of the frames of the provided image.
+    ///
+    /// By default, every image is considered still.
+    fn frame_delays(&self, _handle: &image::Handle) -> Vec<Duration> {
+        vec![Duration::from_secs(0)]
+    }
 }
 
 /// A graphics backend that supports SVG rendering.
@@ -1,10 +1,16 @@
-//! Find system fonts or use the built-in ones.
+//! Find system fonts, load fonts at runtime or use the built-in ones.
+mod attributes;
+mod error;
+
 #[cfg(feature = "font-source")]
 mod source;
 
+pub use attributes::Attributes;
+pub use error::Error;
+
 #[cfg(feature = "font-source")]
 #[cfg_attr(docsrs, doc(cfg(feature = "font-source")))]
-pub use source::Source;
+pub use source::{Face, Source, FALLBACK_FAMILIES};
 
 #[cfg(feature = "font-source")]
 #[cfg_attr(docsrs, doc(cfg(feature = "font-source")))]
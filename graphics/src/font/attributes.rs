@@ -0,0 +1,130 @@
+use iced_native::font::{Descriptor, Family, Stretch, Style, Weight};
+
+/// The family and the attributes of a font, read from its data.
+#[derive(Debug, Clone)]
+pub struct Attributes {
+    families: Vec<String>,
+    weight: Weight,
+    style: Style,
+    stretch: Stretch,
+}
+
+impl Attributes {
+    /// Reads the [`Attributes`] of the font with the given index in the
+    /// given font data.
+    ///
+    /// [`Attributes`]: struct.Attributes.html
+    pub fn parse(data: &[u8], index: u32) -> Option<Self> {
+        let face = ttf_parser::Face::from_slice(data, index).ok()?;
+
+        // The typographic family groups every weight and style of a family,
+        // so it comes first
+        let names = |id| {
+            face.names()
+                .filter(move |name| name.name_id() == id)
+                .filter_map(|name| name.to_string())
+        };
+
+        let families = names(ttf_parser::name_id::TYPOGRAPHIC_FAMILY)
+            .chain(names(ttf_parser::name_id::FAMILY))
+            .collect();
+
+        let style = if face.is_italic() {
+            Style::Italic
+        } else if face.is_oblique() {
+            Style::Oblique
+        } else {
+            Style::Normal
+        };
+
+        let stretch = match face.width().to_number() {
+            1 => Stretch::UltraCondensed,
+            2 => Stretch::ExtraCondensed,
+            3 => Stretch::Condensed,
+            4 => Stretch::SemiCondensed,
+            6 => Stretch::SemiExpanded,
+            7 => Stretch::Expanded,
+            8 => Stretch::ExtraExpanded,
+            9 => Stretch::UltraExpanded,
+            _ => Stretch::Normal,
+        };
+
+        Some(Attributes {
+            families,
+            weight: Weight::from_number(face.weight().to_number()),
+            style,
+            stretch,
+        })
+    }
+
+    /// Returns the index of the font matching the given font descriptor
+    /// among the fonts with the given attributes.
+    ///
+    /// A font matches when one of its families is the named family of the
+    /// descriptor and its weight, style and stretch are the same.
+    pub fn find(
+        fonts: &[Option<Self>],
+        descriptor: &Descriptor,
+    ) -> Option<usize> {
+        fonts.iter().position(|attributes| {
+            attributes
+                .as_ref()
+                .map_or(false, |attributes| attributes.matches(descriptor))
+        })
+    }
+
+    /// Returns the font descriptor of the font with these [`Attributes`],
+    /// if it has a family.
+    ///
+    /// [`Attributes`]: struct.Attributes.html
+    pub fn descriptor(&self) -> Option<Descriptor> {
+        let family = self.families.first()?;
+
+        Some(
+            Descriptor::new(Family::name(family.clone()))
+                .weight(self.weight)
+                .style(self.style)
+                .stretch(self.stretch),
+        )
+    }
+
+    /// Returns whether the font with these [`Attributes`] matches the given
+    /// font descriptor.
+    ///
+    /// [`Attributes`]: struct.Attributes.html
+    pub fn matches(&self, descriptor: &Descriptor) -> bool {
+        let name = match &descriptor.family {
+            Family::Name(name) => name,
+            _ => return false,
+        };
+
+        self.weight == descriptor.weight
+            && self.style == descriptor.style
+            && self.stretch == descriptor.stretch
+            && self
+                .families
+                .iter()
+                .any(|family| family.eq_ignore_ascii_case(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn descriptor_matches_the_font() {
+        let attributes = Attributes::parse(
+            include_bytes!("../../fonts/Lato-Regular.ttf"),
+            0,
+        )
+        .expect("Parse font");
+
+        let descriptor = attributes.descriptor().expect("Read descriptor");
+
+        assert_eq!(descriptor, Descriptor::new(Family::name("Lato")));
+        assert!(attributes.matches(&descriptor));
+        assert!(attributes.matches(&Descriptor::new(Family::name("lato"))));
+        assert!(!attributes.matches(&descriptor.clone().weight(Weight::Bold)));
+    }
+}
@@ -0,0 +1,9 @@
+/// An error produced when loading a font at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The bytes are not a valid font.
+    Invalid,
+
+    /// The renderer of the application cannot load fonts.
+    Unsupported,
+}
@@ -1,4 +1,47 @@
 use crate::font::{Family, LoadError};
+use iced_native::font;
+use std::sync::Arc;
+
+/// The families of some system fonts covering scripts and symbols that are
+/// usually missing in other fonts, in order of priority.
+///
+/// Fonts without outlines, like most color emoji fonts, cannot be rendered
+/// and are not included.
+pub const FALLBACK_FAMILIES: &[&str] = &[
+    // Chinese, Japanese and Korean
+    "Noto Sans CJK SC",
+    "Source Han Sans SC",
+    "PingFang SC",
+    "Hiragino Sans",
+    "Microsoft YaHei",
+    "Yu Gothic",
+    "Malgun Gothic",
+    // Other scripts
+    "Noto Sans",
+    "Noto Sans Arabic",
+    "Noto Sans Hebrew",
+    "Noto Sans Devanagari",
+    "Arial Unicode MS",
+    "Segoe UI",
+    // Emoji and symbols
+    "Noto Emoji",
+    "Segoe UI Emoji",
+    "Segoe UI Symbol",
+    "Apple Symbols",
+    "Symbola",
+    "DejaVu Sans",
+];
+
+/// A font loaded from the system.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Face {
+    /// The data of the file containing the font.
+    pub data: Vec<u8>,
+
+    /// The index of the font in its data, which is not zero for most fonts
+    /// of a collection, like `.ttc` files.
+    pub index: u32,
+}
 
 /// A font source that can find and load system fonts.
 #[allow(missing_debug_implementations)]
@@ -19,24 +62,68 @@ impl Source {
     /// Finds and loads a font matching the set of provided family priorities.
     ///
     /// [`Source`]: struct.Source.html
-    pub fn load(&self, families: &[Family]) -> Result<Vec<u8>, LoadError> {
-        let font = self.raw.select_best_match(
-            families,
-            &font_kit::properties::Properties::default(),
-        )?;
+    pub fn load(&self, families: &[Family]) -> Result<Face, LoadError> {
+        self.select(families, &font_kit::properties::Properties::default())
+    }
+
+    /// Finds and loads the font that best matches the given font descriptor.
+    pub fn load_descriptor(
+        &self,
+        descriptor: &font::Descriptor,
+    ) -> Result<Face, LoadError> {
+        use font_kit::properties::{Properties, Stretch, Style, Weight};
+
+        let family = match &descriptor.family {
+            font::Family::Name(name) => Family::Title(name.to_string()),
+            font::Family::Serif => Family::Serif,
+            font::Family::SansSerif => Family::SansSerif,
+            font::Family::Monospace => Family::Monospace,
+            font::Family::Cursive => Family::Cursive,
+            font::Family::Fantasy => Family::Fantasy,
+        };
+
+        let properties = Properties {
+            style: match descriptor.style {
+                font::Style::Normal => Style::Normal,
+                font::Style::Italic => Style::Italic,
+                font::Style::Oblique => Style::Oblique,
+            },
+            weight: Weight(f32::from(descriptor.weight.to_number())),
+            stretch: Stretch(descriptor.stretch.to_percentage() / 100.0),
+        };
+
+        self.select(&[family], &properties)
+    }
+
+    fn select(
+        &self,
+        families: &[Family],
+        properties: &font_kit::properties::Properties,
+    ) -> Result<Face, LoadError> {
+        let font = self.raw.select_best_match(families, properties)?;
 
         match font {
-            font_kit::handle::Handle::Path { path, .. } => {
+            font_kit::handle::Handle::Path { path, font_index } => {
                 use std::io::Read;
 
-                let mut buf = Vec::new();
+                let mut data = Vec::new();
                 let mut reader = std::fs::File::open(path).expect("Read font");
-                let _ = reader.read_to_end(&mut buf);
+                let _ = reader.read_to_end(&mut data);
 
-                Ok(buf)
+                Ok(Face {
+                    data,
+                    index: font_index,
+                })
             }
-            font_kit::handle::Handle::Memory { bytes, .. } => {
-                Ok(bytes.as_ref().clone())
+            font_kit::handle::Handle::Memory { bytes, font_index } => {
+                // The data is only copied when shared
+                let data = Arc::try_unwrap(bytes)
+                    .unwrap_or_else(|bytes| bytes.as_ref().clone());
+
+                Ok(Face {
+                    data,
+                    index: font_index,
+                })
             }
         }
     }
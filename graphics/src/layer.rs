@@ -3,9 +3,11 @@ use crate::image;
 use crate::svg;
 use crate::triangle;
 use crate::{
-    Background, Font, HorizontalAlignment, Point, Primitive, Rectangle, Size,
-    Vector, VerticalAlignment, Viewport,
+    gradient, Background, Font, Gradient, HorizontalAlignment, LineHeight,
+    Overflow, Point, Primitive, Rectangle, Size, Span, Vector,
+    VerticalAlignment, Viewport, Wrapping,
 };
+use iced_native::Color;
 
 /// A group of primitives that should be clipped together.
 #[derive(Debug, Clone)]
@@ -30,6 +32,11 @@ pub struct Layer<'a> {
     /// [`Layer`]: struct.Layer.html
     pub text: Vec<Text<'a>>,
 
+    /// The rich text of the [`Layer`].
+    ///
+    /// [`Layer`]: struct.Layer.html
+    pub rich_text: Vec<RichText<'a>>,
+
     /// The images of the [`Layer`].
     ///
     /// [`Layer`]: struct.Layer.html
@@ -46,6 +53,7 @@ impl<'a> Layer<'a> {
             quads: Vec::new(),
             meshes: Vec::new(),
             text: Vec::new(),
+            rich_text: Vec::new(),
             images: Vec::new(),
         }
     }
@@ -71,9 +79,12 @@ impl<'a> Layer<'a> {
                 font: Font::Default,
                 horizontal_alignment: HorizontalAlignment::Left,
                 vertical_alignment: VerticalAlignment::Top,
+                line_height: LineHeight::default(),
+                wrapping: Wrapping::default(),
+                overflow: Overflow::default(),
             };
 
-            overlay.text.push(text);
+            overlay.text.push(text.clone());
 
             overlay.text.push(Text {
                 bounds: text.bounds + Vector::new(-1.0, -1.0),
@@ -124,6 +135,9 @@ impl<'a> Layer<'a> {
                 font,
                 horizontal_alignment,
                 vertical_alignment,
+                line_height,
+                wrapping,
+                overflow,
             } => {
                 let layer = layers.last_mut().unwrap();
 
@@ -132,7 +146,25 @@ impl<'a> Layer<'a> {
                     bounds: *bounds + translation,
                     size: *size,
                     color: color.into_linear(),
-                    font: *font,
+                    font: font.clone(),
+                    horizontal_alignment: *horizontal_alignment,
+                    vertical_alignment: *vertical_alignment,
+                    line_height: *line_height,
+                    wrapping: *wrapping,
+                    overflow: *overflow,
+                });
+            }
+            Primitive::RichText {
+                spans,
+                bounds,
+                horizontal_alignment,
+                vertical_alignment,
+            } => {
+                let layer = layers.last_mut().unwrap();
+
+                layer.rich_text.push(RichText {
+                    spans,
+                    bounds: *bounds + translation,
                     horizontal_alignment: *horizontal_alignment,
                     vertical_alignment: *vertical_alignment,
                 });
@@ -143,9 +175,27 @@ impl<'a> Layer<'a> {
                 border_radius,
                 border_width,
                 border_color,
+                shadow,
             } => {
                 let layer = layers.last_mut().unwrap();
 
+                let (colors, offsets, angle) = match background {
+                    Background::Color(color) => (
+                        [color.into_linear(); gradient::MAX_STOPS],
+                        [0.0; gradient::MAX_STOPS],
+                        0.0,
+                    ),
+                    Background::Gradient(gradient) => {
+                        let (colors, offsets) = Self::color_stops(gradient);
+
+                        let angle = match gradient {
+                            Gradient::Linear(linear) => linear.angle,
+                        };
+
+                        (colors, offsets, angle)
+                    }
+                };
+
                 // TODO: Move some of these computations to the GPU (?)
                 layer.quads.push(Quad {
                     position: [
@@ -153,12 +203,15 @@ impl<'a> Layer<'a> {
                         bounds.y + translation.y,
                     ],
                     size: [bounds.width, bounds.height],
-                    color: match background {
-                        Background::Color(color) => color.into_linear(),
-                    },
-                    border_radius: *border_radius as f32,
-                    border_width: *border_width as f32,
+                    colors,
+                    offsets,
+                    angle,
+                    border_radius: (*border_radius).into(),
+                    border_width: (*border_width).into(),
                     border_color: border_color.into_linear(),
+                    shadow_color: shadow.color.into_linear(),
+                    shadow_offset: [shadow.offset.x, shadow.offset.y],
+                    shadow_blur_radius: shadow.blur_radius,
                 });
             }
             Primitive::Mesh2D { buffers, size } => {
@@ -216,27 +269,72 @@ impl<'a> Layer<'a> {
             Primitive::Cached { cache } => {
                 Self::process_primitive(layers, translation, &cache);
             }
-            Primitive::Image { handle, bounds } => {
+            Primitive::Image {
+                handle,
+                frame,
+                filter_method,
+                bounds,
+            } => {
                 let layer = layers.last_mut().unwrap();
 
                 layer.images.push(Image::Raster {
                     handle: handle.clone(),
+                    frame: *frame,
+                    filter_method: *filter_method,
                     bounds: *bounds + translation,
                 });
             }
-            Primitive::Svg { handle, bounds } => {
+            Primitive::Svg {
+                handle,
+                color,
+                bounds,
+            } => {
                 let layer = layers.last_mut().unwrap();
 
                 layer.images.push(Image::Vector {
                     handle: handle.clone(),
+                    color: *color,
                     bounds: *bounds + translation,
                 });
             }
         }
     }
+
+    fn color_stops(
+        gradient: &Gradient,
+    ) -> ([[f32; 4]; gradient::MAX_STOPS], [f32; gradient::MAX_STOPS]) {
+        let mut colors = [[0.0; 4]; gradient::MAX_STOPS];
+        let mut offsets = [0.0; gradient::MAX_STOPS];
+        let mut count = 0;
+
+        for (i, stop) in gradient.stops().enumerate() {
+            colors[i] = stop.color.into_linear();
+            offsets[i] = stop.offset;
+            count = i + 1;
+        }
+
+        // Unused slots repeat the last stop, so they do not affect the
+        // interpolation performed by the shaders.
+        if count > 0 {
+            let (last_color, last_offset) =
+                (colors[count - 1], offsets[count - 1]);
+
+            for (color, offset) in
+                colors.iter_mut().zip(offsets.iter_mut()).skip(count)
+            {
+                *color = last_color;
+                *offset = last_offset;
+            }
+        }
+
+        (colors, offsets)
+    }
 }
 
-/// A colored rectangle with a border.
+/// A colored rectangle with a border and an optional shadow.
+///
+/// Its background is always described as a linear gradient; solid colors
+/// simply repeat the same color in every stop.
 ///
 /// This type can be directly uploaded to GPU memory.
 #[derive(Debug, Clone, Copy)]
@@ -252,25 +350,53 @@ pub struct Quad {
     /// [`Quad`]: struct.Quad.html
     pub size: [f32; 2],
 
-    /// The color of the [`Quad`], in __linear RGB__.
+    /// The colors of the background stops of the [`Quad`], in
+    /// __linear RGB__.
     ///
     /// [`Quad`]: struct.Quad.html
-    pub color: [f32; 4],
+    pub colors: [[f32; 4]; gradient::MAX_STOPS],
+
+    /// The offsets of the background stops of the [`Quad`], on [0, 1].
+    ///
+    /// [`Quad`]: struct.Quad.html
+    pub offsets: [f32; gradient::MAX_STOPS],
 
     /// The border color of the [`Quad`], in __linear RGB__.
     ///
     /// [`Quad`]: struct.Quad.html
     pub border_color: [f32; 4],
 
-    /// The border radius of the [`Quad`].
+    /// The border radius of each corner of the [`Quad`], in clockwise order
+    /// starting from the top left corner.
+    ///
+    /// [`Quad`]: struct.Quad.html
+    pub border_radius: [f32; 4],
+
+    /// The border width of each side of the [`Quad`], in clockwise order
+    /// starting from the top side.
     ///
     /// [`Quad`]: struct.Quad.html
-    pub border_radius: f32,
+    pub border_width: [f32; 4],
 
-    /// The border width of the [`Quad`].
+    /// The angle of the background gradient of the [`Quad`], in radians.
     ///
     /// [`Quad`]: struct.Quad.html
-    pub border_width: f32,
+    pub angle: f32,
+
+    /// The color of the shadow of the [`Quad`], in __linear RGB__.
+    ///
+    /// [`Quad`]: struct.Quad.html
+    pub shadow_color: [f32; 4],
+
+    /// The offset of the shadow of the [`Quad`].
+    ///
+    /// [`Quad`]: struct.Quad.html
+    pub shadow_offset: [f32; 2],
+
+    /// The blur radius of the shadow of the [`Quad`].
+    ///
+    /// [`Quad`]: struct.Quad.html
+    pub shadow_blur_radius: f32,
 }
 
 /// A mesh of triangles.
@@ -293,7 +419,7 @@ pub struct Mesh<'a> {
 }
 
 /// A paragraph of text.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct Text<'a> {
     /// The content of the [`Text`].
     ///
@@ -329,6 +455,45 @@ pub struct Text<'a> {
     ///
     /// [`Text`]: struct.Text.html
     pub vertical_alignment: VerticalAlignment,
+
+    /// The line height of the [`Text`].
+    ///
+    /// [`Text`]: struct.Text.html
+    pub line_height: LineHeight,
+
+    /// The wrapping strategy of the [`Text`].
+    ///
+    /// [`Text`]: struct.Text.html
+    pub wrapping: Wrapping,
+
+    /// The overflow strategy of the [`Text`].
+    ///
+    /// [`Text`]: struct.Text.html
+    pub overflow: Overflow,
+}
+
+/// A paragraph of text made of spans with different styles.
+#[derive(Debug, Clone, Copy)]
+pub struct RichText<'a> {
+    /// The spans of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub spans: &'a [Span],
+
+    /// The layout bounds of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub bounds: Rectangle,
+
+    /// The horizontal alignment of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub horizontal_alignment: HorizontalAlignment,
+
+    /// The vertical alignment of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub vertical_alignment: VerticalAlignment,
 }
 
 /// A raster or vector image.
@@ -339,6 +504,12 @@ pub enum Image {
         /// The handle of a raster image.
         handle: image::Handle,
 
+        /// The frame of the image to draw, if it is animated.
+        frame: usize,
+
+        /// The filtering method used to sample the image.
+        filter_method: image::FilterMethod,
+
         /// The bounds of the image.
         bounds: Rectangle,
     },
@@ -347,6 +518,9 @@ pub enum Image {
         /// The handle of a vector image.
         handle: svg::Handle,
 
+        /// The color replacing the colors of the image, if any.
+        color: Option<Color>,
+
         /// The bounds of the image.
         bounds: Rectangle,
     },
@@ -19,6 +19,8 @@ mod widget;
 pub mod backend;
 pub mod font;
 pub mod layer;
+pub mod text;
+pub mod theme;
 pub mod triangle;
 pub mod window;
 
@@ -29,12 +31,14 @@ pub use antialiasing::Antialiasing;
 pub use backend::Backend;
 pub use defaults::Defaults;
 pub use layer::Layer;
-pub use primitive::Primitive;
+pub use primitive::{Primitive, Span};
 pub use renderer::Renderer;
+pub use theme::Theme;
 pub use transformation::Transformation;
 pub use viewport::Viewport;
 
 pub use iced_native::{
-    Background, Font, HorizontalAlignment, Point, Rectangle, Size, Vector,
-    VerticalAlignment,
+    gradient, Background, BorderRadius, BorderWidth, ContentFit, Font,
+    Gradient, HorizontalAlignment, LineHeight, Overflow, Point, Rectangle,
+    Shadow, Size, Vector, VerticalAlignment, Wrapping,
 };
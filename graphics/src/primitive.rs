@@ -1,6 +1,7 @@
 use iced_native::{
-    image, svg, Background, Color, Font, HorizontalAlignment, Rectangle, Size,
-    Vector, VerticalAlignment,
+    image, svg, Background, BorderRadius, BorderWidth, Color, Font,
+    HorizontalAlignment, LineHeight, Overflow, Rectangle, Shadow, Size, Vector,
+    VerticalAlignment, Wrapping,
 };
 
 use crate::triangle;
@@ -32,6 +33,23 @@ pub enum Primitive {
         horizontal_alignment: HorizontalAlignment,
         /// The vertical alignment of the text
         vertical_alignment: VerticalAlignment,
+        /// The line height of the text
+        line_height: LineHeight,
+        /// The wrapping strategy of the text
+        wrapping: Wrapping,
+        /// The overflow strategy of the text
+        overflow: Overflow,
+    },
+    /// A paragraph of text made of spans with different styles
+    RichText {
+        /// The spans of the text
+        spans: Vec<Span>,
+        /// The bounds of the text
+        bounds: Rectangle,
+        /// The horizontal alignment of the text
+        horizontal_alignment: HorizontalAlignment,
+        /// The vertical alignment of the text
+        vertical_alignment: VerticalAlignment,
     },
     /// A quad primitive
     Quad {
@@ -39,17 +57,23 @@ pub enum Primitive {
         bounds: Rectangle,
         /// The background of the quad
         background: Background,
-        /// The border radius of the quad
-        border_radius: u16,
-        /// The border width of the quad
-        border_width: u16,
+        /// The border radius of each corner of the quad
+        border_radius: BorderRadius,
+        /// The border width of each side of the quad
+        border_width: BorderWidth,
         /// The border color of the quad
         border_color: Color,
+        /// The shadow cast by the quad
+        shadow: Shadow,
     },
     /// An image primitive
     Image {
         /// The handle of the image
         handle: image::Handle,
+        /// The frame of the image to draw, if it is animated
+        frame: usize,
+        /// The filtering method used to sample the image
+        filter_method: image::FilterMethod,
         /// The bounds of the image
         bounds: Rectangle,
     },
@@ -58,6 +82,9 @@ pub enum Primitive {
         /// The path of the SVG file
         handle: svg::Handle,
 
+        /// The color replacing the colors of the SVG, if any
+        color: Option<Color>,
+
         /// The bounds of the viewport
         bounds: Rectangle,
     },
@@ -100,6 +127,19 @@ pub enum Primitive {
     },
 }
 
+/// A piece of text with its own style, laid out as part of a paragraph.
+#[derive(Debug, Clone)]
+pub struct Span {
+    /// The contents of the span
+    pub content: String,
+    /// The color of the span
+    pub color: Color,
+    /// The size of the span
+    pub size: f32,
+    /// The font of the span
+    pub font: Font,
+}
+
 impl Default for Primitive {
     fn default() -> Primitive {
         Primitive::None
@@ -1,12 +1,18 @@
-use crate::{Backend, Defaults, Primitive};
+use crate::defaults::{self, Defaults};
+use crate::{backend, font, Backend, Primitive, Theme};
 use iced_native::layout::{self, Layout};
 use iced_native::mouse;
-use iced_native::{Background, Color, Element, Point, Widget};
+use iced_native::{Background, Color, Element, Point, Shadow, Widget};
+
+use std::borrow::Cow;
+use std::time::Instant;
 
 /// A backend-agnostic renderer that supports all the built-in widgets.
 #[derive(Debug)]
 pub struct Renderer<B: Backend> {
     backend: B,
+    theme: Theme,
+    redraw_request: Option<Instant>,
 }
 
 impl<B: Backend> Renderer<B> {
@@ -15,7 +21,11 @@ impl<B: Backend> Renderer<B> {
     /// [`Renderer`]: struct.Renderer.html
     /// [`Backend`]: backend/trait.Backend.html
     pub fn new(backend: B) -> Self {
-        Self { backend }
+        Self {
+            backend,
+            theme: Theme::default(),
+            redraw_request: None,
+        }
     }
 
     /// Returns a reference to the [`Backend`] of the [`Renderer`].
@@ -33,6 +43,65 @@ impl<B: Backend> Renderer<B> {
     pub fn backend_mut(&mut self) -> &mut B {
         &mut self.backend
     }
+
+    /// Returns the [`Theme`] the [`Renderer`] uses to draw the built-in
+    /// widgets.
+    ///
+    /// [`Theme`]: struct.Theme.html
+    /// [`Renderer`]: struct.Renderer.html
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    /// Sets the [`Theme`] the [`Renderer`] uses to draw the built-in widgets.
+    ///
+    /// [`Theme`]: struct.Theme.html
+    /// [`Renderer`]: struct.Renderer.html
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    /// Requests a new frame to be drawn at the given [`Instant`], after the
+    /// current one.
+    ///
+    /// Widgets call this while drawing to keep an animation going. If many
+    /// frames are requested, the earliest one is drawn.
+    ///
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    pub fn request_redraw(&mut self, at: Instant) {
+        self.redraw_request = Some(match self.redraw_request {
+            Some(requested) => requested.min(at),
+            None => at,
+        });
+    }
+
+    /// Returns the earliest [`Instant`] a new frame was requested at since
+    /// the last call, clearing the request.
+    ///
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    pub fn take_redraw_request(&mut self) -> Option<Instant> {
+        self.redraw_request.take()
+    }
+}
+
+impl<B> Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    /// Loads the given font bytes in the [`Backend`] of the [`Renderer`].
+    ///
+    /// Once loaded, the font can be used with the returned font descriptor,
+    /// or any other matching its family and attributes. It is also used as a
+    /// fallback for the glyphs missing in other fonts.
+    ///
+    /// [`Backend`]: backend/trait.Backend.html
+    /// [`Renderer`]: struct.Renderer.html
+    pub fn load_font(
+        &mut self,
+        bytes: Cow<'static, [u8]>,
+    ) -> Result<iced_native::font::Descriptor, font::Error> {
+        self.backend.load_font(bytes)
+    }
 }
 
 impl<B> iced_native::Renderer for Renderer<B>
@@ -42,6 +111,14 @@ where
     type Output = (Primitive, mouse::Interaction);
     type Defaults = Defaults;
 
+    fn defaults(&self) -> Defaults {
+        Defaults {
+            text: defaults::Text {
+                color: self.theme.palette.text,
+            },
+        }
+    }
+
     fn layout<'a, Message>(
         &mut self,
         element: &Element<'a, Message, Self>,
@@ -87,9 +164,10 @@ fn explain_layout(
     primitives.push(Primitive::Quad {
         bounds: layout.bounds(),
         background: Background::Color(Color::TRANSPARENT),
-        border_radius: 0,
-        border_width: 1,
+        border_radius: 0.into(),
+        border_width: 1.into(),
         border_color: [0.6, 0.6, 0.6, 0.5].into(),
+        shadow: Shadow::NONE,
     });
 
     for child in layout.children() {
@@ -0,0 +1,8 @@
+//! Shape and lay out text with the fonts of a renderer.
+pub mod shaping;
+
+mod cache;
+mod paragraph;
+
+pub use cache::Cache;
+pub use paragraph::{Cluster, Layout, Paragraph};
@@ -0,0 +1,94 @@
+use crate::text::Layout;
+use glyph_brush::ToSectionText;
+use iced_native::Hasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher as _};
+
+/// A cache of the sizes of measured paragraphs.
+///
+/// Only the measurements used since the last trim are kept.
+#[derive(Debug, Default)]
+pub struct Cache {
+    sizes: HashMap<u64, (f32, f32)>,
+    recently_used: HashSet<u64>,
+}
+
+impl Cache {
+    /// Creates a new empty [`Cache`].
+    ///
+    /// [`Cache`]: struct.Cache.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the size of the given sections laid out with the given
+    /// [`Layout`] in the given bounds, measuring them only if they were not
+    /// measured before.
+    ///
+    /// [`Layout`]: struct.Layout.html
+    pub fn measure<S: ToSectionText>(
+        &mut self,
+        layout: &Layout<'_>,
+        (width, height): (f32, f32),
+        sections: &[S],
+        measure: impl FnOnce() -> (f32, f32),
+    ) -> (f32, f32) {
+        let mut hasher = Hasher::default();
+
+        layout.hash(&mut hasher);
+        width.to_bits().hash(&mut hasher);
+        height.to_bits().hash(&mut hasher);
+
+        for section in sections {
+            let section = section.to_section_text();
+
+            section.text.hash(&mut hasher);
+            section.scale.x.to_bits().hash(&mut hasher);
+            section.scale.y.to_bits().hash(&mut hasher);
+            section.font_id.hash(&mut hasher);
+        }
+
+        let key = hasher.finish();
+        let _ = self.recently_used.insert(key);
+
+        *self.sizes.entry(key).or_insert_with(measure)
+    }
+
+    /// Drops the measurements not used since the last trim.
+    pub fn trim(&mut self) {
+        let recently_used = std::mem::take(&mut self.recently_used);
+
+        self.sizes.retain(|key, _| recently_used.contains(key));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::Paragraph;
+    use glyph_brush::Text;
+    use iced_native::{HorizontalAlignment, VerticalAlignment};
+
+    #[test]
+    fn measurements_are_kept_until_unused() {
+        let paragraph =
+            Paragraph::new(HorizontalAlignment::Left, VerticalAlignment::Top);
+        let layout = paragraph.layout(&[], &[]);
+        let sections: [Text<'_>; 1] = [Text::new("Hello")];
+
+        let mut cache = Cache::new();
+        let measure = |cache: &mut Cache, size| {
+            cache.measure(&layout, (100.0, 100.0), &sections, || size)
+        };
+
+        assert_eq!(measure(&mut cache, (1.0, 1.0)), (1.0, 1.0));
+        assert_eq!(measure(&mut cache, (2.0, 2.0)), (1.0, 1.0));
+
+        cache.trim();
+        assert_eq!(measure(&mut cache, (2.0, 2.0)), (1.0, 1.0));
+
+        cache.trim();
+        cache.trim();
+        assert_eq!(measure(&mut cache, (2.0, 2.0)), (2.0, 2.0));
+    }
+}
@@ -0,0 +1,808 @@
+use crate::text::shaping::{self, Data, Run};
+use glyph_brush::ab_glyph::{point, Font, Rect, ScaleFont};
+use glyph_brush::{
+    BuiltInLineBreaker, FontId, GlyphPositioner, LineBreak, LineBreaker,
+    SectionGeometry, SectionGlyph, ToSectionText,
+};
+use iced_native::{HorizontalAlignment, Overflow, VerticalAlignment, Wrapping};
+use std::hash::{Hash, Hasher};
+use unicode_bidi::BidiInfo;
+
+/// The tolerance used when checking whether some text fits in its bounds,
+/// absorbing the rounding performed when converting to physical pixels.
+const TOLERANCE: f32 = 0.01;
+
+/// The configuration of a paragraph of text, laid out following a
+/// [`Wrapping`] strategy, an [`Overflow`] strategy and a line height.
+///
+/// The text is shaped and reordered following the Unicode Bidirectional
+/// Algorithm before being positioned, which needs the raw font data. Thus,
+/// glyphs are positioned by the [`Layout`] of a [`Paragraph`].
+///
+/// Measuring and drawing must both use a [`Paragraph`], so they agree on
+/// the resulting layout.
+///
+/// [`Layout`]: struct.Layout.html
+#[derive(Debug, Clone, Copy)]
+pub struct Paragraph {
+    /// The horizontal alignment of the lines.
+    pub horizontal_alignment: HorizontalAlignment,
+
+    /// The vertical alignment of the lines.
+    pub vertical_alignment: VerticalAlignment,
+
+    /// The strategy used to break lines that do not fit.
+    pub wrapping: Wrapping,
+
+    /// The strategy used to truncate text that does not fit.
+    pub overflow: Overflow,
+
+    /// The line height, or `None` to use the metrics of the fonts.
+    pub line_height: Option<f32>,
+}
+
+/// The horizontal extent of a cluster of glyphs in a line of a
+/// [`Paragraph`], measured from the left of the line.
+///
+/// A cluster is the smallest piece of text shaped as a whole, like a
+/// character with its diacritics or a ligature.
+///
+/// [`Paragraph`]: struct.Paragraph.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cluster {
+    /// The byte index where the cluster starts in the whole text.
+    pub index: usize,
+
+    /// The horizontal position of the left edge of the cluster.
+    pub x: f32,
+
+    /// The width of the cluster.
+    pub width: f32,
+
+    /// Whether the cluster is part of a right-to-left run.
+    pub is_rtl: bool,
+}
+
+#[derive(Debug)]
+struct Line {
+    glyphs: Vec<SectionGlyph>,
+    clusters: Vec<Cluster>,
+    baseline: f32,
+    width: f32,
+    ascent: f32,
+    descent: f32,
+    line_gap: f32,
+}
+
+impl Paragraph {
+    /// Creates a new [`Paragraph`] with the given alignment, the default
+    /// [`Wrapping`] and [`Overflow`] and the line height of the fonts.
+    ///
+    /// [`Paragraph`]: struct.Paragraph.html
+    /// [`Wrapping`]: ../enum.Wrapping.html
+    /// [`Overflow`]: ../enum.Overflow.html
+    pub fn new(
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+    ) -> Self {
+        Paragraph {
+            horizontal_alignment,
+            vertical_alignment,
+            wrapping: Wrapping::default(),
+            overflow: Overflow::default(),
+            line_height: None,
+        }
+    }
+
+    /// Returns the size of the paragraph laid out in the given bounds.
+    pub fn measure<F, S>(
+        &self,
+        fonts: &[F],
+        data: &[Data],
+        fallbacks: &[FontId],
+        bounds: (f32, f32),
+        sections: &[S],
+    ) -> (f32, f32)
+    where
+        F: Font,
+        S: ToSectionText,
+    {
+        let lines = self.lines(fonts, data, fallbacks, bounds, sections, false);
+
+        let width = lines.iter().map(|line| line.width).fold(0.0, f32::max);
+
+        (width, self.height(&lines))
+    }
+
+    /// Returns the [`Cluster`] of every glyph in the first line of the
+    /// paragraph, in visual order.
+    ///
+    /// The text is laid out in a single line, so this is useful to place a
+    /// caret or a selection in a text input.
+    ///
+    /// [`Cluster`]: struct.Cluster.html
+    pub fn clusters<F, S>(
+        &self,
+        fonts: &[F],
+        data: &[Data],
+        fallbacks: &[FontId],
+        sections: &[S],
+    ) -> Vec<Cluster>
+    where
+        F: Font,
+        S: ToSectionText,
+    {
+        let paragraph = Paragraph {
+            wrapping: Wrapping::None,
+            ..*self
+        };
+
+        paragraph
+            .lines(
+                fonts,
+                data,
+                fallbacks,
+                (f32::INFINITY, f32::INFINITY),
+                sections,
+                true,
+            )
+            .into_iter()
+            .next()
+            .map(|line| line.clusters)
+            .unwrap_or_default()
+    }
+
+    fn line_height(&self, line: &Line) -> f32 {
+        self.line_height
+            .unwrap_or(line.ascent - line.descent + line.line_gap)
+    }
+
+    fn height(&self, lines: &[Line]) -> f32 {
+        let height: f32 = lines.iter().map(|line| self.line_height(line)).sum();
+
+        // The natural spacing does not add a gap after the last line
+        match (self.line_height, lines.last()) {
+            (None, Some(last)) => height - last.line_gap,
+            _ => height,
+        }
+    }
+
+    /// Returns a [`Layout`] of this [`Paragraph`] that shapes text with the
+    /// given font data and fallback fonts.
+    ///
+    /// [`Layout`]: struct.Layout.html
+    /// [`Paragraph`]: struct.Paragraph.html
+    pub fn layout<'a>(
+        &'a self,
+        data: &'a [Data],
+        fallbacks: &'a [FontId],
+    ) -> Layout<'a> {
+        Layout {
+            paragraph: self,
+            data,
+            fallbacks,
+        }
+    }
+
+    /// Breaks the given sections into lines that fit in the given bounds,
+    /// relative to the top left corner of the paragraph.
+    ///
+    /// The text is shaped and every line is reordered visually following
+    /// the Unicode Bidirectional Algorithm. The trailing whitespace of the
+    /// lines is dropped, unless it is kept explicitly.
+    fn lines<F, S>(
+        &self,
+        fonts: &[F],
+        data: &[Data],
+        fallbacks: &[FontId],
+        (width, height): (f32, f32),
+        sections: &[S],
+        keep_whitespace: bool,
+    ) -> Vec<Line>
+    where
+        F: Font,
+        S: ToSectionText,
+    {
+        let (line_breaker, max_width) = match self.wrapping {
+            Wrapping::Word => (BuiltInLineBreaker::UnicodeLineBreaker, width),
+            Wrapping::Glyph => (BuiltInLineBreaker::AnyCharLineBreaker, width),
+            Wrapping::None => {
+                (BuiltInLineBreaker::UnicodeLineBreaker, f32::INFINITY)
+            }
+        };
+
+        let text: String = sections
+            .iter()
+            .map(|section| section.to_section_text().text)
+            .collect();
+
+        let bidi = BidiInfo::new(&text, None);
+        let runs =
+            shaping::shape(fonts, data, fallbacks, sections, &text, &bidi);
+
+        let breaks: Vec<LineBreak> = line_breaker.line_breaks(&text).collect();
+
+        // The byte index where every section starts in the whole text
+        let starts: Vec<usize> = sections
+            .iter()
+            .scan(0, |start, section| {
+                let current = *start;
+                *start += section.to_section_text().text.len();
+
+                Some(current)
+            })
+            .collect();
+
+        let section_at = |index: usize| {
+            let section_index = starts
+                .iter()
+                .rposition(|start| *start <= index)
+                .unwrap_or(0);
+
+            sections[section_index].to_section_text()
+        };
+
+        let character = |glyph: &SectionGlyph| {
+            sections[glyph.section_index].to_section_text().text
+                [glyph.byte_index..]
+                .chars()
+                .next()
+        };
+
+        let mut lines: Vec<Line> = Vec::new();
+
+        // Runs never cross paragraphs, and both runs and line breaks are in
+        // logical order, so they are walked once along the paragraphs
+        let mut run_end = 0;
+        let mut break_end = 0;
+
+        for paragraph in &bidi.paragraphs {
+            let run_start = run_end;
+
+            while runs
+                .get(run_end)
+                .map_or(false, |run| run.range.end <= paragraph.range.end)
+            {
+                run_end += 1;
+            }
+
+            let runs = &runs[run_start..run_end];
+
+            while breaks.get(break_end).map_or(false, |line_break| {
+                line_break.offset() <= paragraph.range.start
+            }) {
+                break_end += 1;
+            }
+
+            let break_start = break_end;
+
+            while breaks.get(break_end).map_or(false, |line_break| {
+                line_break.offset() < paragraph.range.end
+            }) {
+                break_end += 1;
+            }
+
+            // The advance of every cluster, in logical order
+            let mut clusters: Vec<(usize, f32)> = runs
+                .iter()
+                .flat_map(|run| run.glyphs.iter())
+                .map(|glyph| (glyph.cluster, glyph.advance))
+                .collect();
+
+            clusters.sort_by_key(|(cluster, _)| *cluster);
+            clusters.dedup_by(|(cluster, advance), (previous, total)| {
+                if cluster == previous {
+                    *total += *advance;
+                    true
+                } else {
+                    false
+                }
+            });
+
+            let mut ranges = Vec::new();
+            let mut start = paragraph.range.start;
+            let mut candidate: Option<(usize, f32)> = None;
+            let mut x = 0.0;
+
+            let breaks: Vec<&LineBreak> = breaks[break_start..break_end]
+                .iter()
+                .filter(|line_break| {
+                    self.wrapping != Wrapping::None
+                        || matches!(line_break, LineBreak::Hard(_))
+                })
+                .collect();
+
+            let mut next_break = 0;
+
+            for (cluster, advance) in clusters {
+                while let Some(line_break) = breaks
+                    .get(next_break)
+                    .filter(|line_break| line_break.offset() <= cluster)
+                {
+                    next_break += 1;
+
+                    match line_break {
+                        LineBreak::Hard(offset) => {
+                            ranges.push(start..*offset);
+                            start = *offset;
+                            candidate = None;
+                            x = 0.0;
+                        }
+                        LineBreak::Soft(offset) if *offset > start => {
+                            candidate = Some((*offset, x));
+                        }
+                        LineBreak::Soft(_) => {}
+                    }
+                }
+
+                let is_whitespace = text[cluster..]
+                    .chars()
+                    .next()
+                    .map_or(false, char::is_whitespace);
+
+                if !is_whitespace && x + advance > max_width + TOLERANCE {
+                    if let Some((offset, candidate_x)) = candidate.take() {
+                        ranges.push(start..offset);
+                        start = offset;
+                        x -= candidate_x;
+                    }
+                }
+
+                x += advance;
+            }
+
+            ranges.push(start..paragraph.range.end);
+
+            for range in ranges {
+                // Trailing whitespace is not visible, but a caret can be
+                // placed after it
+                let range = if keep_whitespace {
+                    range
+                } else {
+                    range.start..range.start + text[range].trim_end().len()
+                };
+
+                let section = section_at(range.start);
+                let font = fonts[section.font_id.0].as_scaled(section.scale);
+
+                let mut line = Line {
+                    glyphs: Vec::new(),
+                    clusters: Vec::new(),
+                    baseline: 0.0,
+                    width: 0.0,
+                    ascent: font.ascent(),
+                    descent: font.descent(),
+                    line_gap: font.line_gap(),
+                };
+
+                if range.is_empty() {
+                    lines.push(line);
+                    continue;
+                }
+
+                let (levels, visual_runs) =
+                    bidi.visual_runs(paragraph, range.clone());
+
+                for visual_run in visual_runs {
+                    let is_rtl = levels[visual_run.start].is_rtl();
+
+                    let mut overlapping: Vec<&Run> = runs
+                        .iter()
+                        .filter(|run| {
+                            run.range.start < visual_run.end
+                                && visual_run.start < run.range.end
+                        })
+                        .collect();
+
+                    if is_rtl {
+                        overlapping.reverse();
+                    }
+
+                    let glyphs = overlapping
+                        .into_iter()
+                        .flat_map(|run| run.glyphs.iter())
+                        .filter(|glyph| visual_run.contains(&glyph.cluster));
+
+                    for glyph in glyphs {
+                        let font =
+                            fonts[glyph.font_id.0].as_scaled(glyph.scale);
+
+                        line.glyphs.push(SectionGlyph {
+                            section_index: glyph.section_index,
+                            byte_index: glyph.cluster
+                                - starts[glyph.section_index],
+                            glyph: glyph.id.with_scale_and_position(
+                                glyph.scale,
+                                point(
+                                    line.width + glyph.x_offset,
+                                    -glyph.y_offset,
+                                ),
+                            ),
+                            font_id: glyph.font_id,
+                        });
+
+                        // The glyphs of a cluster are contiguous
+                        match line.clusters.last_mut() {
+                            Some(cluster) if cluster.index == glyph.cluster => {
+                                cluster.width += glyph.advance;
+                            }
+                            _ => line.clusters.push(Cluster {
+                                index: glyph.cluster,
+                                x: line.width,
+                                width: glyph.advance,
+                                is_rtl,
+                            }),
+                        }
+
+                        line.width += glyph.advance;
+                        line.ascent = line.ascent.max(font.ascent());
+                        line.descent = line.descent.min(font.descent());
+                        line.line_gap = line.line_gap.max(font.line_gap());
+                    }
+                }
+
+                lines.push(line);
+            }
+        }
+
+        // Drop the lines past the bounds, keeping at least one
+        let mut top = 0.0;
+        let mut visible = 0;
+
+        for line in &lines {
+            let bottom = top + self.line_height(line);
+
+            let fits = match self.overflow {
+                Overflow::Clip => top < height,
+                Overflow::Ellipsis => bottom <= height + TOLERANCE,
+            };
+
+            if visible > 0 && !fits {
+                break;
+            }
+
+            top = bottom;
+            visible += 1;
+        }
+
+        let is_truncated = visible < lines.len();
+        lines.truncate(visible);
+
+        // Checked before dropping any glyph, as the line may be cut right
+        // between two glyphs
+        let overflows = lines
+            .last()
+            .map_or(false, |line| line.width > width + TOLERANCE);
+
+        // Drop the glyphs past the bounds
+        for line in lines.iter_mut() {
+            line.glyphs.retain(|glyph| glyph.glyph.position.x < width);
+            line.width = line.width.min(width);
+        }
+
+        if self.overflow == Overflow::Ellipsis {
+            if let Some(line) = lines.last_mut() {
+                if is_truncated || overflows {
+                    ellipsize(line, fonts, width, character);
+                }
+            }
+        }
+
+        lines
+    }
+}
+
+/// Replaces the end of the given line with an ellipsis, so it fits in the
+/// given width.
+fn ellipsize<F: Font>(
+    line: &mut Line,
+    fonts: &[F],
+    width: f32,
+    character: impl Fn(&SectionGlyph) -> Option<char>,
+) {
+    let last = match line.glyphs.last() {
+        Some(last) => last.clone(),
+        None => return,
+    };
+
+    let font = &fonts[last.font_id.0];
+    let scaled = font.as_scaled(last.glyph.scale);
+
+    // Fall back to three periods if the font has no ellipsis glyph
+    let ellipsis = if font.glyph_id('…').0 != 0 {
+        "…"
+    } else {
+        "..."
+    };
+
+    let ellipsis_width: f32 = ellipsis
+        .chars()
+        .map(|c| scaled.h_advance(font.glyph_id(c)))
+        .sum();
+
+    let right = |glyph: &SectionGlyph| {
+        let font = fonts[glyph.font_id.0].as_scaled(glyph.glyph.scale);
+
+        glyph.glyph.position.x + font.h_advance(glyph.glyph.id)
+    };
+
+    while let Some(glyph) = line.glyphs.last() {
+        let is_whitespace = character(glyph).map_or(false, char::is_whitespace);
+
+        if !is_whitespace && right(glyph) + ellipsis_width <= width + TOLERANCE
+        {
+            break;
+        }
+
+        let _ = line.glyphs.pop();
+    }
+
+    let mut x = line.glyphs.last().map(right).unwrap_or(0.0);
+
+    for c in ellipsis.chars() {
+        let glyph = scaled.scaled_glyph(c);
+        let advance = scaled.h_advance(glyph.id);
+
+        line.glyphs.push(SectionGlyph {
+            glyph: glyph_brush::ab_glyph::Glyph {
+                position: point(x, line.baseline),
+                ..glyph
+            },
+            ..last.clone()
+        });
+
+        x += advance;
+    }
+
+    line.width = x;
+}
+
+/// A [`Paragraph`] together with the font data and the fallback fonts
+/// needed to shape its text.
+///
+/// [`Paragraph`]: struct.Paragraph.html
+#[derive(Debug, Clone, Copy)]
+pub struct Layout<'a> {
+    paragraph: &'a Paragraph,
+    data: &'a [Data],
+    fallbacks: &'a [FontId],
+}
+
+impl GlyphPositioner for Layout<'_> {
+    fn calculate_glyphs<F, S>(
+        &self,
+        fonts: &[F],
+        geometry: &SectionGeometry,
+        sections: &[S],
+    ) -> Vec<SectionGlyph>
+    where
+        F: Font,
+        S: ToSectionText,
+    {
+        let paragraph = self.paragraph;
+        let lines = paragraph.lines(
+            fonts,
+            self.data,
+            self.fallbacks,
+            geometry.bounds,
+            sections,
+            false,
+        );
+        let (x, y) = geometry.screen_position;
+
+        let mut top = match paragraph.vertical_alignment {
+            VerticalAlignment::Top => y,
+            VerticalAlignment::Center => y - paragraph.height(&lines) / 2.0,
+            VerticalAlignment::Bottom => y - paragraph.height(&lines),
+        };
+
+        let mut glyphs = Vec::new();
+
+        for line in lines {
+            let line_height = paragraph.line_height(&line);
+
+            // Custom line heights center the glyphs vertically in the line
+            let baseline = top
+                + line.ascent
+                + paragraph
+                    .line_height
+                    .map(|height| (height - (line.ascent - line.descent)) / 2.0)
+                    .unwrap_or(0.0);
+
+            let left = match paragraph.horizontal_alignment {
+                HorizontalAlignment::Left => x,
+                HorizontalAlignment::Center => x - line.width / 2.0,
+                HorizontalAlignment::Right => x - line.width,
+            };
+
+            for mut glyph in line.glyphs {
+                glyph.glyph.position.x += left;
+                glyph.glyph.position.y += baseline - line.baseline;
+
+                glyphs.push(glyph);
+            }
+
+            top += line_height;
+        }
+
+        glyphs
+    }
+
+    fn bounds_rect(&self, geometry: &SectionGeometry) -> Rect {
+        let (x, y) = geometry.screen_position;
+        let (width, height) = geometry.bounds;
+
+        let (left, right) = match self.paragraph.horizontal_alignment {
+            HorizontalAlignment::Left => (x, x + width),
+            HorizontalAlignment::Center => (x - width / 2.0, x + width / 2.0),
+            HorizontalAlignment::Right => (x - width, x),
+        };
+
+        let (top, bottom) = match self.paragraph.vertical_alignment {
+            VerticalAlignment::Top => (y, y + height),
+            VerticalAlignment::Center => (y - height / 2.0, y + height / 2.0),
+            VerticalAlignment::Bottom => (y - height, y),
+        };
+
+        Rect {
+            min: point(left, top),
+            max: point(right, bottom),
+        }
+    }
+}
+
+impl Hash for Layout<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Fonts are never removed, so the data behind a font id never changes
+        // and the fallback fonts only change when new ones are added
+        let paragraph = self.paragraph;
+
+        self.fallbacks.len().hash(state);
+
+        paragraph.horizontal_alignment.hash(state);
+        paragraph.vertical_alignment.hash(state);
+        paragraph.wrapping.hash(state);
+        paragraph.overflow.hash(state);
+        paragraph.line_height.map(f32::to_bits).hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glyph_brush::ab_glyph::FontArc;
+    use glyph_brush::Text;
+
+    const SIZE: f32 = 20.0;
+
+    fn font() -> FontArc {
+        FontArc::try_from_slice(include_bytes!("../../fonts/Lato-Regular.ttf"))
+            .expect("Load font")
+    }
+
+    fn section(content: &str) -> Text<'_> {
+        Text::new(content).with_scale(SIZE)
+    }
+
+    fn paragraph(wrapping: Wrapping, overflow: Overflow) -> Paragraph {
+        Paragraph {
+            wrapping,
+            overflow,
+            ..Paragraph::new(HorizontalAlignment::Left, VerticalAlignment::Top)
+        }
+    }
+
+    fn lines(
+        paragraph: &Paragraph,
+        content: &str,
+        bounds: (f32, f32),
+    ) -> Vec<Line> {
+        paragraph.lines(
+            &[font()],
+            &[Data::default()],
+            &[],
+            bounds,
+            &[section(content)],
+            false,
+        )
+    }
+
+    fn width(content: &str) -> f32 {
+        let paragraph = paragraph(Wrapping::None, Overflow::Clip);
+
+        paragraph
+            .measure(
+                &[font()],
+                &[Data::default()],
+                &[],
+                (f32::INFINITY, f32::INFINITY),
+                &[section(content)],
+            )
+            .0
+    }
+
+    #[test]
+    fn words_are_wrapped_in_the_bounds() {
+        let paragraph = paragraph(Wrapping::Word, Overflow::Clip);
+        let bounds = (width("hello") + 1.0, f32::INFINITY);
+
+        let lines = lines(&paragraph, "hello world", bounds);
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].glyphs.len(), 5);
+        assert_eq!(lines[1].glyphs.len(), 5);
+        assert!(lines.iter().all(|line| line.width <= bounds.0));
+    }
+
+    #[test]
+    fn lines_are_only_broken_explicitly_without_wrapping() {
+        let paragraph = paragraph(Wrapping::None, Overflow::Clip);
+        let bounds = (f32::INFINITY, f32::INFINITY);
+
+        assert_eq!(lines(&paragraph, "hello world", bounds).len(), 1);
+        assert_eq!(lines(&paragraph, "hello\nworld", bounds).len(), 2);
+        assert_eq!(lines(&paragraph, "a\nb\nc\nd", bounds).len(), 4);
+    }
+
+    #[test]
+    fn trailing_whitespace_is_not_measured() {
+        assert_eq!(width("hello  "), width("hello"));
+    }
+
+    #[test]
+    fn clusters_keep_trailing_whitespace() {
+        let paragraph = paragraph(Wrapping::Word, Overflow::Clip);
+
+        let clusters = paragraph.clusters(
+            &[font()],
+            &[Data::default()],
+            &[],
+            &[section("ab ")],
+        );
+
+        assert_eq!(
+            clusters
+                .iter()
+                .map(|cluster| cluster.index)
+                .collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+        assert!(clusters[2].width > 0.0);
+        assert_eq!(clusters[1].x, clusters[0].x + clusters[0].width);
+    }
+
+    #[test]
+    fn hidden_lines_are_replaced_with_an_ellipsis() {
+        let paragraph = paragraph(Wrapping::Word, Overflow::Ellipsis);
+        let line_height = lines(&paragraph, "hello", (100.0, 100.0))
+            .first()
+            .map(|line| paragraph.line_height(line))
+            .expect("Lay out line");
+
+        let bounds = (width("hello world"), line_height);
+        let lines = lines(&paragraph, "hello world hello world", bounds);
+
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].width <= bounds.0 + TOLERANCE);
+        assert_eq!(
+            lines[0].glyphs.last().map(|glyph| glyph.glyph.id),
+            Some(font().glyph_id('…'))
+        );
+    }
+
+    #[test]
+    fn overflowing_lines_are_ellipsized() {
+        let paragraph = paragraph(Wrapping::None, Overflow::Ellipsis);
+        let bounds = (width("hello"), f32::INFINITY);
+
+        let lines = lines(&paragraph, "hello world", bounds);
+
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].width <= bounds.0 + TOLERANCE);
+        assert!(lines[0].glyphs.len() < "hello world".len());
+        assert_eq!(
+            lines[0].glyphs.last().map(|glyph| glyph.glyph.id),
+            Some(font().glyph_id('…'))
+        );
+    }
+}
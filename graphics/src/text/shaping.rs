@@ -0,0 +1,349 @@
+//! Shape text and reorder it following the Unicode Bidirectional Algorithm.
+use glyph_brush::ab_glyph::{Font, GlyphId, PxScale, ScaleFont};
+use glyph_brush::{FontId, ToSectionText};
+use std::iter::Peekable;
+use std::ops::Range;
+use unicode_bidi::{BidiInfo, Level};
+
+/// What is needed to shape text with a font, besides the font itself.
+///
+/// The raw data of a font is read from the font, so it is never copied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Data {
+    /// The index of the font in its data, which is not zero for most fonts
+    /// of a collection, like `.ttc` files.
+    pub index: u32,
+}
+
+/// A glyph produced by shaping a [`Run`].
+///
+/// [`Run`]: struct.Run.html
+#[derive(Debug, Clone, Copy)]
+pub struct Glyph {
+    /// The id of the glyph in its font.
+    pub id: GlyphId,
+
+    /// The index of the section of the glyph.
+    pub section_index: usize,
+
+    /// The font of the glyph, which may be a fallback font.
+    pub font_id: FontId,
+
+    /// The scale of the glyph.
+    pub scale: PxScale,
+
+    /// The byte index of the cluster of the glyph in the whole text.
+    pub cluster: usize,
+
+    /// The horizontal advance of the glyph.
+    pub advance: f32,
+
+    /// The horizontal offset of the glyph from its pen position.
+    pub x_offset: f32,
+
+    /// The vertical offset of the glyph from its pen position.
+    pub y_offset: f32,
+}
+
+/// A run of text sharing the same section and embedding level, shaped as
+/// a whole.
+#[derive(Debug)]
+pub struct Run {
+    /// The byte range of the run in the whole text.
+    pub range: Range<usize>,
+
+    /// The embedding level of the run.
+    pub level: Level,
+
+    /// The glyphs of the run, in visual order.
+    pub glyphs: Vec<Glyph>,
+}
+
+/// Shapes the given sections, splitting them in runs at every section and
+/// embedding level boundary.
+///
+/// The glyphs missing in the font of a section are looked up in the given
+/// fallback fonts, in order.
+///
+/// The runs are returned in logical order and the byte indices of their
+/// glyphs refer to the given text, which must be the concatenation of the
+/// sections.
+pub fn shape<F, S>(
+    fonts: &[F],
+    data: &[Data],
+    fallbacks: &[FontId],
+    sections: &[S],
+    text: &str,
+    bidi: &BidiInfo<'_>,
+) -> Vec<Run>
+where
+    F: Font,
+    S: ToSectionText,
+{
+    let mut runs = Vec::new();
+    let mut start = 0;
+
+    let mut faces = Faces::new(fonts, data);
+    let mut paragraphs = bidi
+        .paragraphs
+        .iter()
+        .map(|paragraph| paragraph.range.start)
+        .peekable();
+
+    for (section_index, section) in sections.iter().enumerate() {
+        let section = section.to_section_text();
+        let end = start + section.text.len();
+
+        let chain: Vec<FontId> = std::iter::once(section.font_id)
+            .chain(
+                fallbacks
+                    .iter()
+                    .copied()
+                    .filter(|font_id| *font_id != section.font_id),
+            )
+            .collect();
+
+        for range in level_runs(bidi, &mut paragraphs, start..end) {
+            let level = bidi.levels[range.start];
+
+            let glyphs = shape_run(
+                fonts,
+                &mut faces,
+                &chain,
+                text,
+                range.clone(),
+                level,
+                section_index,
+                section.scale,
+            );
+
+            runs.push(Run {
+                range,
+                level,
+                glyphs,
+            });
+        }
+
+        start = end;
+    }
+
+    runs
+}
+
+/// The faces of the fonts, parsed once per font when first needed.
+struct Faces<'a, F> {
+    fonts: &'a [F],
+    data: &'a [Data],
+    parsed: Vec<Option<Option<rustybuzz::Face<'a>>>>,
+}
+
+impl<'a, F: Font> Faces<'a, F> {
+    fn new(fonts: &'a [F], data: &'a [Data]) -> Self {
+        Faces {
+            fonts,
+            data,
+            parsed: fonts.iter().map(|_| None).collect(),
+        }
+    }
+
+    fn get(&mut self, font_id: FontId) -> Option<&rustybuzz::Face<'a>> {
+        let fonts = self.fonts;
+        let data = self.data;
+
+        self.parsed[font_id.0]
+            .get_or_insert_with(|| {
+                data.get(font_id.0).and_then(|data| {
+                    rustybuzz::Face::from_slice(
+                        fonts[font_id.0].font_data(),
+                        data.index,
+                    )
+                })
+            })
+            .as_ref()
+    }
+}
+
+/// Splits the given range of text where its embedding level changes or a
+/// new paragraph starts.
+///
+/// The given starts of the paragraphs are consumed up to the end of the
+/// range, so consecutive ranges must be given in order.
+fn level_runs(
+    bidi: &BidiInfo<'_>,
+    paragraphs: &mut Peekable<impl Iterator<Item = usize>>,
+    range: Range<usize>,
+) -> Vec<Range<usize>> {
+    let mut runs = Vec::new();
+    let mut start = range.start;
+
+    while paragraphs
+        .peek()
+        .map_or(false, |start| *start <= range.start)
+    {
+        let _ = paragraphs.next();
+    }
+
+    for (i, _) in bidi.text[range.clone()].char_indices().skip(1) {
+        let i = range.start + i;
+
+        let is_new_paragraph = paragraphs.peek() == Some(&i);
+
+        if is_new_paragraph {
+            let _ = paragraphs.next();
+        }
+
+        if bidi.levels[i] != bidi.levels[start] || is_new_paragraph {
+            runs.push(start..i);
+            start = i;
+        }
+    }
+
+    if start < range.end {
+        runs.push(start..range.end);
+    }
+
+    runs
+}
+
+/// Shapes a run of text with a single direction, using the first font of
+/// the given chain and falling back to the next ones for the missing glyphs.
+///
+/// The glyphs are returned in visual order.
+fn shape_run<'a, F: Font>(
+    fonts: &'a [F],
+    faces: &mut Faces<'a, F>,
+    chain: &[FontId],
+    text: &str,
+    range: Range<usize>,
+    level: Level,
+    section_index: usize,
+    scale: PxScale,
+) -> Vec<Glyph> {
+    let font_id = chain[0];
+    let font = &fonts[font_id.0];
+    let run = &text[range.clone()];
+
+    // The id, cluster, advance and offsets of every glyph, in font units
+    let shaped: Vec<(GlyphId, usize, f32, f32, f32)> = match faces.get(font_id)
+    {
+        Some(face) => {
+            let mut buffer = rustybuzz::UnicodeBuffer::new();
+            buffer.push_str(run);
+            buffer.set_direction(if level.is_rtl() {
+                rustybuzz::Direction::RightToLeft
+            } else {
+                rustybuzz::Direction::LeftToRight
+            });
+            buffer.guess_segment_properties();
+
+            let output = rustybuzz::shape(face, &[], buffer);
+
+            output
+                .glyph_infos()
+                .iter()
+                .zip(output.glyph_positions())
+                .map(|(info, position)| {
+                    (
+                        GlyphId(info.codepoint as u16),
+                        info.cluster as usize,
+                        position.x_advance as f32,
+                        position.x_offset as f32,
+                        position.y_offset as f32,
+                    )
+                })
+                .collect()
+        }
+        None => {
+            // Without a face to shape with, we map every character to a
+            // glyph directly
+            let mut shaped: Vec<_> = run
+                .char_indices()
+                .map(|(cluster, c)| {
+                    let id = font.glyph_id(c);
+
+                    (id, cluster, font.h_advance_unscaled(id), 0.0, 0.0)
+                })
+                .collect();
+
+            if level.is_rtl() {
+                shaped.reverse();
+            }
+
+            shaped
+        }
+    };
+
+    let scaled = font.as_scaled(scale);
+    let (h_factor, v_factor) =
+        (scaled.h_scale_factor(), scaled.v_scale_factor());
+
+    let mut glyphs: Vec<Glyph> = shaped
+        .into_iter()
+        // Control characters are never drawn
+        .filter(|(_, cluster, ..)| {
+            !run[*cluster..]
+                .chars()
+                .next()
+                .map_or(true, char::is_control)
+        })
+        .map(|(id, cluster, advance, x_offset, y_offset)| Glyph {
+            id,
+            section_index,
+            font_id,
+            scale,
+            cluster: range.start + cluster,
+            advance: advance * h_factor,
+            x_offset: x_offset * h_factor,
+            y_offset: y_offset * v_factor,
+        })
+        .collect();
+
+    if chain.len() == 1 {
+        return glyphs;
+    }
+
+    // Every sequence of missing glyphs is shaped again with the next font
+    let mut i = 0;
+
+    while i < glyphs.len() {
+        if glyphs[i].id.0 != 0 {
+            i += 1;
+            continue;
+        }
+
+        let end = glyphs[i..]
+            .iter()
+            .position(|glyph| glyph.id.0 != 0)
+            .map_or(glyphs.len(), |length| i + length);
+
+        let clusters = glyphs[i..end].iter().map(|glyph| glyph.cluster);
+        let first = clusters.clone().min().unwrap_or(range.start);
+        let last = clusters.max().unwrap_or(range.start);
+
+        // The missing text ends where the next cluster starts
+        let next = glyphs
+            .iter()
+            .map(|glyph| glyph.cluster)
+            .filter(|cluster| *cluster > last)
+            .min()
+            .unwrap_or(range.end);
+
+        let fallback = shape_run(
+            fonts,
+            faces,
+            &chain[1..],
+            text,
+            first..next,
+            level,
+            section_index,
+            scale,
+        );
+
+        let length = fallback.len();
+        let _ = glyphs.splice(i..end, fallback);
+
+        i += length;
+    }
+
+    glyphs
+}
@@ -0,0 +1,26 @@
+//! Choose the colors of the built-in widgets.
+use crate::{Backend, Renderer};
+
+pub use iced_style::theme::{Palette, Theme};
+
+/// A renderer that draws the built-in widgets with a [`Theme`].
+///
+/// Shells use this trait to keep the [`Theme`] of an application in sync with
+/// its renderer.
+///
+/// [`Theme`]: struct.Theme.html
+pub trait Themed {
+    /// Sets the [`Theme`] used to draw the built-in widgets.
+    ///
+    /// [`Theme`]: struct.Theme.html
+    fn set_theme(&mut self, theme: Theme);
+}
+
+impl<B> Themed for Renderer<B>
+where
+    B: Backend,
+{
+    fn set_theme(&mut self, theme: Theme) {
+        Renderer::set_theme(self, theme);
+    }
+}
@@ -7,16 +7,21 @@
 //! ```
 //! use iced_graphics::{button, Button};
 //! ```
+pub mod animated_image;
 pub mod button;
 pub mod checkbox;
 pub mod container;
 pub mod image;
+pub mod list;
+pub mod number_input;
 pub mod pane_grid;
 pub mod progress_bar;
 pub mod radio;
+pub mod rich_text;
 pub mod scrollable;
 pub mod slider;
 pub mod svg;
+pub mod text_editor;
 pub mod text_input;
 
 mod column;
@@ -24,6 +29,8 @@ mod row;
 mod space;
 mod text;
 
+#[doc(no_inline)]
+pub use animated_image::AnimatedImage;
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
@@ -31,16 +38,24 @@ pub use checkbox::Checkbox;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use list::List;
+#[doc(no_inline)]
+pub use number_input::NumberInput;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use rich_text::RichText;
+#[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
+pub use text_editor::TextEditor;
+#[doc(no_inline)]
 pub use text_input::TextInput;
 
 pub use column::Column;
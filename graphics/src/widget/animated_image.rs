@@ -0,0 +1,48 @@
+//! Display animated images, like GIFs, in your user interface.
+use crate::backend::{self, Backend};
+use crate::widget::image::clip;
+use crate::{Primitive, Renderer};
+use iced_native::animated_image;
+use iced_native::image;
+use iced_native::mouse;
+use iced_native::{Layout, Rectangle};
+
+use std::time::{Duration, Instant};
+
+pub use iced_native::animated_image::{AnimatedImage, State};
+
+impl<B> animated_image::Renderer for Renderer<B>
+where
+    B: Backend + backend::Image,
+{
+    fn frame_delays(&self, handle: &image::Handle) -> Vec<Duration> {
+        self.backend().frame_delays(handle)
+    }
+
+    fn draw(
+        &mut self,
+        handle: image::Handle,
+        frame: usize,
+        next_frame: Option<Instant>,
+        filter_method: image::FilterMethod,
+        layout: Layout<'_>,
+        image_bounds: Rectangle,
+    ) -> Self::Output {
+        // Keep drawing frames while the image is animated
+        if let Some(next_frame) = next_frame {
+            self.request_redraw(next_frame);
+        }
+
+        let image = Primitive::Image {
+            handle,
+            frame,
+            filter_method,
+            bounds: image_bounds,
+        };
+
+        (
+            clip(image, layout.bounds(), image_bounds),
+            mouse::Interaction::default(),
+        )
+    }
+}
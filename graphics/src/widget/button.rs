@@ -8,7 +8,7 @@ use crate::defaults::{self, Defaults};
 use crate::{Backend, Primitive, Renderer};
 use iced_native::mouse;
 use iced_native::{
-    Background, Color, Element, Layout, Point, Rectangle, Vector,
+    Background, Color, Element, Layout, Point, Rectangle, Shadow, Vector,
 };
 
 pub use iced_native::button::State;
@@ -42,15 +42,15 @@ where
         let is_mouse_over = bounds.contains(cursor_position);
 
         let styling = if is_disabled {
-            style.disabled()
+            style.disabled(self.theme())
         } else if is_mouse_over {
             if is_pressed {
-                style.pressed()
+                style.pressed(self.theme())
             } else {
-                style.hovered()
+                style.hovered(self.theme())
             }
         } else {
-            style.active()
+            style.active(self.theme())
         };
 
         let (content, _) = content.draw(
@@ -65,7 +65,10 @@ where
         );
 
         (
-            if styling.background.is_some() || styling.border_width > 0 {
+            if styling.background.is_some()
+                || !styling.border_width.is_zero()
+                || styling.shadow.is_visible()
+            {
                 let background = Primitive::Quad {
                     bounds,
                     background: styling
@@ -74,6 +77,7 @@ where
                     border_radius: styling.border_radius,
                     border_width: styling.border_width,
                     border_color: styling.border_color,
+                    shadow: styling.shadow,
                 };
 
                 if styling.shadow_offset == Vector::default() {
@@ -81,7 +85,6 @@ where
                         primitives: vec![background, content],
                     }
                 } else {
-                    // TODO: Implement proper shadow support
                     let shadow = Primitive::Quad {
                         bounds: Rectangle {
                             x: bounds.x + styling.shadow_offset.x,
@@ -92,8 +95,9 @@ where
                             [0.0, 0.0, 0.0, 0.5].into(),
                         ),
                         border_radius: styling.border_radius,
-                        border_width: 0,
+                        border_width: 0.into(),
                         border_color: Color::TRANSPARENT,
+                        shadow: Shadow::NONE,
                     };
 
                     Primitive::Group {
@@ -166,7 +166,7 @@ where
         messages: &mut Vec<Message>,
         _renderer: &Renderer<B>,
         _clipboard: Option<&dyn Clipboard>,
-    ) {
+    ) -> iced_native::event::Status {
         let bounds = layout.bounds();
 
         let canvas_event = match event {
@@ -183,8 +183,12 @@ where
                 self.program.update(canvas_event, bounds, cursor)
             {
                 messages.push(message);
+
+                return iced_native::event::Status::Captured;
             }
         }
+
+        iced_native::event::Status::Ignored
     }
 
     fn draw(
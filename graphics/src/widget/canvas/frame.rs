@@ -1,4 +1,6 @@
-use iced_native::{Point, Rectangle, Size, Vector};
+use iced_native::{
+    LineHeight, Overflow, Point, Rectangle, Size, Vector, Wrapping,
+};
 
 use crate::{
     canvas::{Fill, Geometry, Path, Stroke, Text},
@@ -232,6 +234,9 @@ impl Frame {
             font: text.font,
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
+            line_height: LineHeight::default(),
+            wrapping: Wrapping::default(),
+            overflow: Overflow::default(),
         });
     }
 
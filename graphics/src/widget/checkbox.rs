@@ -3,7 +3,10 @@ use crate::backend::{self, Backend};
 use crate::{Primitive, Renderer};
 use iced_native::checkbox;
 use iced_native::mouse;
-use iced_native::{HorizontalAlignment, Rectangle, VerticalAlignment};
+use iced_native::{
+    HorizontalAlignment, LineHeight, Overflow, Rectangle, Shadow,
+    VerticalAlignment, Wrapping,
+};
 
 pub use iced_style::checkbox::{Style, StyleSheet};
 
@@ -31,9 +34,9 @@ where
         style_sheet: &Self::Style,
     ) -> Self::Output {
         let style = if is_mouse_over {
-            style_sheet.hovered(is_checked)
+            style_sheet.hovered(self.theme(), is_checked)
         } else {
-            style_sheet.active(is_checked)
+            style_sheet.active(self.theme(), is_checked)
         };
 
         let checkbox = Primitive::Quad {
@@ -42,6 +45,7 @@ where
             border_radius: style.border_radius,
             border_width: style.border_width,
             border_color: style.border_color,
+            shadow: Shadow::NONE,
         };
 
         (
@@ -59,6 +63,9 @@ where
                         color: style.checkmark_color,
                         horizontal_alignment: HorizontalAlignment::Center,
                         vertical_alignment: VerticalAlignment::Center,
+                        line_height: LineHeight::default(),
+                        wrapping: Wrapping::default(),
+                        overflow: Overflow::default(),
                     };
 
                     vec![checkbox, check, label]
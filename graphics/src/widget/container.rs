@@ -28,7 +28,7 @@ where
         content: &Element<'_, Message, Self>,
         content_layout: Layout<'_>,
     ) -> Self::Output {
-        let style = style_sheet.style();
+        let style = style_sheet.style(self.theme());
 
         let defaults = Defaults {
             text: defaults::Text {
@@ -39,7 +39,10 @@ where
         let (content, mouse_interaction) =
             content.draw(self, &defaults, content_layout, cursor_position);
 
-        if style.background.is_some() || style.border_width > 0 {
+        if style.background.is_some()
+            || !style.border_width.is_zero()
+            || style.shadow.is_visible()
+        {
             let quad = Primitive::Quad {
                 bounds,
                 background: style
@@ -48,6 +51,7 @@ where
                 border_radius: style.border_radius,
                 border_width: style.border_width,
                 border_color: style.border_color,
+                shadow: style.shadow,
             };
 
             (
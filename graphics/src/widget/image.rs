@@ -1,11 +1,21 @@
 //! Display images in your user interface.
+pub mod atlas;
+
+#[cfg(feature = "image")]
+#[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+pub mod raster;
+
+#[cfg(feature = "svg")]
+#[cfg_attr(docsrs, doc(cfg(feature = "svg")))]
+pub mod vector;
+
 use crate::backend::{self, Backend};
 use crate::{Primitive, Renderer};
 use iced_native::image;
 use iced_native::mouse;
-use iced_native::Layout;
+use iced_native::{Layout, Rectangle, Vector};
 
-pub use iced_native::image::{Handle, Image};
+pub use iced_native::image::{FilterMethod, Handle, Image, Stream};
 
 impl<B> image::Renderer for Renderer<B>
 where
@@ -18,14 +28,42 @@ where
     fn draw(
         &mut self,
         handle: image::Handle,
+        filter_method: image::FilterMethod,
         layout: Layout<'_>,
+        image_bounds: Rectangle,
     ) -> Self::Output {
+        let image = Primitive::Image {
+            handle,
+            frame: 0,
+            filter_method,
+            bounds: image_bounds,
+        };
+
         (
-            Primitive::Image {
-                handle,
-                bounds: layout.bounds(),
-            },
+            clip(image, layout.bounds(), image_bounds),
             mouse::Interaction::default(),
         )
     }
 }
+
+/// Clips an image primitive to the given bounds, if it overflows them.
+pub(crate) fn clip(
+    image: Primitive,
+    bounds: Rectangle,
+    image_bounds: Rectangle,
+) -> Primitive {
+    let overflows = image_bounds.x < bounds.x
+        || image_bounds.y < bounds.y
+        || image_bounds.x + image_bounds.width > bounds.x + bounds.width
+        || image_bounds.y + image_bounds.height > bounds.y + bounds.height;
+
+    if overflows {
+        Primitive::Clip {
+            bounds,
+            offset: Vector::new(0, 0),
+            content: Box::new(image),
+        }
+    } else {
+        image
+    }
+}
@@ -0,0 +1,299 @@
+//! Allocate images in the layers of a texture atlas.
+pub mod entry;
+
+mod allocation;
+mod allocator;
+mod layer;
+
+pub use allocation::Allocation;
+pub use entry::Entry;
+pub use layer::Layer;
+
+use allocator::Allocator;
+
+/// The width and height of a layer of an [`Atlas`].
+///
+/// [`Atlas`]: struct.Atlas.html
+pub const SIZE: u32 = 2048;
+
+/// The allocations of a texture atlas made of layers of `SIZE * SIZE`
+/// pixels.
+///
+/// An [`Atlas`] only keeps track of the space in its layers. The pixels are
+/// stored in the textures of a backend, which implements [`Storage`].
+///
+/// [`Atlas`]: struct.Atlas.html
+/// [`Storage`]: trait.Storage.html
+#[derive(Debug)]
+pub struct Atlas {
+    layers: Vec<Layer>,
+}
+
+/// The textures holding the layers of an [`Atlas`] in a backend.
+///
+/// [`Atlas`]: struct.Atlas.html
+pub trait Storage {
+    /// Makes room for the given layers, keeping the contents of the layers
+    /// that are already stored.
+    fn grow(&mut self, layers: &[Layer]);
+
+    /// Uploads the BGRA pixels of an image with the given size to the
+    /// allocations of the given [`Entry`].
+    ///
+    /// [`Entry`]: entry/enum.Entry.html
+    fn upload(&mut self, entry: &Entry, width: u32, height: u32, data: &[u8]);
+}
+
+impl Atlas {
+    /// Creates a new [`Atlas`] with two empty layers.
+    ///
+    /// [`Atlas`]: struct.Atlas.html
+    pub fn new() -> Self {
+        Atlas {
+            layers: vec![Layer::Empty, Layer::Empty],
+        }
+    }
+
+    /// Returns the layers of the [`Atlas`].
+    ///
+    /// [`Atlas`]: struct.Atlas.html
+    pub fn layers(&self) -> &[Layer] {
+        &self.layers
+    }
+
+    /// Returns the amount of layers of the [`Atlas`].
+    ///
+    /// [`Atlas`]: struct.Atlas.html
+    pub fn layer_count(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Allocates an image with the given size and uploads its BGRA pixels
+    /// to the given [`Storage`].
+    ///
+    /// Returns `None` if the image cannot be allocated.
+    ///
+    /// [`Storage`]: trait.Storage.html
+    pub fn upload(
+        &mut self,
+        width: u32,
+        height: u32,
+        data: &[u8],
+        storage: &mut impl Storage,
+    ) -> Option<Entry> {
+        let entry = {
+            let entry = self.allocate(width, height)?;
+
+            // We grow the storage after allocating if necessary
+            storage.grow(&self.layers);
+
+            entry
+        };
+
+        log::info!("Allocated atlas entry: {:?}", entry);
+
+        storage.upload(&entry, width, height, data);
+
+        Some(entry)
+    }
+
+    /// Frees the allocations of the given [`Entry`].
+    ///
+    /// [`Entry`]: entry/enum.Entry.html
+    pub fn remove(&mut self, entry: &Entry) {
+        log::info!("Removing atlas entry: {:?}", entry);
+
+        match entry {
+            Entry::Contiguous(allocation) => {
+                self.deallocate(allocation);
+            }
+            Entry::Fragmented { fragments, .. } => {
+                for fragment in fragments {
+                    self.deallocate(&fragment.allocation);
+                }
+            }
+        }
+    }
+
+    fn allocate(&mut self, width: u32, height: u32) -> Option<Entry> {
+        // Allocate one layer if texture fits perfectly
+        if width == SIZE && height == SIZE {
+            let mut empty_layers = self
+                .layers
+                .iter_mut()
+                .enumerate()
+                .filter(|(_, layer)| layer.is_empty());
+
+            if let Some((i, layer)) = empty_layers.next() {
+                *layer = Layer::Full;
+
+                return Some(Entry::Contiguous(Allocation::Full { layer: i }));
+            }
+
+            self.layers.push(Layer::Full);
+
+            return Some(Entry::Contiguous(Allocation::Full {
+                layer: self.layers.len() - 1,
+            }));
+        }
+
+        // Split big textures across multiple layers
+        if width > SIZE || height > SIZE {
+            let mut fragments = Vec::new();
+            let mut y = 0;
+
+            while y < height {
+                let height = std::cmp::min(height - y, SIZE);
+                let mut x = 0;
+
+                while x < width {
+                    let width = std::cmp::min(width - x, SIZE);
+
+                    let allocation = self.allocate(width, height)?;
+
+                    if let Entry::Contiguous(allocation) = allocation {
+                        fragments.push(entry::Fragment {
+                            position: (x, y),
+                            allocation,
+                        });
+                    }
+
+                    x += width;
+                }
+
+                y += height;
+            }
+
+            return Some(Entry::Fragmented {
+                size: (width, height),
+                fragments,
+            });
+        }
+
+        // Try allocating on an existing layer
+        for (i, layer) in self.layers.iter_mut().enumerate() {
+            match layer {
+                Layer::Empty => {
+                    let mut allocator = Allocator::new(SIZE);
+
+                    if let Some(region) = allocator.allocate(width, height) {
+                        *layer = Layer::Busy(allocator);
+
+                        return Some(Entry::Contiguous(Allocation::Partial {
+                            region,
+                            layer: i,
+                        }));
+                    }
+                }
+                Layer::Busy(allocator) => {
+                    if let Some(region) = allocator.allocate(width, height) {
+                        return Some(Entry::Contiguous(Allocation::Partial {
+                            region,
+                            layer: i,
+                        }));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Create new layer with atlas allocator
+        let mut allocator = Allocator::new(SIZE);
+
+        if let Some(region) = allocator.allocate(width, height) {
+            self.layers.push(Layer::Busy(allocator));
+
+            return Some(Entry::Contiguous(Allocation::Partial {
+                region,
+                layer: self.layers.len() - 1,
+            }));
+        }
+
+        // We ran out of memory (?)
+        None
+    }
+
+    fn deallocate(&mut self, allocation: &Allocation) {
+        log::info!("Deallocating atlas: {:?}", allocation);
+
+        match allocation {
+            Allocation::Full { layer } => {
+                self.layers[*layer] = Layer::Empty;
+            }
+            Allocation::Partial { layer, region } => {
+                let layer = &mut self.layers[*layer];
+
+                if let Layer::Busy(allocator) = layer {
+                    allocator.deallocate(region);
+
+                    if allocator.is_empty() {
+                        *layer = Layer::Empty;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Default for Atlas {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn big_images_are_fragmented() {
+        let mut atlas = Atlas::new();
+
+        let entry = atlas.allocate(SIZE + 10, SIZE).unwrap();
+
+        assert_eq!(entry.size(), (SIZE + 10, SIZE));
+
+        let allocations: Vec<_> = entry
+            .allocations(SIZE + 10)
+            .map(|(offset, allocation)| {
+                (offset, allocation.size(), allocation.layer())
+            })
+            .collect();
+
+        assert_eq!(
+            allocations,
+            vec![(0, (SIZE, SIZE), 0), (SIZE as usize * 4, (10, SIZE), 1)]
+        );
+    }
+
+    #[test]
+    fn removed_entries_free_their_layers() {
+        let mut atlas = Atlas::new();
+
+        let small = atlas.allocate(10, 10).unwrap();
+        let full = atlas.allocate(SIZE, SIZE).unwrap();
+        let other = atlas.allocate(SIZE, SIZE).unwrap();
+
+        assert_eq!(atlas.layer_count(), 3);
+        assert!(atlas.layers().iter().all(|layer| !layer.is_empty()));
+
+        atlas.remove(&small);
+        atlas.remove(&full);
+
+        assert!(atlas.layers()[0].is_empty());
+        assert!(atlas.layers()[1].is_empty());
+
+        let reused = atlas.allocate(SIZE, SIZE).unwrap();
+
+        match (reused, other) {
+            (
+                Entry::Contiguous(Allocation::Full { layer: reused }),
+                Entry::Contiguous(Allocation::Full { layer: other }),
+            ) => {
+                assert_eq!(reused, 0);
+                assert_eq!(other, 2);
+            }
+            _ => panic!("Expected full layers"),
+        }
+    }
+}
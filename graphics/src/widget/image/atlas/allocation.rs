@@ -1,17 +1,30 @@
 use crate::image::atlas::{self, allocator};
 
+/// A region of a layer of an [`Atlas`].
+///
+/// [`Atlas`]: struct.Atlas.html
 #[derive(Debug)]
 pub enum Allocation {
+    /// A part of a layer.
     Partial {
+        /// The index of the layer
         layer: usize,
+
+        /// The allocated region of the layer
         region: allocator::Region,
     },
+
+    /// A whole layer.
     Full {
+        /// The index of the layer
         layer: usize,
     },
 }
 
 impl Allocation {
+    /// Returns the position of the [`Allocation`] in its layer.
+    ///
+    /// [`Allocation`]: enum.Allocation.html
     pub fn position(&self) -> (u32, u32) {
         match self {
             Allocation::Partial { region, .. } => region.position(),
@@ -19,6 +32,9 @@ impl Allocation {
         }
     }
 
+    /// Returns the size of the [`Allocation`].
+    ///
+    /// [`Allocation`]: enum.Allocation.html
     pub fn size(&self) -> (u32, u32) {
         match self {
             Allocation::Partial { region, .. } => region.size(),
@@ -26,6 +42,9 @@ impl Allocation {
         }
     }
 
+    /// Returns the index of the layer of the [`Allocation`].
+    ///
+    /// [`Allocation`]: enum.Allocation.html
     pub fn layer(&self) -> usize {
         match self {
             Allocation::Partial { layer, .. } => *layer,
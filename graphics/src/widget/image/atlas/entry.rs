@@ -0,0 +1,69 @@
+//! Locate the images allocated in an atlas.
+use crate::image::atlas;
+
+/// The allocations of an image in an [`Atlas`].
+///
+/// [`Atlas`]: ../struct.Atlas.html
+#[derive(Debug)]
+pub enum Entry {
+    /// An image stored in a single allocation.
+    Contiguous(atlas::Allocation),
+
+    /// An image bigger than a layer, split in many allocations.
+    Fragmented {
+        /// The size of the whole image
+        size: (u32, u32),
+
+        /// The fragments of the image
+        fragments: Vec<Fragment>,
+    },
+}
+
+impl Entry {
+    /// Returns the size of the image stored in the [`Entry`].
+    ///
+    /// [`Entry`]: enum.Entry.html
+    pub fn size(&self) -> (u32, u32) {
+        match self {
+            Entry::Contiguous(allocation) => allocation.size(),
+            Entry::Fragmented { size, .. } => *size,
+        }
+    }
+
+    /// Returns the allocations of the [`Entry`], along with the byte offset
+    /// of their first pixel in the BGRA data of an image with the given
+    /// width.
+    ///
+    /// [`Entry`]: enum.Entry.html
+    pub fn allocations(
+        &self,
+        image_width: u32,
+    ) -> impl Iterator<Item = (usize, &atlas::Allocation)> {
+        let (contiguous, fragments) = match self {
+            Entry::Contiguous(allocation) => (Some(allocation), &[][..]),
+            Entry::Fragmented { fragments, .. } => (None, &fragments[..]),
+        };
+
+        contiguous
+            .map(|allocation| (0, allocation))
+            .into_iter()
+            .chain(fragments.iter().map(move |fragment| {
+                let (x, y) = fragment.position;
+                let offset = (y * image_width + x) as usize * 4;
+
+                (offset, &fragment.allocation)
+            }))
+    }
+}
+
+/// A part of an image bigger than a layer of an [`Atlas`].
+///
+/// [`Atlas`]: ../struct.Atlas.html
+#[derive(Debug)]
+pub struct Fragment {
+    /// The position of the fragment in the image
+    pub position: (u32, u32),
+
+    /// The allocation of the fragment
+    pub allocation: atlas::Allocation,
+}
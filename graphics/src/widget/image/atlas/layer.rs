@@ -0,0 +1,28 @@
+use crate::image::atlas::Allocator;
+
+/// A layer of an [`Atlas`].
+///
+/// [`Atlas`]: struct.Atlas.html
+#[derive(Debug)]
+pub enum Layer {
+    /// A layer without allocations.
+    Empty,
+
+    /// A layer with some allocations.
+    Busy(Allocator),
+
+    /// A layer allocated as a whole.
+    Full,
+}
+
+impl Layer {
+    /// Returns whether the [`Layer`] has no allocations.
+    ///
+    /// [`Layer`]: enum.Layer.html
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Layer::Empty => true,
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,356 @@
+//! Decode raster images and upload them to an atlas.
+use crate::image::atlas::{self, Atlas};
+use iced_native::image;
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+/// The memory of a raster image.
+#[derive(Debug)]
+pub enum Memory {
+    /// An image decoded in the host, waiting to be uploaded.
+    Host(::image::ImageBuffer<::image::Bgra<u8>, Vec<u8>>),
+
+    /// An image uploaded to an [`Atlas`].
+    ///
+    /// [`Atlas`]: ../atlas/struct.Atlas.html
+    Device(atlas::Entry),
+
+    /// The frames of an animated image.
+    Animation(Vec<Frame>),
+
+    /// An image whose file could not be found.
+    NotFound,
+
+    /// An image whose data could not be decoded.
+    Invalid,
+}
+
+impl Memory {
+    /// Returns the dimensions of the image, or of the first frame of an
+    /// animation.
+    pub fn dimensions(&self) -> (u32, u32) {
+        match self {
+            Memory::Host(image) => image.dimensions(),
+            Memory::Device(entry) => entry.size(),
+            Memory::Animation(frames) => frames
+                .first()
+                .map(|frame| frame.memory.dimensions())
+                .unwrap_or((1, 1)),
+            Memory::NotFound => (1, 1),
+            Memory::Invalid => (1, 1),
+        }
+    }
+
+    /// Returns how long every frame of the image is displayed.
+    ///
+    /// Still images have a single frame.
+    pub fn frame_delays(&self) -> Vec<Duration> {
+        match self {
+            Memory::Animation(frames) => {
+                frames.iter().map(|frame| frame.delay).collect()
+            }
+            _ => vec![Duration::from_secs(0)],
+        }
+    }
+
+    fn remove(&self, atlas: &mut Atlas) {
+        match self {
+            Memory::Device(entry) => atlas.remove(entry),
+            Memory::Animation(frames) => {
+                for frame in frames {
+                    frame.memory.remove(atlas);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// A frame of an animated image.
+///
+/// Frames are kept on the host until they are drawn for the first time.
+#[derive(Debug)]
+pub struct Frame {
+    memory: Memory,
+    delay: Duration,
+}
+
+/// A cache of raster images, keyed by the id of their handle.
+#[derive(Debug)]
+pub struct Cache {
+    map: HashMap<u64, Memory>,
+    hits: HashSet<u64>,
+    versions: HashMap<u64, u64>,
+    outdated: HashMap<u64, atlas::Entry>,
+}
+
+impl Cache {
+    /// Creates a new empty [`Cache`].
+    ///
+    /// [`Cache`]: struct.Cache.html
+    pub fn new() -> Self {
+        Self {
+            map: HashMap::new(),
+            hits: HashSet::new(),
+            versions: HashMap::new(),
+            outdated: HashMap::new(),
+        }
+    }
+
+    /// Loads the image of the given handle, decoding it if it is not
+    /// cached or if its stream has a new frame.
+    pub fn load(&mut self, handle: &image::Handle) -> &mut Memory {
+        if self.contains(handle) {
+            if !self.is_outdated(handle) {
+                return self.get(handle).unwrap();
+            }
+
+            // Keep the atlas entry of the previous frame around, so it can
+            // be reused if the new frame has the same size
+            if let Some(Memory::Device(entry)) = self.map.remove(&handle.id()) {
+                let _ = self.outdated.insert(handle.id(), entry);
+            }
+        }
+
+        let memory = match handle.data() {
+            image::Data::Path(path) => {
+                let animation = ::image::ImageFormat::from_path(path)
+                    .ok()
+                    .and_then(|format| {
+                        let file = std::fs::File::open(path).ok()?;
+
+                        decode_frames(format, std::io::BufReader::new(file))
+                    });
+
+                if let Some(memory) = animation {
+                    memory
+                } else if let Ok(image) = ::image::open(path) {
+                    Memory::Host(image.to_bgra8())
+                } else {
+                    Memory::NotFound
+                }
+            }
+            image::Data::Bytes(bytes) => {
+                let animation =
+                    ::image::guess_format(&bytes).ok().and_then(|format| {
+                        decode_frames(format, std::io::Cursor::new(&bytes[..]))
+                    });
+
+                if let Some(memory) = animation {
+                    memory
+                } else if let Ok(image) = ::image::load_from_memory(&bytes) {
+                    Memory::Host(image.to_bgra8())
+                } else {
+                    Memory::Invalid
+                }
+            }
+            image::Data::Pixels {
+                width,
+                height,
+                pixels,
+            } => {
+                if let Some(image) = ::image::ImageBuffer::from_vec(
+                    *width,
+                    *height,
+                    pixels.to_vec(),
+                ) {
+                    Memory::Host(image)
+                } else {
+                    Memory::Invalid
+                }
+            }
+            image::Data::Stream(frame) => {
+                let frame = frame.lock().expect("Lock stream frame");
+
+                let _ = self.versions.insert(handle.id(), frame.version);
+
+                if let Some(image) = ::image::ImageBuffer::from_vec(
+                    frame.width,
+                    frame.height,
+                    frame.pixels.to_vec(),
+                ) {
+                    Memory::Host(image)
+                } else {
+                    Memory::Invalid
+                }
+            }
+        };
+
+        self.insert(handle, memory);
+        self.get(handle).unwrap()
+    }
+
+    /// Uploads the given frame of the image of the given handle to an
+    /// [`Atlas`], if it was not uploaded already.
+    ///
+    /// [`Atlas`]: ../atlas/struct.Atlas.html
+    pub fn upload(
+        &mut self,
+        handle: &image::Handle,
+        frame: usize,
+        atlas: &mut Atlas,
+        storage: &mut impl atlas::Storage,
+    ) -> Option<&atlas::Entry> {
+        let outdated = {
+            let _ = self.load(handle);
+
+            self.outdated.remove(&handle.id())
+        };
+
+        let memory = match self.get(handle).unwrap() {
+            Memory::Animation(frames) => match frames.len() {
+                0 => return None,
+                n => &mut frames[frame % n].memory,
+            },
+            memory => memory,
+        };
+
+        if let Memory::Host(image) = memory {
+            let (width, height) = image.dimensions();
+
+            let entry = match outdated {
+                Some(entry) if entry.size() == (width, height) => {
+                    storage.upload(&entry, width, height, &image);
+
+                    entry
+                }
+                outdated => {
+                    if let Some(entry) = outdated {
+                        atlas.remove(&entry);
+                    }
+
+                    atlas.upload(width, height, &image, storage)?
+                }
+            };
+
+            *memory = Memory::Device(entry);
+        } else if let Some(entry) = outdated {
+            atlas.remove(&entry);
+        }
+
+        if let Memory::Device(allocation) = memory {
+            Some(allocation)
+        } else {
+            None
+        }
+    }
+
+    /// Removes the images that were not used since the last trim from the
+    /// [`Cache`] and the given [`Atlas`].
+    ///
+    /// [`Cache`]: struct.Cache.html
+    /// [`Atlas`]: ../atlas/struct.Atlas.html
+    pub fn trim(&mut self, atlas: &mut Atlas) {
+        let hits = &self.hits;
+
+        self.map.retain(|k, memory| {
+            let retain = hits.contains(k);
+
+            if !retain {
+                memory.remove(atlas);
+            }
+
+            retain
+        });
+
+        self.versions.retain(|k, _| hits.contains(k));
+
+        for (_, entry) in self.outdated.drain() {
+            atlas.remove(&entry);
+        }
+
+        self.hits.clear();
+    }
+
+    fn get(&mut self, handle: &image::Handle) -> Option<&mut Memory> {
+        let _ = self.hits.insert(handle.id());
+
+        self.map.get_mut(&handle.id())
+    }
+
+    fn insert(&mut self, handle: &image::Handle, memory: Memory) {
+        let _ = self.map.insert(handle.id(), memory);
+    }
+
+    fn contains(&self, handle: &image::Handle) -> bool {
+        self.map.contains_key(&handle.id())
+    }
+
+    fn is_outdated(&self, handle: &image::Handle) -> bool {
+        match handle.data() {
+            image::Data::Stream(frame) => {
+                let frame = frame.lock().expect("Lock stream frame");
+
+                self.versions.get(&handle.id()) != Some(&frame.version)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Default for Cache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Decodes all the frames of an animated image, if the format supports
+/// animation.
+///
+/// Returns `None` for still images, which are loaded as usual.
+fn decode_frames<R: std::io::Read>(
+    format: ::image::ImageFormat,
+    reader: R,
+) -> Option<Memory> {
+    use ::image::AnimationDecoder;
+
+    // Browsers slow down frames with tiny delays, and many GIFs rely on it
+    const MIN_DELAY: Duration = Duration::from_millis(20);
+    const DEFAULT_DELAY: Duration = Duration::from_millis(100);
+
+    let frames = match format {
+        ::image::ImageFormat::Gif => {
+            ::image::gif::GifDecoder::new(reader).ok()?.into_frames()
+        }
+        ::image::ImageFormat::Png => {
+            let decoder = ::image::png::PngDecoder::new(reader).ok()?;
+
+            if !decoder.is_apng() {
+                return None;
+            }
+
+            decoder.apng().into_frames()
+        }
+        _ => return None,
+    };
+
+    let mut frames: Vec<Frame> = frames
+        .collect_frames()
+        .ok()?
+        .into_iter()
+        .map(|frame| {
+            let (numer, denom) = frame.delay().numer_denom_ms();
+            let delay = Duration::from_micros(
+                u64::from(numer) * 1000 / u64::from(denom.max(1)),
+            );
+
+            Frame {
+                memory: Memory::Host(
+                    ::image::DynamicImage::ImageRgba8(frame.into_buffer())
+                        .to_bgra8(),
+                ),
+                delay: if delay < MIN_DELAY {
+                    DEFAULT_DELAY
+                } else {
+                    delay
+                },
+            }
+        })
+        .collect();
+
+    match frames.len() {
+        0 => None,
+        1 => Some(frames.remove(0).memory),
+        _ => Some(Memory::Animation(frames)),
+    }
+}
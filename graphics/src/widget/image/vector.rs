@@ -1,15 +1,19 @@
+//! Rasterize vector images and upload them to an atlas.
 use crate::image::atlas::{self, Atlas};
-use iced_native::svg;
+use iced_native::{svg, Color};
 use std::collections::{HashMap, HashSet};
 
-use zerocopy::AsBytes;
-
+/// A parsed vector image.
 pub enum Svg {
+    /// An image parsed successfully.
     Loaded(resvg::usvg::Tree),
+
+    /// An image whose data could not be found or parsed.
     NotFound,
 }
 
 impl Svg {
+    /// Returns the dimensions of the viewport of the image.
     pub fn viewport_dimensions(&self) -> (u32, u32) {
         match self {
             Svg::Loaded(tree) => {
@@ -22,15 +26,22 @@ impl Svg {
     }
 }
 
+/// A cache of vector images and of their rasterizations.
 #[derive(Debug)]
 pub struct Cache {
     svgs: HashMap<u64, Svg>,
-    rasterized: HashMap<(u64, u32, u32), atlas::Entry>,
+    rasterized: HashMap<RasterKey, atlas::Entry>,
     svg_hits: HashSet<u64>,
-    rasterized_hits: HashSet<(u64, u32, u32)>,
+    rasterized_hits: HashSet<RasterKey>,
 }
 
+/// An SVG rasterized with a specific size and color.
+type RasterKey = (u64, u32, u32, Option<[u8; 4]>);
+
 impl Cache {
+    /// Creates a new empty [`Cache`].
+    ///
+    /// [`Cache`]: struct.Cache.html
     pub fn new() -> Self {
         Self {
             svgs: HashMap::new(),
@@ -40,6 +51,7 @@ impl Cache {
         }
     }
 
+    /// Parses the image of the given handle, if it is not cached.
     pub fn load(&mut self, handle: &svg::Handle) -> &Svg {
         if self.svgs.contains_key(&handle.id()) {
             return self.svgs.get(&handle.id()).unwrap();
@@ -66,14 +78,19 @@ impl Cache {
         self.svgs.get(&handle.id()).unwrap()
     }
 
+    /// Rasterizes the image of the given handle with the given size, scale
+    /// and color, and uploads it to an [`Atlas`] if it was not uploaded
+    /// already.
+    ///
+    /// [`Atlas`]: ../atlas/struct.Atlas.html
     pub fn upload(
         &mut self,
         handle: &svg::Handle,
+        color: Option<Color>,
         [width, height]: [f32; 2],
         scale: f32,
-        device: &wgpu::Device,
-        encoder: &mut wgpu::CommandEncoder,
         texture_atlas: &mut Atlas,
+        storage: &mut impl atlas::Storage,
     ) -> Option<&atlas::Entry> {
         let id = handle.id();
 
@@ -82,15 +99,18 @@ impl Cache {
             (scale * height).round() as u32,
         );
 
+        let color = color.map(rgba8);
+        let key = (id, width, height, color);
+
         // TODO: Optimize!
         // We currently rerasterize the SVG when its size changes. This is slow
         // as heck. A GPU rasterizer like `pathfinder` may perform better.
         // It would be cool to be able to smooth resize the `svg` example.
-        if self.rasterized.contains_key(&(id, width, height)) {
+        if self.rasterized.contains_key(&key) {
             let _ = self.svg_hits.insert(id);
-            let _ = self.rasterized_hits.insert((id, width, height));
+            let _ = self.rasterized_hits.insert(key);
 
-            return self.rasterized.get(&(id, width, height));
+            return self.rasterized.get(&key);
         }
 
         match self.load(handle) {
@@ -116,24 +136,32 @@ impl Cache {
                     &mut canvas,
                 );
 
+                if let Some(color) = color {
+                    tint(canvas.get_data_mut(), color);
+                }
+
                 let allocation = texture_atlas.upload(
                     width,
                     height,
-                    canvas.get_data().as_bytes(),
-                    device,
-                    encoder,
+                    bytemuck::cast_slice(canvas.get_data()),
+                    storage,
                 )?;
 
                 let _ = self.svg_hits.insert(id);
-                let _ = self.rasterized_hits.insert((id, width, height));
-                let _ = self.rasterized.insert((id, width, height), allocation);
+                let _ = self.rasterized_hits.insert(key);
+                let _ = self.rasterized.insert(key, allocation);
 
-                self.rasterized.get(&(id, width, height))
+                self.rasterized.get(&key)
             }
             Svg::NotFound => None,
         }
     }
 
+    /// Removes the images that were not used since the last trim from the
+    /// [`Cache`] and the given [`Atlas`].
+    ///
+    /// [`Cache`]: struct.Cache.html
+    /// [`Atlas`]: ../atlas/struct.Atlas.html
     pub fn trim(&mut self, atlas: &mut Atlas) {
         let svg_hits = &self.svg_hits;
         let rasterized_hits = &self.rasterized_hits;
@@ -153,6 +181,12 @@ impl Cache {
     }
 }
 
+impl Default for Cache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl std::fmt::Debug for Svg {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -161,3 +195,31 @@ impl std::fmt::Debug for Svg {
         }
     }
 }
+
+fn rgba8(color: Color) -> [u8; 4] {
+    let channel = |value: f32| (value.max(0.0).min(1.0) * 255.0).round() as u8;
+
+    [
+        channel(color.r),
+        channel(color.g),
+        channel(color.b),
+        channel(color.a),
+    ]
+}
+
+/// Replaces the colors of the given rasterized pixels, keeping their coverage.
+///
+/// Like the rest of the canvas, the resulting pixels are premultiplied.
+fn tint(pixels: &mut [u32], [r, g, b, a]: [u8; 4]) {
+    let premultiply =
+        |channel: u8, alpha: u32| u32::from(channel) * alpha / 255;
+
+    for pixel in pixels {
+        let alpha = (*pixel >> 24) * u32::from(a) / 255;
+
+        *pixel = alpha << 24
+            | premultiply(r, alpha) << 16
+            | premultiply(g, alpha) << 8
+            | premultiply(b, alpha);
+    }
+}
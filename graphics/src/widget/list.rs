@@ -0,0 +1,11 @@
+//! Display a very large amount of rows efficiently.
+use crate::Renderer;
+
+pub use iced_native::list::{State, Viewport};
+pub use iced_style::scrollable::StyleSheet;
+
+/// A scrollable list that only builds, lays out and draws its visible rows.
+///
+/// This is an alias of an `iced_native` list with a default `Renderer`.
+pub type List<'a, Message, Backend> =
+    iced_native::List<'a, Message, Renderer<Backend>>;
@@ -0,0 +1,112 @@
+//! Let your users type or step through numbers.
+//!
+//! A [`NumberInput`] has some local [`State`].
+//!
+//! [`NumberInput`]: struct.NumberInput.html
+//! [`State`]: struct.State.html
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::number_input;
+use iced_native::{
+    Background, Color, Font, HorizontalAlignment, LineHeight, Overflow, Point,
+    Rectangle, Shadow, VerticalAlignment, Wrapping,
+};
+
+pub use iced_native::number_input::{Number, State};
+pub use iced_style::number_input::{Style, StyleSheet};
+
+/// A field that can be filled with a number.
+///
+/// This is an alias of an `iced_native` number input with an
+/// `iced_wgpu::Renderer`.
+pub type NumberInput<'a, T, Message, Backend> =
+    iced_native::NumberInput<'a, T, Message, Renderer<Backend>>;
+
+impl<B> number_input::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        (input, input_interaction): Self::Output,
+        increment_bounds: Rectangle,
+        decrement_bounds: Rectangle,
+        can_increment: bool,
+        can_decrement: bool,
+        is_valid: bool,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let theme = self.theme();
+
+        let mut interaction = input_interaction;
+
+        let mut button = |bounds: Rectangle, icon: &str, is_enabled: bool| {
+            let is_mouse_over = bounds.contains(cursor_position);
+
+            let style = if !is_enabled {
+                style_sheet.disabled(theme)
+            } else if is_mouse_over {
+                interaction = mouse::Interaction::Pointer;
+
+                style_sheet.hovered(theme)
+            } else {
+                style_sheet.active(theme)
+            };
+
+            let background = Primitive::Quad {
+                bounds,
+                background: style
+                    .button_background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+                border_radius: style.button_border_radius,
+                border_width: style.button_border_width,
+                border_color: style.button_border_color,
+                shadow: Shadow::NONE,
+            };
+
+            let icon = Primitive::Text {
+                content: icon.to_string(),
+                font: Font::Default,
+                size: bounds.height * 0.9,
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                color: style.icon_color,
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+                line_height: LineHeight::default(),
+                wrapping: Wrapping::default(),
+                overflow: Overflow::default(),
+            };
+
+            Primitive::Group {
+                primitives: vec![background, icon],
+            }
+        };
+
+        let increment = button(increment_bounds, "+", can_increment);
+        let decrement = button(decrement_bounds, "-", can_decrement);
+
+        let mut primitives = vec![input, increment, decrement];
+
+        if !is_valid {
+            primitives.push(Primitive::Quad {
+                bounds,
+                background: Background::Color(Color::TRANSPARENT),
+                border_radius: 0.into(),
+                border_width: 1.into(),
+                border_color: style_sheet.invalid_color(theme),
+                shadow: Shadow::NONE,
+            });
+        }
+
+        (Primitive::Group { primitives }, interaction)
+    }
+}
@@ -7,7 +7,7 @@
 use crate::{Backend, Primitive, Renderer};
 use iced_native::mouse;
 use iced_native::progress_bar;
-use iced_native::{Color, Rectangle};
+use iced_native::{Color, Rectangle, Shadow};
 
 pub use iced_style::progress_bar::{Style, StyleSheet};
 
@@ -32,7 +32,7 @@ where
         value: f32,
         style_sheet: &Self::Style,
     ) -> Self::Output {
-        let style = style_sheet.style();
+        let style = style_sheet.style(self.theme());
 
         let (range_start, range_end) = range.into_inner();
         let active_progress_width = bounds.width
@@ -43,8 +43,9 @@ where
                 bounds: Rectangle { ..bounds },
                 background: style.background,
                 border_radius: style.border_radius,
-                border_width: 0,
+                border_width: 0.into(),
                 border_color: Color::TRANSPARENT,
+                shadow: Shadow::NONE,
             }],
         };
 
@@ -57,8 +58,9 @@ where
                     },
                     background: style.bar,
                     border_radius: style.border_radius,
-                    border_width: 0,
+                    border_width: 0.into(),
                     border_color: Color::TRANSPARENT,
+                    shadow: Shadow::NONE,
                 };
 
                 Primitive::Group {
@@ -2,7 +2,7 @@
 use crate::{Backend, Primitive, Renderer};
 use iced_native::mouse;
 use iced_native::radio;
-use iced_native::{Background, Color, Rectangle};
+use iced_native::{Background, Color, Rectangle, Shadow};
 
 pub use iced_style::radio::{Style, StyleSheet};
 
@@ -34,17 +34,18 @@ where
         style_sheet: &Self::Style,
     ) -> Self::Output {
         let style = if is_mouse_over {
-            style_sheet.hovered()
+            style_sheet.hovered(self.theme())
         } else {
-            style_sheet.active()
+            style_sheet.active(self.theme())
         };
 
         let radio = Primitive::Quad {
             bounds,
             background: style.background,
-            border_radius: (SIZE / 2.0) as u16,
+            border_radius: ((SIZE / 2.0) as u16).into(),
             border_width: style.border_width,
             border_color: style.border_color,
+            shadow: Shadow::NONE,
         };
 
         (
@@ -58,9 +59,10 @@ where
                             height: bounds.height - DOT_SIZE,
                         },
                         background: Background::Color(style.dot_color),
-                        border_radius: (DOT_SIZE / 2.0) as u16,
-                        border_width: 0,
+                        border_radius: ((DOT_SIZE / 2.0) as u16).into(),
+                        border_width: 0.into(),
                         border_color: Color::TRANSPARENT,
+                        shadow: Shadow::NONE,
                     };
 
                     vec![radio, radio_circle, label]
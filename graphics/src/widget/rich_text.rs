@@ -0,0 +1,125 @@
+//! Write paragraphs mixing different styles and links.
+use crate::backend::{self, Backend};
+use crate::primitive;
+use crate::{Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::rich_text;
+use iced_native::{
+    Color, Font, HorizontalAlignment, Point, Rectangle, Size, VerticalAlignment,
+};
+
+pub use iced_native::rich_text::Span;
+
+/// A paragraph of text made of spans, each one with its own style.
+///
+/// This is an alias of an `iced_native` rich text with an
+/// `iced_wgpu::Renderer`.
+pub type RichText<Message, Backend> =
+    iced_native::RichText<Message, Renderer<Backend>>;
+
+impl<B> rich_text::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    fn measure<Message>(
+        &self,
+        spans: &[Span<Message, Font>],
+        size: u16,
+        font: Font,
+        bounds: Size,
+    ) -> (f32, f32) {
+        self.backend()
+            .measure_spans(&resolve(spans, size, font, Color::BLACK), bounds)
+    }
+
+    fn hit_test<Message>(
+        &self,
+        spans: &[Span<Message, Font>],
+        size: u16,
+        font: Font,
+        bounds: Rectangle,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+        point: Point,
+    ) -> Option<usize> {
+        self.backend().hit_test(
+            &resolve(spans, size, font, Color::BLACK),
+            align(bounds, horizontal_alignment, vertical_alignment),
+            horizontal_alignment,
+            vertical_alignment,
+            point,
+        )
+    }
+
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        spans: &[Span<Message, Font>],
+        size: u16,
+        font: Font,
+        color: Option<Color>,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+        hovered_link: Option<usize>,
+    ) -> Self::Output {
+        (
+            Primitive::RichText {
+                spans: resolve(
+                    spans,
+                    size,
+                    font,
+                    color.unwrap_or(defaults.text.color),
+                ),
+                bounds: align(bounds, horizontal_alignment, vertical_alignment),
+                horizontal_alignment,
+                vertical_alignment,
+            },
+            if hovered_link.is_some() {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+}
+
+/// Fills the missing styles of the given spans with the provided defaults.
+fn resolve<Message>(
+    spans: &[Span<Message, Font>],
+    size: u16,
+    font: Font,
+    color: Color,
+) -> Vec<primitive::Span> {
+    spans
+        .iter()
+        .map(|span| primitive::Span {
+            content: span.content.clone(),
+            color: span.color.unwrap_or(color),
+            size: f32::from(span.size.unwrap_or(size)),
+            font: span.font.clone().unwrap_or_else(|| font.clone()),
+        })
+        .collect()
+}
+
+/// Moves the origin of the given bounds to the anchor of the alignment, like
+/// a `Text` does.
+fn align(
+    bounds: Rectangle,
+    horizontal_alignment: HorizontalAlignment,
+    vertical_alignment: VerticalAlignment,
+) -> Rectangle {
+    let x = match horizontal_alignment {
+        HorizontalAlignment::Left => bounds.x,
+        HorizontalAlignment::Center => bounds.center_x(),
+        HorizontalAlignment::Right => bounds.x + bounds.width,
+    };
+
+    let y = match vertical_alignment {
+        VerticalAlignment::Top => bounds.y,
+        VerticalAlignment::Center => bounds.center_y(),
+        VerticalAlignment::Bottom => bounds.y + bounds.height,
+    };
+
+    Rectangle { x, y, ..bounds }
+}
@@ -2,13 +2,15 @@
 use crate::{Backend, Primitive, Renderer};
 use iced_native::mouse;
 use iced_native::scrollable;
-use iced_native::{Background, Color, Rectangle, Vector};
+use iced_native::{Background, Color, Rectangle, Shadow, Vector};
 
-pub use iced_native::scrollable::State;
+use std::time::Instant;
+
+pub use iced_native::scrollable::{Direction, State, Viewport};
 pub use iced_style::scrollable::{Scrollbar, Scroller, StyleSheet};
 
-/// A widget that can vertically display an infinite amount of content
-/// with a scrollbar.
+/// A widget that can display an infinite amount of content with
+/// scrollbars, vertically, horizontally or in both directions.
 ///
 /// This is an alias of an `iced_native` scrollable with a default
 /// `Renderer`.
@@ -24,40 +26,87 @@ where
 {
     type Style = Box<dyn iced_style::scrollable::StyleSheet>;
 
-    fn scrollbar(
+    fn scrollbars(
         &self,
         bounds: Rectangle,
         content_bounds: Rectangle,
-        offset: u32,
-    ) -> Option<scrollable::Scrollbar> {
-        if content_bounds.height > bounds.height {
+        offset: Vector<u32>,
+        direction: scrollable::Direction,
+    ) -> scrollable::Scrollbars {
+        let thickness = f32::from(SCROLLBAR_WIDTH + 2 * SCROLLBAR_MARGIN);
+
+        let has_vertical =
+            direction.is_vertical() && content_bounds.height > bounds.height;
+        let has_horizontal =
+            direction.is_horizontal() && content_bounds.width > bounds.width;
+
+        // When both scrollbars are present, they leave the bottom right
+        // corner empty so they do not overlap
+        let vertical = if has_vertical {
             let scrollbar_bounds = Rectangle {
-                x: bounds.x + bounds.width
-                    - f32::from(SCROLLBAR_WIDTH + 2 * SCROLLBAR_MARGIN),
+                x: bounds.x + bounds.width - thickness,
                 y: bounds.y,
-                width: f32::from(SCROLLBAR_WIDTH + 2 * SCROLLBAR_MARGIN),
-                height: bounds.height,
+                width: thickness,
+                height: if has_horizontal {
+                    bounds.height - thickness
+                } else {
+                    bounds.height
+                },
             };
 
-            let ratio = bounds.height / content_bounds.height;
-            let scrollbar_height = bounds.height * ratio;
-            let y_offset = offset as f32 * ratio;
+            let ratio = scrollbar_bounds.height / content_bounds.height;
+
+            Some(scrollable::Scrollbar {
+                orientation: scrollable::Orientation::Vertical,
+                bounds: scrollbar_bounds,
+                scroller: scrollable::Scroller {
+                    bounds: Rectangle {
+                        x: scrollbar_bounds.x + f32::from(SCROLLBAR_MARGIN),
+                        y: scrollbar_bounds.y + offset.y as f32 * ratio,
+                        width: f32::from(SCROLLBAR_WIDTH),
+                        height: bounds.height / content_bounds.height
+                            * scrollbar_bounds.height,
+                    },
+                },
+            })
+        } else {
+            None
+        };
 
-            let scroller_bounds = Rectangle {
-                x: scrollbar_bounds.x + f32::from(SCROLLBAR_MARGIN),
-                y: scrollbar_bounds.y + y_offset,
-                width: scrollbar_bounds.width - f32::from(2 * SCROLLBAR_MARGIN),
-                height: scrollbar_height,
+        let horizontal = if has_horizontal {
+            let scrollbar_bounds = Rectangle {
+                x: bounds.x,
+                y: bounds.y + bounds.height - thickness,
+                width: if has_vertical {
+                    bounds.width - thickness
+                } else {
+                    bounds.width
+                },
+                height: thickness,
             };
 
+            let ratio = scrollbar_bounds.width / content_bounds.width;
+
             Some(scrollable::Scrollbar {
+                orientation: scrollable::Orientation::Horizontal,
                 bounds: scrollbar_bounds,
                 scroller: scrollable::Scroller {
-                    bounds: scroller_bounds,
+                    bounds: Rectangle {
+                        x: scrollbar_bounds.x + offset.x as f32 * ratio,
+                        y: scrollbar_bounds.y + f32::from(SCROLLBAR_MARGIN),
+                        width: bounds.width / content_bounds.width
+                            * scrollbar_bounds.width,
+                        height: f32::from(SCROLLBAR_WIDTH),
+                    },
                 },
             })
         } else {
             None
+        };
+
+        scrollable::Scrollbars {
+            vertical,
+            horizontal,
         }
     }
 
@@ -67,72 +116,109 @@ where
         bounds: Rectangle,
         _content_bounds: Rectangle,
         is_mouse_over: bool,
-        is_mouse_over_scrollbar: bool,
-        scrollbar: Option<scrollable::Scrollbar>,
-        offset: u32,
+        mouse_over_scrollbar: Option<scrollable::Orientation>,
+        scrollbars: scrollable::Scrollbars,
+        offset: Vector<u32>,
         style_sheet: &Self::Style,
         (content, mouse_interaction): Self::Output,
     ) -> Self::Output {
+        if state.is_animating() {
+            self.request_redraw(Instant::now());
+        }
+
+        let is_scrollable =
+            scrollbars.vertical.is_some() || scrollbars.horizontal.is_some();
+
         (
-            if let Some(scrollbar) = scrollbar {
-                let clip = Primitive::Clip {
+            if is_scrollable {
+                let mut primitives = vec![Primitive::Clip {
                     bounds,
-                    offset: Vector::new(0, offset),
+                    offset,
                     content: Box::new(content),
-                };
+                }];
 
-                let style = if state.is_scroller_grabbed() {
-                    style_sheet.dragging()
-                } else if is_mouse_over_scrollbar {
-                    style_sheet.hovered()
-                } else {
-                    style_sheet.active()
-                };
+                for scrollbar in scrollbars
+                    .vertical
+                    .iter()
+                    .chain(scrollbars.horizontal.iter())
+                {
+                    let is_grabbed =
+                        state.grabbed_scroller() == Some(scrollbar.orientation);
+                    let is_hovered =
+                        mouse_over_scrollbar == Some(scrollbar.orientation);
 
-                let is_scrollbar_visible =
-                    style.background.is_some() || style.border_width > 0;
+                    let style = match scrollbar.orientation {
+                        scrollable::Orientation::Vertical => {
+                            if is_grabbed {
+                                style_sheet.dragging(self.theme())
+                            } else if is_hovered {
+                                style_sheet.hovered(self.theme())
+                            } else {
+                                style_sheet.active(self.theme())
+                            }
+                        }
+                        scrollable::Orientation::Horizontal => {
+                            if is_grabbed {
+                                style_sheet.dragging_horizontal(self.theme())
+                            } else if is_hovered {
+                                style_sheet.hovered_horizontal(self.theme())
+                            } else {
+                                style_sheet.active_horizontal(self.theme())
+                            }
+                        }
+                    };
 
-                let scroller = if is_mouse_over
-                    || state.is_scroller_grabbed()
-                    || is_scrollbar_visible
-                {
-                    Primitive::Quad {
-                        bounds: scrollbar.scroller.bounds,
-                        background: Background::Color(style.scroller.color),
-                        border_radius: style.scroller.border_radius,
-                        border_width: style.scroller.border_width,
-                        border_color: style.scroller.border_color,
-                    }
-                } else {
-                    Primitive::None
-                };
-
-                let scrollbar = if is_scrollbar_visible {
-                    Primitive::Quad {
-                        bounds: Rectangle {
-                            x: scrollbar.bounds.x + f32::from(SCROLLBAR_MARGIN),
-                            width: scrollbar.bounds.width
-                                - f32::from(2 * SCROLLBAR_MARGIN),
-                            ..scrollbar.bounds
-                        },
-                        background: style
-                            .background
-                            .unwrap_or(Background::Color(Color::TRANSPARENT)),
-                        border_radius: style.border_radius,
-                        border_width: style.border_width,
-                        border_color: style.border_color,
+                    let is_scrollbar_visible = style.background.is_some()
+                        || !style.border_width.is_zero();
+
+                    if is_scrollbar_visible {
+                        let margin = f32::from(SCROLLBAR_MARGIN);
+
+                        let bounds = match scrollbar.orientation {
+                            scrollable::Orientation::Vertical => Rectangle {
+                                x: scrollbar.bounds.x + margin,
+                                width: scrollbar.bounds.width - 2.0 * margin,
+                                ..scrollbar.bounds
+                            },
+                            scrollable::Orientation::Horizontal => Rectangle {
+                                y: scrollbar.bounds.y + margin,
+                                height: scrollbar.bounds.height - 2.0 * margin,
+                                ..scrollbar.bounds
+                            },
+                        };
+
+                        primitives.push(Primitive::Quad {
+                            bounds,
+                            background: style.background.unwrap_or(
+                                Background::Color(Color::TRANSPARENT),
+                            ),
+                            border_radius: style.border_radius,
+                            border_width: style.border_width,
+                            border_color: style.border_color,
+                            shadow: Shadow::NONE,
+                        });
                     }
-                } else {
-                    Primitive::None
-                };
 
-                Primitive::Group {
-                    primitives: vec![clip, scrollbar, scroller],
+                    if is_mouse_over
+                        || state.is_scroller_grabbed()
+                        || is_scrollbar_visible
+                    {
+                        primitives.push(Primitive::Quad {
+                            bounds: scrollbar.scroller.bounds,
+                            background: Background::Color(style.scroller.color),
+                            border_radius: style.scroller.border_radius,
+                            border_width: style.scroller.border_width,
+                            border_color: style.scroller.border_color,
+                            shadow: Shadow::NONE,
+                        });
+                    }
                 }
+
+                Primitive::Group { primitives }
             } else {
                 content
             },
-            if is_mouse_over_scrollbar || state.is_scroller_grabbed() {
+            if mouse_over_scrollbar.is_some() || state.is_scroller_grabbed() {
                 mouse::Interaction::Idle
             } else {
                 mouse_interaction
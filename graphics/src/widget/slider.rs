@@ -7,7 +7,7 @@
 use crate::{Backend, Primitive, Renderer};
 use iced_native::mouse;
 use iced_native::slider;
-use iced_native::{Background, Color, Point, Rectangle};
+use iced_native::{Background, Color, Point, Rectangle, Shadow};
 
 pub use iced_native::slider::State;
 pub use iced_style::slider::{Handle, HandleShape, Style, StyleSheet};
@@ -43,11 +43,11 @@ where
         let is_mouse_over = bounds.contains(cursor_position);
 
         let style = if is_dragging {
-            style_sheet.dragging()
+            style_sheet.dragging(self.theme())
         } else if is_mouse_over {
-            style_sheet.hovered()
+            style_sheet.hovered(self.theme())
         } else {
-            style_sheet.active()
+            style_sheet.active(self.theme())
         };
 
         let rail_y = bounds.y + (bounds.height / 2.0).round();
@@ -61,9 +61,10 @@ where
                     height: 2.0,
                 },
                 background: Background::Color(style.rail_colors.0),
-                border_radius: 0,
-                border_width: 0,
+                border_radius: 0.into(),
+                border_width: 0.into(),
                 border_color: Color::TRANSPARENT,
+                shadow: Shadow::NONE,
             },
             Primitive::Quad {
                 bounds: Rectangle {
@@ -73,9 +74,10 @@ where
                     height: 2.0,
                 },
                 background: Background::Color(style.rail_colors.1),
-                border_radius: 0,
-                border_width: 0,
+                border_radius: 0.into(),
+                border_width: 0.into(),
                 border_color: Color::TRANSPARENT,
+                shadow: Shadow::NONE,
             },
         );
 
@@ -84,7 +86,9 @@ where
         let (handle_width, handle_height, handle_border_radius) =
             match style.handle.shape {
                 HandleShape::Circle { radius } => {
-                    (f32::from(radius * 2), f32::from(radius * 2), radius)
+                    let diameter = f32::from(radius * 2);
+
+                    (diameter, diameter, radius.into())
                 }
                 HandleShape::Rectangle {
                     width,
@@ -106,6 +110,7 @@ where
             border_radius: handle_border_radius,
             border_width: style.handle.border_width,
             border_color: style.handle.border_color,
+            shadow: Shadow::NONE,
         };
 
         (
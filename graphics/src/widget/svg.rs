@@ -1,7 +1,8 @@
 //! Display vector graphics in your application.
 use crate::backend::{self, Backend};
+use crate::image;
 use crate::{Primitive, Renderer};
-use iced_native::{mouse, svg, Layout};
+use iced_native::{mouse, svg, Color, Layout, Rectangle};
 
 pub use iced_native::svg::{Handle, Svg};
 
@@ -16,13 +17,18 @@ where
     fn draw(
         &mut self,
         handle: svg::Handle,
+        color: Option<Color>,
         layout: Layout<'_>,
+        image_bounds: Rectangle,
     ) -> Self::Output {
+        let svg = Primitive::Svg {
+            handle,
+            color,
+            bounds: image_bounds,
+        };
+
         (
-            Primitive::Svg {
-                handle,
-                bounds: layout.bounds(),
-            },
+            image::clip(svg, layout.bounds(), image_bounds),
             mouse::Interaction::default(),
         )
     }
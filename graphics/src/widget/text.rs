@@ -4,7 +4,8 @@ use crate::{Primitive, Renderer};
 use iced_native::mouse;
 use iced_native::text;
 use iced_native::{
-    Color, Font, HorizontalAlignment, Rectangle, Size, VerticalAlignment,
+    Color, Font, HorizontalAlignment, LineHeight, Overflow, Rectangle, Size,
+    VerticalAlignment, Wrapping,
 };
 
 /// A paragraph of text.
@@ -27,10 +28,18 @@ where
         content: &str,
         size: u16,
         font: Font,
+        line_height: LineHeight,
+        wrapping: Wrapping,
         bounds: Size,
     ) -> (f32, f32) {
-        self.backend()
-            .measure(content, f32::from(size), font, bounds)
+        self.backend().measure(
+            content,
+            f32::from(size),
+            font,
+            line_height,
+            wrapping,
+            bounds,
+        )
     }
 
     fn draw(
@@ -43,6 +52,9 @@ where
         color: Option<Color>,
         horizontal_alignment: HorizontalAlignment,
         vertical_alignment: VerticalAlignment,
+        line_height: LineHeight,
+        wrapping: Wrapping,
+        overflow: Overflow,
     ) -> Self::Output {
         let x = match horizontal_alignment {
             iced_native::HorizontalAlignment::Left => bounds.x,
@@ -65,6 +77,9 @@ where
                 font,
                 horizontal_alignment,
                 vertical_alignment,
+                line_height,
+                wrapping,
+                overflow,
             },
             mouse::Interaction::default(),
         )
@@ -0,0 +1,245 @@
+//! Display fields that can be filled with multiple lines of text.
+//!
+//! A [`TextEditor`] has some local [`State`].
+//!
+//! [`TextEditor`]: struct.TextEditor.html
+//! [`State`]: struct.State.html
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::text_editor::{self, Line};
+use iced_native::text_input::{cursor, Value};
+use iced_native::{
+    Background, Color, Font, HorizontalAlignment, LineHeight, Overflow, Point,
+    Rectangle, Shadow, Size, Vector, VerticalAlignment, Wrapping,
+};
+use std::f32;
+
+pub use iced_native::text_editor::State;
+pub use iced_style::text_editor::{Style, StyleSheet};
+
+/// A field that can be filled with multiple lines of text.
+///
+/// This is an alias of an `iced_native` text editor with an
+/// `iced_wgpu::Renderer`.
+pub type TextEditor<'a, Message, Backend> =
+    iced_native::TextEditor<'a, Message, Renderer<Backend>>;
+
+impl<B> text_editor::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Font = Font;
+    type Style = Box<dyn StyleSheet>;
+
+    fn default_size(&self) -> u16 {
+        // TODO: Make this configurable
+        20
+    }
+
+    fn measure_value(&self, value: &str, size: u16, font: Font) -> f32 {
+        let backend = self.backend();
+
+        let (width, _) = backend.measure(
+            value,
+            f32::from(size),
+            font,
+            LineHeight::default(),
+            Wrapping::None,
+            Size::INFINITY,
+        );
+
+        width
+    }
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        text_bounds: Rectangle,
+        cursor_position: Point,
+        font: Font,
+        size: u16,
+        placeholder: &str,
+        value: &Value,
+        lines: &[Line],
+        state: &State,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if state.is_focused() {
+            style_sheet.focused(self.theme())
+        } else if is_mouse_over {
+            style_sheet.hovered(self.theme())
+        } else {
+            style_sheet.active(self.theme())
+        };
+
+        let editor = Primitive::Quad {
+            bounds,
+            background: style.background,
+            border_radius: style.border_radius,
+            border_width: style.border_width,
+            border_color: style.border_color,
+            shadow: style.shadow,
+        };
+
+        let line_height = f32::from(size);
+
+        // The value may have changed since the offset was last updated
+        let offset = state
+            .offset()
+            .min(lines.len() as f32 * line_height - text_bounds.height)
+            .max(0.0);
+
+        let is_visible = |index: usize| {
+            let top = index as f32 * line_height;
+
+            top + line_height > offset && top < offset + text_bounds.height
+        };
+
+        let line_bounds = |index: usize| Rectangle {
+            x: text_bounds.x,
+            y: text_bounds.y + index as f32 * line_height,
+            width: f32::INFINITY,
+            height: line_height,
+        };
+
+        let measure = |renderer: &Self, line: &Line, position: usize| {
+            renderer.measure_value(
+                &value.select(line.start, position).to_string(),
+                size,
+                font.clone(),
+            )
+        };
+
+        let mut primitives = Vec::new();
+
+        if state.is_focused() {
+            match state.cursor().state(value) {
+                cursor::State::Index(position) => {
+                    let index = text_editor::find_line(lines, position);
+                    let line = &lines[index];
+
+                    let x = measure(self, line, position);
+
+                    primitives.push(Primitive::Quad {
+                        bounds: Rectangle {
+                            x: text_bounds.x + x,
+                            width: 1.0,
+                            ..line_bounds(index)
+                        },
+                        background: Background::Color(
+                            style_sheet.value_color(self.theme()),
+                        ),
+                        border_radius: 0.into(),
+                        border_width: 0.into(),
+                        border_color: Color::TRANSPARENT,
+                        shadow: Shadow::NONE,
+                    });
+                }
+                cursor::State::Selection { start, end } => {
+                    let left = start.min(end);
+                    let right = end.max(start);
+
+                    for (index, line) in lines.iter().enumerate() {
+                        if line.end < left
+                            || line.start > right
+                            || !is_visible(index)
+                        {
+                            continue;
+                        }
+
+                        let from = measure(self, line, left.max(line.start));
+                        let to = measure(self, line, right.min(line.end));
+
+                        let has_line_break = lines
+                            .get(index + 1)
+                            .map(|next| next.start > line.end)
+                            .unwrap_or(false);
+
+                        // Show the selected line breaks, so selected empty
+                        // lines are visible
+                        let line_break = if has_line_break && right > line.end {
+                            line_height / 4.0
+                        } else {
+                            0.0
+                        };
+
+                        primitives.push(Primitive::Quad {
+                            bounds: Rectangle {
+                                x: text_bounds.x + from,
+                                width: to - from + line_break,
+                                ..line_bounds(index)
+                            },
+                            background: Background::Color(
+                                style_sheet.selection_color(self.theme()),
+                            ),
+                            border_radius: 0.into(),
+                            border_width: 0.into(),
+                            border_color: Color::TRANSPARENT,
+                            shadow: Shadow::NONE,
+                        });
+                    }
+                }
+            }
+        }
+
+        if value.len() == 0 {
+            primitives.push(Primitive::Text {
+                content: placeholder.to_string(),
+                color: style_sheet.placeholder_color(self.theme()),
+                font,
+                bounds: Rectangle {
+                    width: text_bounds.width,
+                    height: f32::INFINITY,
+                    ..text_bounds
+                },
+                size: f32::from(size),
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Top,
+                line_height: LineHeight::default(),
+                wrapping: Wrapping::default(),
+                overflow: Overflow::default(),
+            });
+        } else {
+            let color = style_sheet.value_color(self.theme());
+
+            for (index, line) in lines.iter().enumerate() {
+                if line.start == line.end || !is_visible(index) {
+                    continue;
+                }
+
+                primitives.push(Primitive::Text {
+                    content: value.select(line.start, line.end).to_string(),
+                    color,
+                    font: font.clone(),
+                    bounds: line_bounds(index),
+                    size: f32::from(size),
+                    horizontal_alignment: HorizontalAlignment::Left,
+                    vertical_alignment: VerticalAlignment::Top,
+                    line_height: LineHeight::default(),
+                    wrapping: Wrapping::default(),
+                    overflow: Overflow::default(),
+                });
+            }
+        }
+
+        let contents = Primitive::Clip {
+            bounds: text_bounds,
+            offset: Vector::new(0, offset as u32),
+            content: Box::new(Primitive::Group { primitives }),
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![editor, contents],
+            },
+            if is_mouse_over {
+                mouse::Interaction::Text
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+}
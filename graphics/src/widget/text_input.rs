@@ -5,16 +5,17 @@
 //! [`TextInput`]: struct.TextInput.html
 //! [`State`]: struct.State.html
 use crate::backend::{self, Backend};
+use crate::text::Cluster;
 use crate::{Primitive, Renderer};
 use iced_native::mouse;
 use iced_native::text_input::{self, cursor};
 use iced_native::{
-    Background, Color, Font, HorizontalAlignment, Point, Rectangle, Size,
-    Vector, VerticalAlignment,
+    Background, Color, Font, HorizontalAlignment, LineHeight, Overflow, Point,
+    Rectangle, Shadow, Size, Vector, VerticalAlignment, Wrapping,
 };
 use std::f32;
 
-pub use iced_native::text_input::State;
+pub use iced_native::text_input::{History, Mask, State};
 pub use iced_style::text_input::{Style, StyleSheet};
 
 /// A field that can be filled with text.
@@ -38,8 +39,14 @@ where
     fn measure_value(&self, value: &str, size: u16, font: Font) -> f32 {
         let backend = self.backend();
 
-        let (width, _) =
-            backend.measure(value, f32::from(size), font, Size::INFINITY);
+        let (width, _) = backend.measure(
+            value,
+            f32::from(size),
+            font,
+            LineHeight::default(),
+            Wrapping::None,
+            Size::INFINITY,
+        );
 
         width
     }
@@ -60,13 +67,17 @@ where
                 cursor::State::Selection { end, .. } => end,
             };
 
+            let clusters = self.backend().clusters(
+                &value.to_string(),
+                f32::from(size),
+                font,
+            );
+
             let (_, offset) = measure_cursor_and_scroll_offset(
-                self,
                 text_bounds,
                 value,
-                size,
+                &clusters,
                 focus_position,
-                font,
             );
 
             offset
@@ -75,6 +86,25 @@ where
         }
     }
 
+    fn find_cursor_position(
+        &self,
+        text_bounds: Rectangle,
+        font: Font,
+        size: Option<u16>,
+        value: &text_input::Value,
+        state: &text_input::State,
+        x: f32,
+    ) -> usize {
+        let size = size.unwrap_or(self.default_size());
+        let offset = self.offset(text_bounds, font.clone(), size, value, state);
+
+        let clusters =
+            self.backend()
+                .clusters(&value.to_string(), f32::from(size), font);
+
+        find_cursor_position(value, &clusters, x + offset)
+    }
+
     fn draw(
         &mut self,
         bounds: Rectangle,
@@ -90,11 +120,11 @@ where
         let is_mouse_over = bounds.contains(cursor_position);
 
         let style = if state.is_focused() {
-            style_sheet.focused()
+            style_sheet.focused(self.theme())
         } else if is_mouse_over {
-            style_sheet.hovered()
+            style_sheet.hovered(self.theme())
         } else {
-            style_sheet.active()
+            style_sheet.active(self.theme())
         };
 
         let input = Primitive::Quad {
@@ -103,6 +133,7 @@ where
             border_radius: style.border_radius,
             border_width: style.border_width,
             border_color: style.border_color,
+            shadow: style.shadow,
         };
 
         let text = value.to_string();
@@ -114,11 +145,11 @@ where
                 text.clone()
             },
             color: if text.is_empty() {
-                style_sheet.placeholder_color()
+                style_sheet.placeholder_color(self.theme())
             } else {
-                style_sheet.value_color()
+                style_sheet.value_color(self.theme())
             },
-            font,
+            font: font.clone(),
             bounds: Rectangle {
                 y: text_bounds.center_y(),
                 width: f32::INFINITY,
@@ -127,21 +158,24 @@ where
             size: f32::from(size),
             horizontal_alignment: HorizontalAlignment::Left,
             vertical_alignment: VerticalAlignment::Center,
+            line_height: LineHeight::default(),
+            wrapping: Wrapping::default(),
+            overflow: Overflow::default(),
         };
 
         let (contents_primitive, offset) = if state.is_focused() {
             let cursor = state.cursor();
+            let clusters =
+                self.backend().clusters(&text, f32::from(size), font.clone());
 
             let (cursor_primitive, offset) = match cursor.state(value) {
                 cursor::State::Index(position) => {
                     let (text_value_width, offset) =
                         measure_cursor_and_scroll_offset(
-                            self,
                             text_bounds,
                             value,
-                            size,
+                            &clusters,
                             position,
-                            font,
                         );
 
                     (
@@ -153,61 +187,56 @@ where
                                 height: text_bounds.height,
                             },
                             background: Background::Color(
-                                style_sheet.value_color(),
+                                style_sheet.value_color(self.theme()),
                             ),
-                            border_radius: 0,
-                            border_width: 0,
+                            border_radius: 0.into(),
+                            border_width: 0.into(),
                             border_color: Color::TRANSPARENT,
+                            shadow: Shadow::NONE,
                         },
                         offset,
                     )
                 }
                 cursor::State::Selection { start, end } => {
-                    let left = start.min(end);
-                    let right = end.max(start);
+                    let (_, offset) = measure_cursor_and_scroll_offset(
+                        text_bounds,
+                        value,
+                        &clusters,
+                        end,
+                    );
 
-                    let (left_position, left_offset) =
-                        measure_cursor_and_scroll_offset(
-                            self,
-                            text_bounds,
-                            value,
-                            size,
-                            left,
-                            font,
-                        );
-
-                    let (right_position, right_offset) =
-                        measure_cursor_and_scroll_offset(
-                            self,
-                            text_bounds,
-                            value,
-                            size,
-                            right,
-                            font,
-                        );
-
-                    let width = right_position - left_position;
+                    // A selection of bidirectional text may be split in
+                    // many visual spans
+                    let spans = selection_spans(
+                        value,
+                        &clusters,
+                        start.min(end),
+                        start.max(end),
+                    );
 
                     (
-                        Primitive::Quad {
-                            bounds: Rectangle {
-                                x: text_bounds.x + left_position,
-                                y: text_bounds.y,
-                                width,
-                                height: text_bounds.height,
-                            },
-                            background: Background::Color(
-                                style_sheet.selection_color(),
-                            ),
-                            border_radius: 0,
-                            border_width: 0,
-                            border_color: Color::TRANSPARENT,
-                        },
-                        if end == right {
-                            right_offset
-                        } else {
-                            left_offset
+                        Primitive::Group {
+                            primitives: spans
+                                .into_iter()
+                                .map(|(x, width)| Primitive::Quad {
+                                    bounds: Rectangle {
+                                        x: text_bounds.x + x,
+                                        y: text_bounds.y,
+                                        width,
+                                        height: text_bounds.height,
+                                    },
+                                    background: Background::Color(
+                                        style_sheet
+                                            .selection_color(self.theme()),
+                                    ),
+                                    border_radius: 0.into(),
+                                    border_width: 0.into(),
+                                    border_color: Color::TRANSPARENT,
+                                    shadow: Shadow::NONE,
+                                })
+                                .collect(),
                         },
+                        offset,
                     )
                 }
             };
@@ -251,24 +280,183 @@ where
     }
 }
 
-fn measure_cursor_and_scroll_offset<B>(
-    renderer: &Renderer<B>,
+fn measure_cursor_and_scroll_offset(
     text_bounds: Rectangle,
     value: &text_input::Value,
-    size: u16,
+    clusters: &[Cluster],
     cursor_index: usize,
-    font: Font,
-) -> (f32, f32)
-where
-    B: Backend + backend::Text,
-{
-    use iced_native::text_input::Renderer;
+) -> (f32, f32) {
+    let position = caret_position(clusters, offset_of(value, cursor_index));
+    let offset = ((position + 5.0) - text_bounds.width).max(0.0);
 
-    let text_before_cursor = value.until(cursor_index).to_string();
+    (position, offset)
+}
 
-    let text_value_width =
-        renderer.measure_value(&text_before_cursor, size, font);
-    let offset = ((text_value_width + 5.0) - text_bounds.width).max(0.0);
+/// Returns the grapheme index of the caret closest to the given horizontal
+/// position.
+fn find_cursor_position(
+    value: &text_input::Value,
+    clusters: &[Cluster],
+    x: f32,
+) -> usize {
+    (0..=value.len())
+        .map(|index| {
+            let position = caret_position(clusters, offset_of(value, index));
 
-    (text_value_width, offset)
+            (index, (position - x).abs())
+        })
+        .fold((0, f32::INFINITY), |closest, candidate| {
+            if candidate.1 < closest.1 {
+                candidate
+            } else {
+                closest
+            }
+        })
+        .0
+}
+
+/// Returns the horizontal spans, as a position and a width, covered by the
+/// graphemes between the given indices, in visual order.
+fn selection_spans(
+    value: &text_input::Value,
+    clusters: &[Cluster],
+    start: usize,
+    end: usize,
+) -> Vec<(f32, f32)> {
+    let selected = offset_of(value, start)..offset_of(value, end);
+    let mut spans: Vec<(f32, f32)> = Vec::new();
+
+    for cluster in clusters {
+        if !selected.contains(&cluster.index) {
+            continue;
+        }
+
+        match spans.last_mut() {
+            Some((x, width)) if (*x + *width - cluster.x).abs() < 0.5 => {
+                *width += cluster.width;
+            }
+            _ => spans.push((cluster.x, cluster.width)),
+        }
+    }
+
+    spans
+}
+
+/// Returns the horizontal position of the caret placed at the given byte
+/// offset of a text laid out in the given clusters.
+///
+/// The caret is placed at the leading edge of the cluster starting at the
+/// offset, which is the right edge in right-to-left runs. An offset inside
+/// a cluster, like a ligature, is interpolated between its edges.
+fn caret_position(clusters: &[Cluster], offset: usize) -> f32 {
+    let cluster = match clusters
+        .iter()
+        .filter(|cluster| cluster.index <= offset)
+        .max_by_key(|cluster| cluster.index)
+    {
+        Some(cluster) => cluster,
+        None => return 0.0,
+    };
+
+    let (leading, trailing) = if cluster.is_rtl {
+        (cluster.x + cluster.width, cluster.x)
+    } else {
+        (cluster.x, cluster.x + cluster.width)
+    };
+
+    let next = clusters
+        .iter()
+        .map(|cluster| cluster.index)
+        .filter(|index| *index > cluster.index)
+        .min();
+
+    let progress = match next {
+        Some(next) => {
+            (offset - cluster.index) as f32 / (next - cluster.index) as f32
+        }
+        // The last cluster in logical order ends the text
+        None if offset > cluster.index => 1.0,
+        None => 0.0,
+    };
+
+    leading + (trailing - leading) * progress
+}
+
+/// Returns the byte offset of the grapheme at the given index.
+fn offset_of(value: &text_input::Value, index: usize) -> usize {
+    value.graphemes().take(index).map(str::len).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cluster(index: usize, x: f32, width: f32, is_rtl: bool) -> Cluster {
+        Cluster {
+            index,
+            x,
+            width,
+            is_rtl,
+        }
+    }
+
+    #[test]
+    fn caret_follows_left_to_right_clusters() {
+        let clusters =
+            [cluster(0, 0.0, 10.0, false), cluster(1, 10.0, 10.0, false)];
+
+        assert_eq!(caret_position(&clusters, 0), 0.0);
+        assert_eq!(caret_position(&clusters, 1), 10.0);
+        assert_eq!(caret_position(&clusters, 2), 20.0);
+        assert_eq!(caret_position(&[], 0), 0.0);
+    }
+
+    #[test]
+    fn caret_follows_right_to_left_clusters() {
+        // Logical order is reversed visually
+        let clusters =
+            [cluster(2, 0.0, 10.0, true), cluster(0, 10.0, 10.0, true)];
+
+        assert_eq!(caret_position(&clusters, 0), 20.0);
+        assert_eq!(caret_position(&clusters, 2), 10.0);
+        assert_eq!(caret_position(&clusters, 4), 0.0);
+    }
+
+    #[test]
+    fn caret_is_interpolated_inside_ligatures() {
+        let clusters =
+            [cluster(0, 0.0, 20.0, false), cluster(2, 20.0, 10.0, false)];
+
+        assert_eq!(caret_position(&clusters, 1), 10.0);
+    }
+
+    #[test]
+    fn selection_is_split_in_visual_spans() {
+        let value = text_input::Value::new("abcd");
+
+        // "ab" left to right, followed by "cd" right to left
+        let clusters = [
+            cluster(0, 0.0, 10.0, false),
+            cluster(1, 10.0, 10.0, false),
+            cluster(3, 20.0, 10.0, true),
+            cluster(2, 30.0, 10.0, true),
+        ];
+
+        assert_eq!(
+            selection_spans(&value, &clusters, 1, 3),
+            vec![(10.0, 10.0), (30.0, 10.0)]
+        );
+        assert_eq!(selection_spans(&value, &clusters, 0, 4), vec![(0.0, 40.0)]);
+    }
+
+    #[test]
+    fn cursor_is_found_at_the_closest_caret() {
+        let value = text_input::Value::new("ab");
+        let clusters =
+            [cluster(0, 0.0, 10.0, false), cluster(1, 10.0, 10.0, false)];
+
+        assert_eq!(find_cursor_position(&value, &clusters, 4.0), 0);
+        assert_eq!(find_cursor_position(&value, &clusters, 6.0), 1);
+        assert_eq!(find_cursor_position(&value, &clusters, 50.0), 2);
+    }
 }
@@ -1,10 +1,12 @@
 //! Draw graphics to window surfaces.
 mod compositor;
+mod redraw;
 
 #[cfg(feature = "opengl")]
 mod gl_compositor;
 
 pub use compositor::Compositor;
+pub use redraw::Redraw;
 
 #[cfg(feature = "opengl")]
 pub use gl_compositor::GLCompositor;
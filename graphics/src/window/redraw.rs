@@ -0,0 +1,23 @@
+use crate::{Backend, Renderer};
+
+use std::time::Instant;
+
+/// A renderer that can ask for another frame to be drawn.
+///
+/// Shells use this trait to keep drawing frames while a widget is animating.
+pub trait Redraw {
+    /// Returns the earliest [`Instant`] a new frame was requested at while
+    /// drawing the last one, clearing the request.
+    ///
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    fn take_redraw_request(&mut self) -> Option<Instant>;
+}
+
+impl<B> Redraw for Renderer<B>
+where
+    B: Backend,
+{
+    fn take_redraw_request(&mut self) -> Option<Instant> {
+        Renderer::take_redraw_request(self)
+    }
+}
@@ -1,3 +1,4 @@
+//! Handle events of a user interface.
 use crate::{keyboard, mouse, window};
 
 /// A user interface event.
@@ -17,3 +18,42 @@ pub enum Event {
     /// A window event
     Window(window::Event),
 }
+
+/// The status of an [`Event`] after being processed.
+///
+/// [`Event`]: enum.Event.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Event`] was __NOT__ handled by any widget.
+    ///
+    /// [`Event`]: enum.Event.html
+    Ignored,
+
+    /// The [`Event`] was handled and processed by a widget.
+    ///
+    /// [`Event`]: enum.Event.html
+    Captured,
+}
+
+impl Status {
+    /// Merges two [`Status`] into one.
+    ///
+    /// `Captured` takes precedence over `Ignored`:
+    ///
+    /// ```
+    /// use iced_native::event::Status;
+    ///
+    /// assert_eq!(Status::Ignored.merge(Status::Ignored), Status::Ignored);
+    /// assert_eq!(Status::Ignored.merge(Status::Captured), Status::Captured);
+    /// assert_eq!(Status::Captured.merge(Status::Ignored), Status::Captured);
+    /// assert_eq!(Status::Captured.merge(Status::Captured), Status::Captured);
+    /// ```
+    ///
+    /// [`Status`]: enum.Status.html
+    pub fn merge(self, b: Self) -> Self {
+        match self {
+            Status::Ignored => b,
+            Status::Captured => Status::Captured,
+        }
+    }
+}
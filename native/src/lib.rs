@@ -35,6 +35,7 @@
 #![deny(unused_results)]
 #![forbid(unsafe_code)]
 #![forbid(rust_2018_idioms)]
+pub mod event;
 pub mod keyboard;
 pub mod layout;
 pub mod mouse;
@@ -46,7 +47,6 @@ pub mod window;
 
 mod clipboard;
 mod element;
-mod event;
 mod hasher;
 mod runtime;
 mod user_interface;
@@ -61,8 +61,10 @@ mod debug;
 mod debug;
 
 pub use iced_core::{
-    Align, Background, Color, Font, HorizontalAlignment, Length, Point,
-    Rectangle, Size, Vector, VerticalAlignment,
+    font, gradient, Align, Background, BorderRadius, BorderWidth, Color,
+    ContentFit, Font, Gradient, HorizontalAlignment, Length, LineHeight,
+    Overflow, Point, Rectangle, Shadow, Size, Vector, VerticalAlignment,
+    Wrapping,
 };
 pub use iced_futures::{executor, futures, Command};
 
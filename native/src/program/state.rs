@@ -1,6 +1,6 @@
 use crate::{
-    Cache, Clipboard, Command, Debug, Event, Program, Renderer, Size,
-    UserInterface,
+    event::{self, Event},
+    Cache, Clipboard, Command, Debug, Program, Renderer, Size, UserInterface,
 };
 
 /// The execution state of a [`Program`]. It leverages caching, event
@@ -95,20 +95,33 @@ where
         self.queued_events.is_empty() && self.queued_messages.is_empty()
     }
 
+    /// Invalidates the layout of the widgets of the [`State`], computing it
+    /// again during the next [`update`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`update`]: #method.update
+    pub fn invalidate_layout(&mut self) {
+        if let Some(cache) = &mut self.cache {
+            cache.invalidate_layout();
+        }
+    }
+
     /// Processes all the queued events and messages, rebuilding and redrawing
     /// the widgets of the linked [`Program`] if necessary.
     ///
-    /// Returns the [`Command`] obtained from [`Program`] after updating it,
-    /// only if an update was necessary.
+    /// Returns the processed events, paired with their [`event::Status`], and
+    /// the [`Command`] obtained from [`Program`] after updating it, only if an
+    /// update was necessary.
     ///
     /// [`Program`]: trait.Program.html
+    /// [`event::Status`]: ../event/enum.Status.html
     pub fn update(
         &mut self,
         clipboard: Option<&dyn Clipboard>,
         bounds: Size,
         renderer: &mut P::Renderer,
         debug: &mut Debug,
-    ) -> Option<Command<P::Message>> {
+    ) -> (Vec<(Event, event::Status)>, Option<Command<P::Message>>) {
         let mut user_interface = build_user_interface(
             &mut self.program,
             self.cache.take().unwrap(),
@@ -118,11 +131,17 @@ where
         );
 
         debug.event_processing_started();
-        let mut messages = user_interface.update(
-            self.queued_events.drain(..),
+        let mut messages = Vec::new();
+
+        let statuses = user_interface.update(
+            &self.queued_events,
             clipboard,
             renderer,
+            &mut messages,
         );
+
+        let events = self.queued_events.drain(..).zip(statuses).collect();
+
         messages.extend(self.queued_messages.drain(..));
         debug.event_processing_finished();
 
@@ -133,7 +152,7 @@ where
 
             self.cache = Some(user_interface.into_cache());
 
-            None
+            (events, None)
         } else {
             // When there are messages, we are forced to rebuild twice
             // for now :^)
@@ -164,7 +183,7 @@ where
 
             self.cache = Some(user_interface.into_cache());
 
-            Some(commands)
+            (events, Some(commands))
         }
     }
 }
@@ -45,6 +45,18 @@ pub trait Renderer: Sized {
     /// [`Renderer`]: trait.Renderer.html
     type Defaults: Default;
 
+    /// Returns the [`Defaults`] used to draw the root of a user interface.
+    ///
+    /// By default, it returns `Defaults::default()`. You should override this
+    /// if your defaults depend on the state of the [`Renderer`], like its
+    /// current theme.
+    ///
+    /// [`Defaults`]: #associatedtype.Defaults
+    /// [`Renderer`]: trait.Renderer.html
+    fn defaults(&self) -> Self::Defaults {
+        Self::Defaults::default()
+    }
+
     /// Lays out the elements of a user interface.
     ///
     /// You should override this if you need to perform any operations before or
@@ -1,9 +1,13 @@
+use crate::image::{self, FilterMethod};
 use crate::{
-    button, checkbox, column, progress_bar, radio, row, scrollable, slider,
-    text, text_input, Color, Element, Font, HorizontalAlignment, Layout, Point,
-    Rectangle, Renderer, Size, VerticalAlignment,
+    animated_image, button, checkbox, column, number_input, progress_bar,
+    radio, rich_text, row, scrollable, slider, text, text_editor, text_input,
+    Color, Element, Font, HorizontalAlignment, Layout, LineHeight, Overflow,
+    Point, Rectangle, Renderer, Size, Vector, VerticalAlignment, Wrapping,
 };
 
+use std::time::{Duration, Instant};
+
 /// A renderer that does nothing.
 ///
 /// It can be useful if you are writing tests!
@@ -56,6 +60,8 @@ impl text::Renderer for Null {
         _content: &str,
         _size: u16,
         _font: Font,
+        _line_height: LineHeight,
+        _wrapping: Wrapping,
         _bounds: Size,
     ) -> (f32, f32) {
         (0.0, 20.0)
@@ -71,6 +77,48 @@ impl text::Renderer for Null {
         _color: Option<Color>,
         _horizontal_alignment: HorizontalAlignment,
         _vertical_alignment: VerticalAlignment,
+        _line_height: LineHeight,
+        _wrapping: Wrapping,
+        _overflow: Overflow,
+    ) {
+    }
+}
+
+impl rich_text::Renderer for Null {
+    fn measure<Message>(
+        &self,
+        _spans: &[rich_text::Span<Message, Font>],
+        _size: u16,
+        _font: Font,
+        _bounds: Size,
+    ) -> (f32, f32) {
+        (0.0, 20.0)
+    }
+
+    fn hit_test<Message>(
+        &self,
+        _spans: &[rich_text::Span<Message, Font>],
+        _size: u16,
+        _font: Font,
+        _bounds: Rectangle,
+        _horizontal_alignment: HorizontalAlignment,
+        _vertical_alignment: VerticalAlignment,
+        _point: Point,
+    ) -> Option<usize> {
+        None
+    }
+
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        _spans: &[rich_text::Span<Message, Font>],
+        _size: u16,
+        _font: Font,
+        _color: Option<Color>,
+        _horizontal_alignment: HorizontalAlignment,
+        _vertical_alignment: VerticalAlignment,
+        _hovered_link: Option<usize>,
     ) {
     }
 }
@@ -78,13 +126,14 @@ impl text::Renderer for Null {
 impl scrollable::Renderer for Null {
     type Style = ();
 
-    fn scrollbar(
+    fn scrollbars(
         &self,
         _bounds: Rectangle,
         _content_bounds: Rectangle,
-        _offset: u32,
-    ) -> Option<scrollable::Scrollbar> {
-        None
+        _offset: Vector<u32>,
+        _direction: scrollable::Direction,
+    ) -> scrollable::Scrollbars {
+        scrollable::Scrollbars::default()
     }
 
     fn draw(
@@ -93,9 +142,9 @@ impl scrollable::Renderer for Null {
         _bounds: Rectangle,
         _content_bounds: Rectangle,
         _is_mouse_over: bool,
-        _is_mouse_over_scrollbar: bool,
-        _scrollbar: Option<scrollable::Scrollbar>,
-        _offset: u32,
+        _mouse_over_scrollbar: Option<scrollable::Orientation>,
+        _scrollbars: scrollable::Scrollbars,
+        _offset: Vector<u32>,
         _style: &Self::Style,
         _content: Self::Output,
     ) {
@@ -140,6 +189,52 @@ impl text_input::Renderer for Null {
     }
 }
 
+impl text_editor::Renderer for Null {
+    type Font = Font;
+    type Style = ();
+
+    fn default_size(&self) -> u16 {
+        20
+    }
+
+    fn measure_value(&self, _value: &str, _size: u16, _font: Font) -> f32 {
+        0.0
+    }
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _text_bounds: Rectangle,
+        _cursor_position: Point,
+        _font: Font,
+        _size: u16,
+        _placeholder: &str,
+        _value: &text_input::Value,
+        _lines: &[text_editor::Line],
+        _state: &text_editor::State,
+        _style: &Self::Style,
+    ) -> Self::Output {
+    }
+}
+
+impl number_input::Renderer for Null {
+    type Style = ();
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _input: Self::Output,
+        _increment_bounds: Rectangle,
+        _decrement_bounds: Rectangle,
+        _can_increment: bool,
+        _can_decrement: bool,
+        _is_valid: bool,
+        _style: &Self::Style,
+    ) {
+    }
+}
+
 impl button::Renderer for Null {
     const DEFAULT_PADDING: u16 = 0;
 
@@ -212,6 +307,38 @@ impl slider::Renderer for Null {
     }
 }
 
+impl image::Renderer for Null {
+    fn dimensions(&self, _handle: &image::Handle) -> (u32, u32) {
+        (1, 1)
+    }
+
+    fn draw(
+        &mut self,
+        _handle: image::Handle,
+        _filter_method: FilterMethod,
+        _layout: Layout<'_>,
+        _image_bounds: Rectangle,
+    ) {
+    }
+}
+
+impl animated_image::Renderer for Null {
+    fn frame_delays(&self, _handle: &image::Handle) -> Vec<Duration> {
+        Vec::new()
+    }
+
+    fn draw(
+        &mut self,
+        _handle: image::Handle,
+        _frame: usize,
+        _next_frame: Option<Instant>,
+        _filter_method: FilterMethod,
+        _layout: Layout<'_>,
+        _image_bounds: Rectangle,
+    ) {
+    }
+}
+
 impl progress_bar::Renderer for Null {
     type Style = ();
 
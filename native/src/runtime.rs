@@ -1,5 +1,6 @@
 //! Run commands and subscriptions.
-use crate::{Event, Hasher};
+use crate::event::{self, Event};
+use crate::Hasher;
 
 /// A native runtime with a generic executor and receiver of results.
 ///
@@ -8,5 +9,10 @@ use crate::{Event, Hasher};
 ///
 /// [`Command`]: ../struct.Command.html
 /// [`Subscription`]: ../struct.Subscription.html
-pub type Runtime<Executor, Receiver, Message> =
-    iced_futures::Runtime<Hasher, Event, Executor, Receiver, Message>;
+pub type Runtime<Executor, Receiver, Message> = iced_futures::Runtime<
+    Hasher,
+    (Event, event::Status),
+    Executor,
+    Receiver,
+    Message,
+>;
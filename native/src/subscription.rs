@@ -1,5 +1,6 @@
 //! Listen to external events in your application.
-use crate::{Event, Hasher};
+use crate::event::{self, Event};
+use crate::Hasher;
 use iced_futures::futures::stream::BoxStream;
 
 /// A request to listen to external events.
@@ -15,19 +16,26 @@ use iced_futures::futures::stream::BoxStream;
 ///
 /// [`Command`]: ../struct.Command.html
 /// [`Subscription`]: struct.Subscription.html
-pub type Subscription<T> = iced_futures::Subscription<Hasher, Event, T>;
+pub type Subscription<T> =
+    iced_futures::Subscription<Hasher, (Event, event::Status), T>;
 
 /// A stream of runtime events.
 ///
+/// Every [`Event`] is paired with its [`event::Status`] after being
+/// processed by the widgets of the user interface.
+///
 /// It is the input of a [`Subscription`] in the native runtime.
 ///
+/// [`Event`]: ../enum.Event.html
+/// [`event::Status`]: ../event/enum.Status.html
 /// [`Subscription`]: type.Subscription.html
-pub type EventStream = BoxStream<'static, Event>;
+pub type EventStream = BoxStream<'static, (Event, event::Status)>;
 
 /// A native [`Subscription`] tracker.
 ///
 /// [`Subscription`]: type.Subscription.html
-pub type Tracker = iced_futures::subscription::Tracker<Hasher, Event>;
+pub type Tracker =
+    iced_futures::subscription::Tracker<Hasher, (Event, event::Status)>;
 
 pub use iced_futures::subscription::Recipe;
 
@@ -43,5 +51,31 @@ use events::Events;
 /// [`Subscription`]: type.Subscription.html
 /// [`Event`]: ../enum.Event.html
 pub fn events() -> Subscription<Event> {
-    Subscription::from_recipe(Events)
+    events_with(|event, _status| Some(event))
+}
+
+/// Returns a [`Subscription`] that filters all the runtime events with the
+/// provided function, producing messages accordingly.
+///
+/// The function receives every [`Event`] together with its
+/// [`event::Status`], so you can ignore the events that have already been
+/// captured by a widget. Events for which the function returns `None` are
+/// discarded before reaching your application.
+///
+/// This subscription will call the provided function for every [`Event`]
+/// handled by the runtime. If the function:
+///
+/// - Returns `None`, the [`Event`] will be discarded.
+/// - Returns `Some` message, the `Message` will be produced.
+///
+/// [`Subscription`]: type.Subscription.html
+/// [`Event`]: ../enum.Event.html
+/// [`event::Status`]: ../event/enum.Status.html
+pub fn events_with<Message>(
+    f: fn(Event, event::Status) -> Option<Message>,
+) -> Subscription<Message>
+where
+    Message: 'static + Send,
+{
+    Subscription::from_recipe(Events { f })
 }
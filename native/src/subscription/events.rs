@@ -1,24 +1,38 @@
 use crate::{
+    event::{self, Event},
     subscription::{EventStream, Recipe},
-    Event, Hasher,
+    Hasher,
 };
+use iced_futures::futures::{future, StreamExt};
 use iced_futures::BoxStream;
 
-pub struct Events;
+pub struct Events<Message> {
+    pub(super) f: fn(Event, event::Status) -> Option<Message>,
+}
 
-impl Recipe<Hasher, Event> for Events {
-    type Output = Event;
+impl<Message> Recipe<Hasher, (Event, event::Status)> for Events<Message>
+where
+    Message: 'static + Send,
+{
+    type Output = Message;
 
     fn hash(&self, state: &mut Hasher) {
         use std::hash::Hash;
 
         std::any::TypeId::of::<Self>().hash(state);
+        (self.f as usize).hash(state);
     }
 
     fn stream(
         self: Box<Self>,
         event_stream: EventStream,
     ) -> BoxStream<Self::Output> {
-        event_stream
+        let f = self.f;
+
+        Box::pin(
+            event_stream.filter_map(move |(event, status)| {
+                future::ready(f(event, status))
+            }),
+        )
     }
 }
@@ -1,4 +1,7 @@
-use crate::{layout, mouse, Clipboard, Element, Event, Layout, Point, Size};
+use crate::{
+    event::{self, Event},
+    layout, mouse, Clipboard, Element, Layout, Point, Size,
+};
 
 use std::hash::Hasher;
 
@@ -121,11 +124,16 @@ where
 
     /// Updates the [`UserInterface`] by processing each provided [`Event`].
     ///
-    /// It returns __messages__ that may have been produced as a result of user
-    /// interactions. You should feed these to your __update logic__.
+    /// It pushes the __messages__ that may have been produced as a result of
+    /// user interactions into the provided list. You should feed these to
+    /// your __update logic__.
+    ///
+    /// It also returns the [`event::Status`] of every processed [`Event`], in
+    /// the same order, so you can tell which ones were captured by a widget.
     ///
     /// [`UserInterface`]: struct.UserInterface.html
     /// [`Event`]: enum.Event.html
+    /// [`event::Status`]: event/enum.Status.html
     ///
     /// # Example
     /// Let's allow our [counter](index.html#usage) to change state by
@@ -157,6 +165,7 @@ where
     ///
     /// // Initialize our event storage
     /// let mut events = Vec::new();
+    /// let mut messages = Vec::new();
     ///
     /// loop {
     ///     // Process system events...
@@ -169,40 +178,43 @@ where
     ///     );
     ///
     ///     // Update the user interface
-    ///     let messages = user_interface.update(events.drain(..), None, &renderer);
+    ///     let event_statuses =
+    ///         user_interface.update(&events, None, &renderer, &mut messages);
     ///
     ///     cache = user_interface.into_cache();
     ///
     ///     // Process the produced messages
-    ///     for message in messages {
+    ///     for message in messages.drain(..) {
     ///         counter.update(message);
     ///     }
     /// }
     /// ```
     pub fn update(
         &mut self,
-        events: impl IntoIterator<Item = Event>,
+        events: &[Event],
         clipboard: Option<&dyn Clipboard>,
         renderer: &Renderer,
-    ) -> Vec<Message> {
-        let mut messages = Vec::new();
-
-        for event in events {
-            if let Event::Mouse(mouse::Event::CursorMoved { x, y }) = event {
-                self.cursor_position = Point::new(x, y);
-            }
-
-            self.root.widget.on_event(
-                event,
-                Layout::new(&self.layout),
-                self.cursor_position,
-                &mut messages,
-                renderer,
-                clipboard,
-            );
-        }
+        messages: &mut Vec<Message>,
+    ) -> Vec<event::Status> {
+        events
+            .iter()
+            .cloned()
+            .map(|event| {
+                if let Event::Mouse(mouse::Event::CursorMoved { x, y }) = event
+                {
+                    self.cursor_position = Point::new(x, y);
+                }
 
-        messages
+                self.root.widget.on_event(
+                    event,
+                    Layout::new(&self.layout),
+                    self.cursor_position,
+                    messages,
+                    renderer,
+                    clipboard,
+                )
+            })
+            .collect()
     }
 
     /// Draws the [`UserInterface`] with the provided [`Renderer`].
@@ -242,6 +254,7 @@ where
     /// let mut renderer = Renderer::new();
     /// let mut window_size = Size::new(1024.0, 768.0);
     /// let mut events = Vec::new();
+    /// let mut messages = Vec::new();
     ///
     /// loop {
     ///     // Process system events...
@@ -253,14 +266,15 @@ where
     ///         &mut renderer,
     ///     );
     ///
-    ///     let messages = user_interface.update(events.drain(..), None, &renderer);
+    ///     let event_statuses =
+    ///         user_interface.update(&events, None, &renderer, &mut messages);
     ///
     ///     // Draw the user interface
     ///     let mouse_cursor = user_interface.draw(&mut renderer);
     ///
     ///     cache = user_interface.into_cache();
     ///
-    ///     for message in messages {
+    ///     for message in messages.drain(..) {
     ///         counter.update(message);
     ///     }
     ///
@@ -269,9 +283,11 @@ where
     /// }
     /// ```
     pub fn draw(&self, renderer: &mut Renderer) -> Renderer::Output {
+        let defaults = renderer.defaults();
+
         self.root.widget.draw(
             renderer,
-            &Renderer::Defaults::default(),
+            &defaults,
             Layout::new(&self.layout),
             self.cursor_position,
         )
@@ -319,6 +335,18 @@ impl Cache {
             cursor_position: Point::new(-1.0, -1.0),
         }
     }
+
+    /// Invalidates the layout stored in the [`Cache`], forcing the next
+    /// [`UserInterface`] built with it to compute its layout again.
+    ///
+    /// This is useful when the renderer changes in a way that affects the
+    /// layout of the widgets, like when a new font is loaded.
+    ///
+    /// [`Cache`]: struct.Cache.html
+    /// [`UserInterface`]: struct.UserInterface.html
+    pub fn invalidate_layout(&mut self) {
+        self.hash = 0;
+    }
 }
 
 impl Default for Cache {
@@ -20,22 +20,29 @@
 //!
 //! [`Widget`]: trait.Widget.html
 //! [renderer]: ../renderer/index.html
+pub mod animated_image;
 pub mod button;
 pub mod checkbox;
 pub mod column;
 pub mod container;
 pub mod image;
+pub mod list;
+pub mod number_input;
 pub mod pane_grid;
 pub mod progress_bar;
 pub mod radio;
+pub mod rich_text;
 pub mod row;
 pub mod scrollable;
 pub mod slider;
 pub mod space;
 pub mod svg;
 pub mod text;
+pub mod text_editor;
 pub mod text_input;
 
+#[doc(no_inline)]
+pub use animated_image::AnimatedImage;
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
@@ -47,12 +54,18 @@ pub use container::Container;
 #[doc(no_inline)]
 pub use image::Image;
 #[doc(no_inline)]
+pub use list::List;
+#[doc(no_inline)]
+pub use number_input::NumberInput;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use rich_text::RichText;
+#[doc(no_inline)]
 pub use row::Row;
 #[doc(no_inline)]
 pub use scrollable::Scrollable;
@@ -65,9 +78,14 @@ pub use svg::Svg;
 #[doc(no_inline)]
 pub use text::Text;
 #[doc(no_inline)]
+pub use text_editor::TextEditor;
+#[doc(no_inline)]
 pub use text_input::TextInput;
 
-use crate::{layout, Clipboard, Event, Hasher, Layout, Length, Point};
+use crate::{
+    event::{self, Event},
+    layout, Clipboard, Hasher, Layout, Length, Point,
+};
 
 /// A component that displays information and allows interaction.
 ///
@@ -159,7 +177,10 @@ where
     ///   * the `Renderer`
     ///   * a [`Clipboard`], if available
     ///
-    /// By default, it does nothing.
+    /// It returns whether the [`Event`] was captured by the [`Widget`] or
+    /// any of its children.
+    ///
+    /// By default, it does nothing and ignores the [`Event`].
     ///
     /// [`Event`]: ../enum.Event.html
     /// [`Widget`]: trait.Widget.html
@@ -173,6 +194,7 @@ where
         _messages: &mut Vec<Message>,
         _renderer: &Renderer,
         _clipboard: Option<&dyn Clipboard>,
-    ) {
+    ) -> event::Status {
+        event::Status::Ignored
     }
 }
@@ -0,0 +1,361 @@
+//! Display animated images, like GIFs, in your user interface.
+use crate::{
+    event, image, layout, window, Clipboard, ContentFit, Element, Event,
+    Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+/// A frame that displays an animated image, advancing its frames on
+/// schedule.
+///
+/// Images with a single frame are displayed like an [`Image`].
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::animated_image::{self, AnimatedImage};
+/// #
+/// let mut state = animated_image::State::new();
+///
+/// let spinner = AnimatedImage::new(&mut state, "resources/spinner.gif");
+/// ```
+///
+/// [`Image`]: ../image/struct.Image.html
+#[derive(Debug)]
+pub struct AnimatedImage<'a> {
+    state: &'a mut State,
+    handle: image::Handle,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+    filter_method: image::FilterMethod,
+}
+
+impl<'a> AnimatedImage<'a> {
+    /// Creates a new [`AnimatedImage`] with the given [`State`] and image.
+    ///
+    /// [`AnimatedImage`]: struct.AnimatedImage.html
+    /// [`State`]: struct.State.html
+    pub fn new<T: Into<image::Handle>>(
+        state: &'a mut State,
+        handle: T,
+    ) -> Self {
+        AnimatedImage {
+            state,
+            handle: handle.into(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            content_fit: ContentFit::default(),
+            filter_method: image::FilterMethod::default(),
+        }
+    }
+
+    /// Sets the width of the [`AnimatedImage`] boundaries.
+    ///
+    /// [`AnimatedImage`]: struct.AnimatedImage.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`AnimatedImage`] boundaries.
+    ///
+    /// [`AnimatedImage`]: struct.AnimatedImage.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`ContentFit`] of the [`AnimatedImage`].
+    ///
+    /// [`ContentFit`]: ../../enum.ContentFit.html
+    /// [`AnimatedImage`]: struct.AnimatedImage.html
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the [`FilterMethod`] used to sample the [`AnimatedImage`] when
+    /// scaled.
+    ///
+    /// [`FilterMethod`]: ../image/enum.FilterMethod.html
+    /// [`AnimatedImage`]: struct.AnimatedImage.html
+    pub fn filter_method(mut self, filter_method: image::FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for AnimatedImage<'a>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let (width, height) = renderer.dimensions(&self.handle);
+
+        image::layout(
+            limits,
+            Size::new(width as f32, height as f32),
+            self.width,
+            self.height,
+            self.content_fit,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            self.state.advance(renderer, &self.handle, now);
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> Renderer::Output {
+        let (width, height) = renderer.dimensions(&self.handle);
+
+        let image_bounds = image::fit(
+            Size::new(width as f32, height as f32),
+            layout.bounds(),
+            self.content_fit,
+        );
+
+        // A new image always starts from its first frame, and it needs a
+        // new frame right away to find out whether it is animated
+        let (frame, next_frame) = if self.state.handle == Some(self.handle.id())
+        {
+            (self.state.frame, self.state.next_frame)
+        } else {
+            (0, Some(Instant::now()))
+        };
+
+        self::Renderer::draw(
+            renderer,
+            self.handle.clone(),
+            frame,
+            next_frame,
+            self.filter_method,
+            layout,
+            image_bounds,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.handle.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+        self.content_fit.hash(state);
+    }
+}
+
+/// The local state of an [`AnimatedImage`].
+///
+/// [`AnimatedImage`]: struct.AnimatedImage.html
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    handle: Option<u64>,
+    delays: Vec<Duration>,
+    frame: usize,
+    next_frame: Option<Instant>,
+}
+
+impl State {
+    /// Creates a new [`State`], starting from the first frame.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns the index of the current frame.
+    pub fn frame(&self) -> usize {
+        self.frame
+    }
+
+    /// Restarts the animation from its first frame.
+    pub fn restart(&mut self) {
+        self.start(Instant::now());
+    }
+
+    /// Shows the first frame from the given [`Instant`], scheduling the next
+    /// one if the image is animated.
+    ///
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    fn start(&mut self, now: Instant) {
+        self.frame = 0;
+        self.next_frame = if self.is_still() {
+            None
+        } else {
+            Some(now + self.delays[0])
+        };
+    }
+
+    fn is_still(&self) -> bool {
+        self.delays.len() < 2
+            || self
+                .delays
+                .iter()
+                .all(|delay| *delay == Duration::from_secs(0))
+    }
+
+    fn advance<Renderer: self::Renderer>(
+        &mut self,
+        renderer: &Renderer,
+        handle: &image::Handle,
+        now: Instant,
+    ) {
+        // The delays of the frames are only loaded once per image
+        if self.handle != Some(handle.id()) {
+            self.handle = Some(handle.id());
+            self.delays = renderer.frame_delays(handle);
+            self.start(now);
+        }
+
+        // Still images never schedule a frame
+        let mut next_frame = match self.next_frame {
+            Some(next_frame) => next_frame,
+            None => return,
+        };
+
+        let delays = &self.delays;
+
+        // Skip any frames we missed, so the animation keeps its pace even if
+        // we are drawn less often than it changes
+        while next_frame <= now {
+            self.frame = (self.frame + 1) % delays.len();
+            next_frame += delays[self.frame];
+        }
+
+        self.next_frame = Some(next_frame);
+    }
+}
+
+/// The renderer of an [`AnimatedImage`].
+///
+/// Your [renderer] will need to implement this trait before being able to use
+/// an [`AnimatedImage`] in your user interface.
+///
+/// [`AnimatedImage`]: struct.AnimatedImage.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: image::Renderer {
+    /// Returns the delays of the frames of the given image.
+    ///
+    /// A still image has a single frame, or none if it could not be loaded.
+    fn frame_delays(&self, handle: &image::Handle) -> Vec<Duration>;
+
+    /// Draws a frame of an [`AnimatedImage`].
+    ///
+    /// It receives:
+    ///   * the handle of the image
+    ///   * the index of the frame to draw
+    ///   * the [`Instant`] when the next frame should be drawn, if any
+    ///   * the [`FilterMethod`] used to sample it
+    ///   * the [`Layout`] of the [`AnimatedImage`]
+    ///   * the bounds where the image should be drawn, which may overflow the
+    ///     [`Layout`] and should be clipped to it
+    ///
+    /// [`AnimatedImage`]: struct.AnimatedImage.html
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    /// [`FilterMethod`]: ../image/enum.FilterMethod.html
+    /// [`Layout`]: ../../layout/struct.Layout.html
+    fn draw(
+        &mut self,
+        handle: image::Handle,
+        frame: usize,
+        next_frame: Option<Instant>,
+        filter_method: image::FilterMethod,
+        layout: Layout<'_>,
+        image_bounds: Rectangle,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<AnimatedImage<'a>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn from(image: AnimatedImage<'a>) -> Element<'a, Message, Renderer> {
+        Element::new(image)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+
+    fn animated(delays: Vec<Duration>) -> (State, image::Handle) {
+        let handle = image::Handle::from_memory(Vec::new());
+
+        let state = State {
+            handle: Some(handle.id()),
+            delays,
+            ..State::default()
+        };
+
+        (state, handle)
+    }
+
+    #[test]
+    fn restarted_animations_keep_playing() {
+        let delay = Duration::from_millis(100);
+        let (mut state, handle) = animated(vec![delay; 3]);
+
+        let start = Instant::now();
+        state.start(start);
+        state.advance(&Null, &handle, start + delay);
+        assert_eq!(state.frame(), 1);
+
+        state.restart();
+        assert_eq!(state.frame(), 0);
+        assert!(state.next_frame.is_some());
+
+        let now = Instant::now();
+        state.advance(&Null, &handle, now);
+        assert_eq!(state.frame(), 0);
+
+        state.advance(&Null, &handle, now + delay);
+        assert_eq!(state.frame(), 1);
+    }
+
+    #[test]
+    fn still_images_do_not_request_frames() {
+        let (mut state, handle) = animated(vec![Duration::from_millis(100)]);
+
+        state.restart();
+        state.advance(&Null, &handle, Instant::now());
+
+        assert_eq!(state.frame(), 0);
+        assert_eq!(state.next_frame, None);
+    }
+}
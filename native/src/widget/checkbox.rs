@@ -2,7 +2,7 @@
 use std::hash::Hash;
 
 use crate::{
-    layout, mouse, row, text, Align, Clipboard, Element, Event, Hasher,
+    event, layout, mouse, row, text, Align, Clipboard, Element, Event, Hasher,
     HorizontalAlignment, Layout, Length, Point, Rectangle, Row, Text,
     VerticalAlignment, Widget,
 };
@@ -149,17 +149,21 @@ where
         messages: &mut Vec<Message>,
         _renderer: &Renderer,
         _clipboard: Option<&dyn Clipboard>,
-    ) {
+    ) -> event::Status {
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 let mouse_over = layout.bounds().contains(cursor_position);
 
                 if mouse_over {
                     messages.push((self.on_toggle)(!self.is_checked));
+
+                    return event::Status::Captured;
                 }
             }
             _ => {}
         }
+
+        event::Status::Ignored
     }
 
     fn draw(
@@ -186,6 +190,9 @@ where
             None,
             HorizontalAlignment::Left,
             VerticalAlignment::Center,
+            Default::default(),
+            Default::default(),
+            Default::default(),
         );
 
         let is_mouse_over = bounds.contains(cursor_position);
@@ -1,13 +1,20 @@
 //! Display images in your user interface.
-use crate::{layout, Element, Hasher, Layout, Length, Point, Size, Widget};
+use crate::{
+    layout, ContentFit, Element, Hasher, Layout, Length, Point, Rectangle,
+    Size, Widget,
+};
 
 use std::{
     hash::{Hash, Hasher as _},
     path::PathBuf,
-    sync::Arc,
+    sync::atomic::{self, AtomicU64},
+    sync::{Arc, Mutex},
 };
 
-/// A frame that displays an image while keeping aspect ratio.
+/// A frame that displays an image.
+///
+/// By default, the image keeps its aspect ratio. Use a different
+/// [`ContentFit`] to crop or stretch it instead.
 ///
 /// # Example
 ///
@@ -18,11 +25,15 @@ use std::{
 /// ```
 ///
 /// <img src="https://github.com/hecrj/iced/blob/9712b319bb7a32848001b96bd84977430f14b623/examples/resources/ferris.png?raw=true" width="300">
+///
+/// [`ContentFit`]: ../../enum.ContentFit.html
 #[derive(Debug, Hash)]
 pub struct Image {
     handle: Handle,
     width: Length,
     height: Length,
+    content_fit: ContentFit,
+    filter_method: FilterMethod,
 }
 
 impl Image {
@@ -34,6 +45,8 @@ impl Image {
             handle: handle.into(),
             width: Length::Shrink,
             height: Length::Shrink,
+            content_fit: ContentFit::default(),
+            filter_method: FilterMethod::default(),
         }
     }
 
@@ -52,6 +65,27 @@ impl Image {
         self.height = height;
         self
     }
+
+    /// Sets the [`ContentFit`] of the [`Image`].
+    ///
+    /// Defaults to [`ContentFit::Contain`].
+    ///
+    /// [`ContentFit`]: ../../enum.ContentFit.html
+    /// [`ContentFit::Contain`]: ../../enum.ContentFit.html#variant.Contain
+    /// [`Image`]: struct.Image.html
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the [`FilterMethod`] used to sample the [`Image`] when scaled.
+    ///
+    /// [`FilterMethod`]: enum.FilterMethod.html
+    /// [`Image`]: struct.Image.html
+    pub fn filter_method(mut self, filter_method: FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Image
@@ -73,22 +107,13 @@ where
     ) -> layout::Node {
         let (width, height) = renderer.dimensions(&self.handle);
 
-        let aspect_ratio = width as f32 / height as f32;
-
-        let mut size = limits
-            .width(self.width)
-            .height(self.height)
-            .resolve(Size::new(width as f32, height as f32));
-
-        let viewport_aspect_ratio = size.width / size.height;
-
-        if viewport_aspect_ratio > aspect_ratio {
-            size.width = width as f32 * size.height / height as f32;
-        } else {
-            size.height = height as f32 * size.width / width as f32;
-        }
-
-        layout::Node::new(size)
+        self::layout(
+            limits,
+            Size::new(width as f32, height as f32),
+            self.width,
+            self.height,
+            self.content_fit,
+        )
     }
 
     fn draw(
@@ -98,7 +123,20 @@ where
         layout: Layout<'_>,
         _cursor_position: Point,
     ) -> Renderer::Output {
-        renderer.draw(self.handle.clone(), layout)
+        let (width, height) = renderer.dimensions(&self.handle);
+
+        let image_bounds = fit(
+            Size::new(width as f32, height as f32),
+            layout.bounds(),
+            self.content_fit,
+        );
+
+        renderer.draw(
+            self.handle.clone(),
+            self.filter_method,
+            layout,
+            image_bounds,
+        )
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
@@ -108,6 +146,80 @@ where
         self.handle.hash(state);
         self.width.hash(state);
         self.height.hash(state);
+        self.content_fit.hash(state);
+
+        // The frames of a stream may change their size while keeping the
+        // same handle
+        if let Data::Stream(frame) = self.handle.data() {
+            let frame = frame.lock().expect("Lock stream frame");
+
+            frame.width.hash(state);
+            frame.height.hash(state);
+        }
+    }
+}
+
+/// Lays out some contents of the given intrinsic size, like an image, using
+/// a [`ContentFit`].
+///
+/// [`ContentFit`]: ../../enum.ContentFit.html
+pub(crate) fn layout(
+    limits: &layout::Limits,
+    content_size: Size,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+) -> layout::Node {
+    let raw_size = limits.width(width).height(height).resolve(content_size);
+    let fitted_size = content_fit.fit(content_size, raw_size);
+
+    // Only shrink to the fitted contents along the axes that can shrink
+    let size = Size::new(
+        match width {
+            Length::Shrink => raw_size.width.min(fitted_size.width),
+            _ => raw_size.width,
+        },
+        match height {
+            Length::Shrink => raw_size.height.min(fitted_size.height),
+            _ => raw_size.height,
+        },
+    );
+
+    layout::Node::new(size)
+}
+
+/// Returns the bounds of some contents of the given intrinsic size, fitted
+/// and centered in the given bounds.
+pub(crate) fn fit(
+    content_size: Size,
+    bounds: Rectangle,
+    content_fit: ContentFit,
+) -> Rectangle {
+    let size = content_fit.fit(content_size, bounds.size());
+
+    Rectangle {
+        x: bounds.x + (bounds.width - size.width) / 2.0,
+        y: bounds.y + (bounds.height - size.height) / 2.0,
+        width: size.width,
+        height: size.height,
+    }
+}
+
+/// The filtering method used to sample an [`Image`] when it is scaled.
+///
+/// [`Image`]: struct.Image.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FilterMethod {
+    /// Bilinear interpolation. Smooth, but blurry when magnified.
+    Linear,
+
+    /// Nearest-neighbor interpolation. Keeps pixel art crisp.
+    Nearest,
+}
+
+impl Default for FilterMethod {
+    fn default() -> Self {
+        FilterMethod::Linear
     }
 }
 
@@ -157,6 +269,17 @@ impl Handle {
         Self::from_data(Data::Bytes(bytes))
     }
 
+    /// Creates an image [`Handle`] pointing to the current frame of a
+    /// [`Stream`].
+    ///
+    /// The [`Handle`] keeps the same id when the [`Stream`] is updated.
+    ///
+    /// [`Handle`]: struct.Handle.html
+    /// [`Stream`]: struct.Stream.html
+    pub fn from_stream(stream: &Stream) -> Handle {
+        stream.handle.clone()
+    }
+
     fn from_data(data: Data) -> Handle {
         let mut hasher = Hasher::default();
         data.hash(&mut hasher);
@@ -200,7 +323,7 @@ impl Hash for Handle {
 /// The data of an [`Image`].
 ///
 /// [`Image`]: struct.Image.html
-#[derive(Clone, Hash)]
+#[derive(Clone)]
 pub enum Data {
     /// File data
     Path(PathBuf),
@@ -217,6 +340,39 @@ pub enum Data {
         /// The pixels.
         pixels: Vec<u8>,
     },
+
+    /// The current [`Frame`] of a [`Stream`].
+    ///
+    /// [`Frame`]: struct.Frame.html
+    /// [`Stream`]: struct.Stream.html
+    Stream(Arc<Mutex<Frame>>),
+}
+
+impl Hash for Data {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+
+        match self {
+            Data::Path(path) => path.hash(state),
+            Data::Bytes(bytes) => bytes.hash(state),
+            Data::Pixels {
+                width,
+                height,
+                pixels,
+            } => {
+                width.hash(state);
+                height.hash(state);
+                pixels.hash(state);
+            }
+            Data::Stream(frame) => {
+                let frame = frame.lock().expect("Lock stream frame");
+
+                frame.width.hash(state);
+                frame.height.hash(state);
+                frame.version.hash(state);
+            }
+        }
+    }
 }
 
 impl std::fmt::Debug for Data {
@@ -227,8 +383,100 @@ impl std::fmt::Debug for Data {
             Data::Pixels { width, height, .. } => {
                 write!(f, "Pixels({} * {})", width, height)
             }
+            Data::Stream(_) => write!(f, "Stream(...)"),
+        }
+    }
+}
+
+/// A sequence of images sharing the same [`Handle`], like the frames of a
+/// video or a live plot.
+///
+/// Updating a [`Stream`] does not change the id of its [`Handle`]. This lets
+/// renderers reuse the resources of the previous frame, instead of treating
+/// every frame as a new image.
+///
+/// [`Handle`]: struct.Handle.html
+/// [`Stream`]: struct.Stream.html
+#[derive(Debug, Clone)]
+pub struct Stream {
+    frame: Arc<Mutex<Frame>>,
+    handle: Handle,
+}
+
+impl Stream {
+    /// Creates a new [`Stream`] with a first frame of BGRA pixels.
+    ///
+    /// [`Stream`]: struct.Stream.html
+    pub fn new(width: u32, height: u32, pixels: Vec<u8>) -> Stream {
+        let frame = Arc::new(Mutex::new(Frame {
+            width,
+            height,
+            pixels,
+            version: 0,
+        }));
+
+        // A stream is identified by a unique id instead of its contents, so
+        // the id of its handle does not change when it is updated
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        Stream {
+            handle: Handle {
+                id: NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed),
+                data: Arc::new(Data::Stream(frame.clone())),
+            },
+            frame,
         }
     }
+
+    /// Returns the [`Handle`] of the [`Stream`].
+    ///
+    /// [`Handle`]: struct.Handle.html
+    /// [`Stream`]: struct.Stream.html
+    pub fn handle(&self) -> Handle {
+        self.handle.clone()
+    }
+
+    /// Replaces the current frame of the [`Stream`] with new BGRA pixels.
+    ///
+    /// [`Stream`]: struct.Stream.html
+    pub fn update(&self, width: u32, height: u32, pixels: Vec<u8>) {
+        let mut frame = self.frame.lock().expect("Lock stream frame");
+
+        frame.width = width;
+        frame.height = height;
+        frame.pixels = pixels;
+        frame.version = frame.version.wrapping_add(1);
+    }
+}
+
+/// The current frame of a [`Stream`].
+///
+/// [`Stream`]: struct.Stream.html
+#[derive(Clone)]
+pub struct Frame {
+    /// The width of the frame.
+    pub width: u32,
+
+    /// The height of the frame.
+    pub height: u32,
+
+    /// The BGRA pixels of the frame.
+    pub pixels: Vec<u8>,
+
+    /// The version of the frame, increased on every update of its [`Stream`].
+    ///
+    /// [`Stream`]: struct.Stream.html
+    pub version: u64,
+}
+
+impl std::fmt::Debug for Frame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Frame({} * {}, version {})",
+            self.width, self.height, self.version
+        )
+    }
 }
 
 /// The renderer of an [`Image`].
@@ -246,8 +494,24 @@ pub trait Renderer: crate::Renderer {
 
     /// Draws an [`Image`].
     ///
+    /// It receives:
+    ///   * the [`Handle`] of the [`Image`]
+    ///   * the [`FilterMethod`] used to sample it
+    ///   * the [`Layout`] of the [`Image`]
+    ///   * the bounds where the image should be drawn, which may overflow the
+    ///     [`Layout`] and should be clipped to it
+    ///
     /// [`Image`]: struct.Image.html
-    fn draw(&mut self, handle: Handle, layout: Layout<'_>) -> Self::Output;
+    /// [`Handle`]: struct.Handle.html
+    /// [`FilterMethod`]: enum.FilterMethod.html
+    /// [`Layout`]: ../../layout/struct.Layout.html
+    fn draw(
+        &mut self,
+        handle: Handle,
+        filter_method: FilterMethod,
+        layout: Layout<'_>,
+        image_bounds: Rectangle,
+    ) -> Self::Output;
 }
 
 impl<'a, Message, Renderer> From<Image> for Element<'a, Message, Renderer>
@@ -258,3 +522,77 @@ where
         Element::new(image)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WIDE: Size = Size {
+        width: 200.0,
+        height: 100.0,
+    };
+
+    #[test]
+    fn fit_centers_contents() {
+        let bounds = Rectangle {
+            x: 10.0,
+            y: 10.0,
+            width: 100.0,
+            height: 100.0,
+        };
+
+        assert_eq!(
+            fit(WIDE, bounds, ContentFit::Contain),
+            Rectangle {
+                x: 10.0,
+                y: 35.0,
+                width: 100.0,
+                height: 50.0,
+            }
+        );
+
+        assert_eq!(
+            fit(WIDE, bounds, ContentFit::Cover),
+            Rectangle {
+                x: -40.0,
+                y: 10.0,
+                width: 200.0,
+                height: 100.0,
+            }
+        );
+    }
+
+    #[test]
+    fn layout_shrinks_to_contents() {
+        let limits = layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0));
+
+        let size = |width, height, content_fit| {
+            layout(&limits, WIDE, width, height, content_fit).size()
+        };
+
+        assert_eq!(
+            size(Length::Shrink, Length::Shrink, ContentFit::Contain),
+            Size::new(100.0, 50.0)
+        );
+        assert_eq!(
+            size(Length::Fill, Length::Fill, ContentFit::Contain),
+            Size::new(100.0, 100.0)
+        );
+        assert_eq!(
+            size(Length::Shrink, Length::Shrink, ContentFit::Cover),
+            Size::new(100.0, 100.0)
+        );
+    }
+
+    #[test]
+    fn stream_handles_keep_their_id() {
+        let stream = Stream::new(1, 1, vec![0; 4]);
+        let other = Stream::new(1, 1, vec![0; 4]);
+        let id = stream.handle().id();
+
+        stream.update(2, 2, vec![0; 16]);
+
+        assert_eq!(stream.handle().id(), id);
+        assert_ne!(other.handle().id(), id);
+    }
+}
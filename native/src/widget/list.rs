@@ -0,0 +1,704 @@
+//! Display a very large amount of rows efficiently.
+//!
+//! A [`List`] has some local [`State`].
+//!
+//! [`List`]: struct.List.html
+//! [`State`]: struct.State.html
+use crate::scrollable::{self, Behavior, Direction};
+use crate::{
+    column, event, layout, Clipboard, Element, Event, Hasher, Layout, Length,
+    Point, Rectangle, Size, Vector, Widget,
+};
+
+use std::hash::{Hash, Hasher as _};
+use std::ops::Range;
+use std::sync::Arc;
+
+pub use crate::scrollable::{State, Viewport};
+
+/// A scrollable list of rows that only builds, lays out and draws the rows
+/// inside its visible region.
+///
+/// Rows are produced on demand by a closure, given their index. Since a row
+/// may be built more than once per frame, it cannot borrow any local state.
+///
+/// A [`List`] is scrolled using a [`State`], just like a [`Scrollable`].
+///
+/// [`List`]: struct.List.html
+/// [`State`]: struct.State.html
+/// [`Scrollable`]: ../scrollable/struct.Scrollable.html
+#[allow(missing_debug_implementations)]
+pub struct List<'a, Message, Renderer: scrollable::Renderer> {
+    state: &'a mut State,
+    len: usize,
+    row_height: RowHeight<'a>,
+    view_row: Box<dyn Fn(usize) -> Element<'a, Message, Renderer> + 'a>,
+    width: Length,
+    height: Length,
+    smooth: bool,
+    kinetic: bool,
+    on_scroll: Option<Box<dyn Fn(Viewport) -> Message + 'a>>,
+    style: Renderer::Style,
+}
+
+enum RowHeight<'a> {
+    Fixed(u16),
+    Measured(Box<dyn Fn(usize) -> u16 + 'a>),
+}
+
+impl<'a, Message, Renderer> List<'a, Message, Renderer>
+where
+    Renderer: scrollable::Renderer,
+{
+    /// Creates a new [`List`] of `len` rows with the same height.
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the list
+    ///   * the amount of rows in the list
+    ///   * the height of every row, in pixels
+    ///   * a function that produces the [`Element`] of the row at an index
+    ///
+    /// [`List`]: struct.List.html
+    /// [`State`]: struct.State.html
+    /// [`Element`]: ../../struct.Element.html
+    pub fn new<F>(
+        state: &'a mut State,
+        len: usize,
+        row_height: u16,
+        view_row: F,
+    ) -> Self
+    where
+        F: 'a + Fn(usize) -> Element<'a, Message, Renderer>,
+    {
+        Self::with_row_height(
+            state,
+            len,
+            RowHeight::Fixed(row_height),
+            view_row,
+        )
+    }
+
+    /// Creates a new [`List`] of `len` rows with varying heights.
+    ///
+    /// The height of every row, in pixels, is obtained from the `measure`
+    /// function. It is called for every row when the [`List`] is laid out,
+    /// which happens again when its amount of rows changes or the layout of
+    /// the user interface does, so it should be cheap.
+    ///
+    /// [`List`]: struct.List.html
+    pub fn measured<M, F>(
+        state: &'a mut State,
+        len: usize,
+        measure: M,
+        view_row: F,
+    ) -> Self
+    where
+        M: 'a + Fn(usize) -> u16,
+        F: 'a + Fn(usize) -> Element<'a, Message, Renderer>,
+    {
+        Self::with_row_height(
+            state,
+            len,
+            RowHeight::Measured(Box::new(measure)),
+            view_row,
+        )
+    }
+
+    fn with_row_height<F>(
+        state: &'a mut State,
+        len: usize,
+        row_height: RowHeight<'a>,
+        view_row: F,
+    ) -> Self
+    where
+        F: 'a + Fn(usize) -> Element<'a, Message, Renderer>,
+    {
+        List {
+            state,
+            len,
+            row_height,
+            view_row: Box::new(view_row),
+            width: Length::Fill,
+            height: Length::Fill,
+            smooth: false,
+            kinetic: false,
+            on_scroll: None,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`List`].
+    ///
+    /// [`List`]: struct.List.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`List`].
+    ///
+    /// [`List`]: struct.List.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets whether the [`List`] should animate towards its new scroll
+    /// position when scrolled with a mouse wheel.
+    ///
+    /// [`List`]: struct.List.html
+    pub fn smooth(mut self, smooth: bool) -> Self {
+        self.smooth = smooth;
+        self
+    }
+
+    /// Sets whether the [`List`] should keep scrolling and slowly decelerate
+    /// after a touchpad gesture ends.
+    ///
+    /// [`List`]: struct.List.html
+    pub fn kinetic(mut self, kinetic: bool) -> Self {
+        self.kinetic = kinetic;
+        self
+    }
+
+    /// Sets the message that should be produced when the [`List`] is
+    /// scrolled.
+    ///
+    /// [`List`]: struct.List.html
+    pub fn on_scroll<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(Viewport) -> Message,
+    {
+        self.on_scroll = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of the [`List`].
+    ///
+    /// [`List`]: struct.List.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the [`Positions`] of the rows.
+    ///
+    /// Measured rows are only measured again when `refresh` is set, which
+    /// happens once per layout, or when the amount of rows changes.
+    ///
+    /// [`Positions`]: enum.Positions.html
+    fn positions(&self, refresh: bool) -> Positions {
+        match &self.row_height {
+            RowHeight::Fixed(height) => Positions::Uniform {
+                height: f32::from(*height),
+                len: self.len,
+            },
+            RowHeight::Measured(measure) => {
+                let mut hasher = Hasher::default();
+
+                self.len.hash(&mut hasher);
+                self.width.hash(&mut hasher);
+                self.height.hash(&mut hasher);
+
+                let offsets =
+                    self.state.row_offsets(hasher.finish(), refresh, || {
+                        let mut offsets = Vec::with_capacity(self.len + 1);
+                        let mut y = 0.0;
+
+                        offsets.push(y);
+
+                        for index in 0..self.len {
+                            y += f32::from(measure(index));
+                            offsets.push(y);
+                        }
+
+                        offsets
+                    });
+
+                Positions::Varying(offsets)
+            }
+        }
+    }
+
+    fn content_bounds(bounds: Rectangle, positions: &Positions) -> Rectangle {
+        Rectangle {
+            height: positions.total(),
+            ..bounds
+        }
+    }
+
+    fn offset(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        positions: &Positions,
+    ) -> Vector<u32> {
+        self.state
+            .requested_offset(bounds, content_bounds, |index| {
+                positions.bounds(index, content_bounds)
+            })
+    }
+
+    /// Builds and lays out the rows visible at the given offset.
+    fn visible_rows(
+        &self,
+        renderer: &Renderer,
+        content_bounds: Rectangle,
+        visible_height: f32,
+        offset: Vector<u32>,
+        positions: &Positions,
+    ) -> (Vec<Element<'a, Message, Renderer>>, layout::Node) {
+        let range = positions.visible(offset.y as f32, visible_height);
+
+        let mut rows = Vec::with_capacity(range.len());
+        let mut nodes = Vec::with_capacity(range.len());
+
+        for index in range {
+            let (y, height) = positions.row(index);
+            let size = Size::new(content_bounds.width, height);
+
+            let row = (self.view_row)(index);
+            let mut node =
+                row.layout(renderer, &layout::Limits::new(size, size));
+
+            node.move_to(Point::new(0.0, y));
+
+            rows.push(row);
+            nodes.push(node);
+        }
+
+        (
+            rows,
+            layout::Node::with_children(content_bounds.size(), nodes),
+        )
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for List<'a, Message, Renderer>
+where
+    Renderer: scrollable::Renderer + column::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = limits.resolve(Size::new(0.0, self.positions(true).total()));
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let positions = self.positions(false);
+        let content_bounds = Self::content_bounds(bounds, &positions);
+        let previous_offset = self.offset(bounds, content_bounds, &positions);
+
+        self.state.resolve(
+            bounds,
+            content_bounds,
+            |index| positions.bounds(index, content_bounds),
+            Direction::Vertical,
+        );
+
+        if let Event::Keyboard(keyboard_event) = event {
+            self.state.track_modifiers(keyboard_event);
+        }
+
+        let offset = self.state.offset(bounds, content_bounds);
+        let scrollbars = renderer.scrollbars(
+            bounds,
+            content_bounds,
+            offset,
+            Direction::Vertical,
+        );
+        let mouse_over_scrollbar = scrollbars.mouse_over(cursor_position);
+
+        let event_status = {
+            let (mut rows, content) = self.visible_rows(
+                renderer,
+                content_bounds,
+                bounds.height,
+                offset,
+                &positions,
+            );
+
+            let content_layout =
+                Layout::with_offset(Vector::new(bounds.x, bounds.y), &content);
+
+            let cursor_position =
+                if is_mouse_over && mouse_over_scrollbar.is_none() {
+                    Point::new(
+                        cursor_position.x,
+                        cursor_position.y + offset.y as f32,
+                    )
+                } else {
+                    Point::new(cursor_position.x, -1.0)
+                };
+
+            rows.iter_mut()
+                .zip(content_layout.children())
+                .map(|(row, layout)| {
+                    row.widget.on_event(
+                        event.clone(),
+                        layout,
+                        cursor_position,
+                        messages,
+                        renderer,
+                        clipboard,
+                    )
+                })
+                .fold(event::Status::Ignored, event::Status::merge)
+        };
+
+        let event_status = match event_status {
+            event::Status::Captured => event::Status::Captured,
+            event::Status::Ignored => self.state.on_event(
+                event,
+                bounds,
+                content_bounds,
+                cursor_position,
+                &scrollbars,
+                Behavior {
+                    direction: Direction::Vertical,
+                    smooth: self.smooth,
+                    kinetic: self.kinetic,
+                },
+            ),
+        };
+
+        let offset = self.state.offset(bounds, content_bounds);
+
+        if offset != previous_offset {
+            if let Some(on_scroll) = &self.on_scroll {
+                messages.push(on_scroll(Viewport {
+                    offset: Vector::new(offset.x as f32, offset.y as f32),
+                    size: bounds.size(),
+                    content_size: content_bounds.size(),
+                }));
+            }
+        }
+
+        event_status
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let positions = self.positions(false);
+        let content_bounds = Self::content_bounds(bounds, &positions);
+        let offset = self.offset(bounds, content_bounds, &positions);
+        let scrollbars = renderer.scrollbars(
+            bounds,
+            content_bounds,
+            offset,
+            Direction::Vertical,
+        );
+
+        let is_mouse_over = bounds.contains(cursor_position);
+        let mouse_over_scrollbar = scrollbars.mouse_over(cursor_position);
+
+        let content = {
+            let (rows, content) = self.visible_rows(
+                renderer,
+                content_bounds,
+                bounds.height,
+                offset,
+                &positions,
+            );
+
+            let content_layout =
+                Layout::with_offset(Vector::new(bounds.x, bounds.y), &content);
+
+            let cursor_position =
+                if is_mouse_over && mouse_over_scrollbar.is_none() {
+                    Point::new(
+                        cursor_position.x,
+                        cursor_position.y + offset.y as f32,
+                    )
+                } else {
+                    Point::new(cursor_position.x, -1.0)
+                };
+
+            column::Renderer::draw(
+                renderer,
+                defaults,
+                &rows,
+                content_layout,
+                cursor_position,
+            )
+        };
+
+        scrollable::Renderer::draw(
+            renderer,
+            self.state,
+            bounds,
+            content_bounds,
+            is_mouse_over,
+            mouse_over_scrollbar,
+            scrollbars,
+            offset,
+            &self.style,
+            content,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.len.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+
+        if let RowHeight::Fixed(height) = self.row_height {
+            height.hash(state);
+        }
+    }
+}
+
+/// The vertical position of every row of a [`List`].
+///
+/// [`List`]: struct.List.html
+enum Positions {
+    Uniform {
+        height: f32,
+        len: usize,
+    },
+
+    /// The offset of every row, followed by the total height.
+    Varying(Arc<Vec<f32>>),
+}
+
+impl Positions {
+    fn len(&self) -> usize {
+        match self {
+            Positions::Uniform { len, .. } => *len,
+            Positions::Varying(offsets) => offsets.len() - 1,
+        }
+    }
+
+    fn total(&self) -> f32 {
+        match self {
+            Positions::Uniform { height, len } => height * *len as f32,
+            Positions::Varying(offsets) => offsets[offsets.len() - 1],
+        }
+    }
+
+    /// Returns the offset and height of the row at the given index.
+    fn row(&self, index: usize) -> (f32, f32) {
+        match self {
+            Positions::Uniform { height, .. } => {
+                (height * index as f32, *height)
+            }
+            Positions::Varying(offsets) => {
+                (offsets[index], offsets[index + 1] - offsets[index])
+            }
+        }
+    }
+
+    /// Returns the index of the row containing the given offset.
+    fn find(&self, y: f32) -> usize {
+        let index = match self {
+            Positions::Uniform { height, .. } => {
+                if *height > 0.0 {
+                    (y / height).max(0.0) as usize
+                } else {
+                    0
+                }
+            }
+            Positions::Varying(offsets) => {
+                match offsets.binary_search_by(|offset| {
+                    offset.partial_cmp(&y).unwrap_or(std::cmp::Ordering::Less)
+                }) {
+                    Ok(index) => index,
+                    Err(index) => index.saturating_sub(1),
+                }
+            }
+        };
+
+        index.min(self.len())
+    }
+
+    /// Returns the range of rows overlapping the visible region.
+    fn visible(&self, offset: f32, height: f32) -> Range<usize> {
+        let start = self.find(offset);
+        let end = (self.find(offset + height) + 1).min(self.len());
+
+        start..end.max(start)
+    }
+
+    fn bounds(
+        &self,
+        index: usize,
+        content_bounds: Rectangle,
+    ) -> Option<Rectangle> {
+        if index < self.len() {
+            let (y, height) = self.row(index);
+
+            Some(Rectangle {
+                x: content_bounds.x,
+                y: content_bounds.y + y,
+                width: content_bounds.width,
+                height,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<List<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + scrollable::Renderer + column::Renderer,
+    Message: 'a,
+{
+    fn from(
+        list: List<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 100.0,
+    };
+
+    fn uniform() -> Positions {
+        Positions::Uniform {
+            height: 20.0,
+            len: 10,
+        }
+    }
+
+    fn varying() -> Positions {
+        // Rows of 10, 20, 30 and 40 pixels
+        Positions::Varying(Arc::new(vec![0.0, 10.0, 30.0, 60.0, 100.0]))
+    }
+
+    #[test]
+    fn rows() {
+        assert_eq!(uniform().row(3), (60.0, 20.0));
+        assert_eq!(uniform().total(), 200.0);
+
+        assert_eq!(varying().row(2), (30.0, 30.0));
+        assert_eq!(varying().total(), 100.0);
+    }
+
+    #[test]
+    fn visible_rows() {
+        assert_eq!(uniform().visible(0.0, 50.0), 0..3);
+        assert_eq!(uniform().visible(30.0, 50.0), 1..5);
+        assert_eq!(uniform().visible(190.0, 50.0), 9..10);
+
+        assert_eq!(varying().visible(0.0, 25.0), 0..2);
+        assert_eq!(varying().visible(35.0, 10.0), 2..3);
+        assert_eq!(varying().visible(90.0, 10.0), 3..4);
+    }
+
+    #[test]
+    fn visible_rows_of_empty_lists() {
+        let positions = Positions::Uniform {
+            height: 20.0,
+            len: 0,
+        };
+
+        assert_eq!(positions.visible(0.0, 100.0), 0..0);
+        assert_eq!(positions.bounds(0, BOUNDS), None);
+    }
+
+    #[test]
+    fn bounds() {
+        let content_bounds = Rectangle { y: 50.0, ..BOUNDS };
+
+        assert_eq!(
+            varying().bounds(3, content_bounds),
+            Some(Rectangle {
+                x: 0.0,
+                y: 110.0,
+                width: 100.0,
+                height: 40.0,
+            })
+        );
+        assert_eq!(varying().bounds(4, content_bounds), None);
+    }
+
+    #[test]
+    fn requested_offset_reveals_rows() {
+        let positions = uniform();
+        let content_bounds = Rectangle {
+            height: positions.total(),
+            ..BOUNDS
+        };
+        let row_bounds = |index| positions.bounds(index, content_bounds);
+
+        let mut state = State::new();
+
+        state.scroll_to_child(7);
+        assert_eq!(
+            state.requested_offset(BOUNDS, content_bounds, row_bounds),
+            Vector::new(0, 60)
+        );
+
+        state.resolve(BOUNDS, content_bounds, row_bounds, Direction::Vertical);
+
+        state.scroll_to_child(1);
+        assert_eq!(
+            state.requested_offset(BOUNDS, content_bounds, row_bounds),
+            Vector::new(0, 20)
+        );
+
+        // Rows that do not exist are ignored
+        state.scroll_to_child(10);
+        assert_eq!(
+            state.requested_offset(BOUNDS, content_bounds, row_bounds),
+            Vector::new(0, 60)
+        );
+    }
+
+    #[test]
+    fn row_offsets_are_cached() {
+        let state = State::new();
+        let offsets = |key, refresh, total| {
+            state.row_offsets(key, refresh, || vec![0.0, total])[1]
+        };
+
+        assert_eq!(offsets(1, false, 10.0), 10.0);
+        assert_eq!(offsets(1, false, 20.0), 10.0);
+        assert_eq!(offsets(1, true, 20.0), 20.0);
+        assert_eq!(offsets(2, false, 30.0), 30.0);
+    }
+}
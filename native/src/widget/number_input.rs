@@ -0,0 +1,686 @@
+//! Let your users type or step through numbers.
+//!
+//! A [`NumberInput`] has some local [`State`].
+//!
+//! [`NumberInput`]: struct.NumberInput.html
+//! [`State`]: struct.State.html
+use crate::{
+    event, keyboard, layout, mouse, text_input, Clipboard, Element, Event,
+    Hasher, Layout, Length, Point, Rectangle, Size, TextInput, Widget,
+};
+
+use std::ops::RangeInclusive;
+use std::u32;
+
+/// A field that can be filled with a number, which can also be stepped with
+/// its buttons, the arrow keys or the mouse wheel.
+///
+/// Typed text is parsed the same way regardless of the locale of the system:
+/// the decimal separator is always a dot. While the typed text is not a valid
+/// number between the bounds, the [`NumberInput`] shows it.
+///
+/// # Example
+/// ```
+/// # use iced_native::{number_input, renderer::Null};
+/// #
+/// # pub type NumberInput<'a, T, Message> =
+/// #     iced_native::NumberInput<'a, T, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     QuantityChanged(u32),
+/// }
+///
+/// let mut state = number_input::State::new();
+/// let quantity = 3;
+///
+/// let input =
+///     NumberInput::new(&mut state, quantity, Message::QuantityChanged)
+///         .bounds(1..=10)
+///         .padding(10);
+/// ```
+///
+/// [`NumberInput`]: struct.NumberInput.html
+#[allow(missing_debug_implementations)]
+pub struct NumberInput<'a, T, Message, Renderer>
+where
+    Renderer: self::Renderer + text_input::Renderer,
+{
+    state: &'a mut State,
+    value: T,
+    bounds: RangeInclusive<T>,
+    step: T,
+    on_change: Box<dyn Fn(T) -> Message>,
+    font: <Renderer as text_input::Renderer>::Font,
+    width: Length,
+    max_width: u32,
+    padding: u16,
+    size: Option<u16>,
+    input_style: <Renderer as text_input::Renderer>::Style,
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, T, Message, Renderer> NumberInput<'a, T, Message, Renderer>
+where
+    T: Number,
+    Renderer: self::Renderer + text_input::Renderer,
+{
+    /// Creates a new [`NumberInput`].
+    ///
+    /// It expects:
+    /// - some [`State`]
+    /// - the current value
+    /// - a function that produces a message when the value changes
+    ///
+    /// By default, the value can be any number of its type, and it is
+    /// stepped by one.
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(state: &'a mut State, value: T, on_change: F) -> Self
+    where
+        F: 'static + Fn(T) -> Message,
+    {
+        NumberInput {
+            state,
+            value,
+            bounds: T::MIN..=T::MAX,
+            step: T::ONE,
+            on_change: Box::new(on_change),
+            font: Default::default(),
+            width: Length::Fill,
+            max_width: u32::MAX,
+            padding: 0,
+            size: None,
+            input_style: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the inclusive range of values of the [`NumberInput`].
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn bounds(mut self, bounds: RangeInclusive<T>) -> Self {
+        self.bounds = bounds;
+        self
+    }
+
+    /// Sets the step of the [`NumberInput`].
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the [`Font`] of the [`NumberInput`].
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    /// [`Font`]: ../../struct.Font.html
+    pub fn font(
+        mut self,
+        font: <Renderer as text_input::Renderer>::Font,
+    ) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the width of the [`NumberInput`].
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the maximum width of the [`NumberInput`].
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the padding of the [`NumberInput`].
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn padding(mut self, units: u16) -> Self {
+        self.padding = units;
+        self
+    }
+
+    /// Sets the text size of the [`NumberInput`].
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the style of the field of the [`NumberInput`].
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn input_style(
+        mut self,
+        style: impl Into<<Renderer as text_input::Renderer>::Style>,
+    ) -> Self {
+        self.input_style = style.into();
+        self
+    }
+
+    /// Sets the style of the [`NumberInput`].
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn text(&self) -> String {
+        match &self.state.text {
+            Some(text) => text.clone(),
+            None => self.value.to_string(),
+        }
+    }
+
+    fn parse(&self, text: &str) -> Option<T> {
+        text.trim()
+            .parse()
+            .ok()
+            .filter(|value| self.bounds.contains(value))
+    }
+
+    fn step_by(&mut self, is_increment: bool, messages: &mut Vec<Message>) {
+        // Stepping starts from the typed number, if valid
+        let current = match &self.state.text {
+            Some(text) => self.parse(text).unwrap_or(self.value),
+            None => self.value,
+        };
+
+        let stepped = if is_increment {
+            current.increment(self.step)
+        } else {
+            current.decrement(self.step)
+        };
+
+        let stepped = clamp(stepped, &self.bounds);
+
+        if stepped != self.value {
+            messages.push((self.on_change)(stepped));
+        }
+
+        self.state.text = None;
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for NumberInput<'a, T, Message, Renderer>
+where
+    T: Number,
+    Renderer: self::Renderer + text_input::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let padding = self.padding as f32;
+        let text_size = self.size.unwrap_or(renderer.default_size());
+
+        let height = f32::from(text_size) + padding * 2.0;
+        let buttons_width = (height * 0.75).round();
+
+        let limits = limits
+            .width(self.width)
+            .max_width(self.max_width)
+            .height(Length::Units(height as u16));
+
+        let size = limits.resolve(Size::ZERO);
+
+        // The field is laid out like a `TextInput`
+        let mut text = layout::Node::new(Size::new(
+            (size.width - buttons_width - padding * 2.0).max(0.0),
+            f32::from(text_size),
+        ));
+        text.move_to(Point::new(padding, padding));
+
+        let input = layout::Node::with_children(
+            Size::new((size.width - buttons_width).max(0.0), height),
+            vec![text],
+        );
+
+        let mut increment =
+            layout::Node::new(Size::new(buttons_width, height / 2.0));
+        increment.move_to(Point::new(input.size().width, 0.0));
+
+        let mut decrement =
+            layout::Node::new(Size::new(buttons_width, height / 2.0));
+        decrement.move_to(Point::new(input.size().width, height / 2.0));
+
+        layout::Node::with_children(
+            Size::new(input.size().width + buttons_width, height),
+            vec![input, increment, decrement],
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let input_layout = children.next().unwrap();
+        let increment_layout = children.next().unwrap();
+        let decrement_layout = children.next().unwrap();
+
+        match &event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if increment_layout.bounds().contains(cursor_position) {
+                    self.step_by(true, messages);
+
+                    return event::Status::Captured;
+                }
+
+                if decrement_layout.bounds().contains(cursor_position) {
+                    self.step_by(false, messages);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if self.state.input.is_focused()
+                    && layout.bounds().contains(cursor_position) =>
+            {
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => *y,
+                    mouse::ScrollDelta::Pixels { y, .. } => *y,
+                };
+
+                if y != 0.0 {
+                    self.step_by(y > 0.0, messages);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Up,
+                ..
+            }) if self.state.input.is_focused() => {
+                self.step_by(true, messages);
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Down,
+                ..
+            }) if self.state.input.is_focused() => {
+                self.step_by(false, messages);
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        let text = self.text();
+        let mut changes = Vec::new();
+
+        let status = {
+            let mut input =
+                TextInput::new(&mut self.state.input, "", &text, |text| text)
+                    .font(self.font.clone())
+                    .padding(self.padding);
+
+            if let Some(size) = self.size {
+                input = input.size(size);
+            }
+
+            input.on_event(
+                event,
+                input_layout,
+                cursor_position,
+                &mut changes,
+                renderer,
+                clipboard,
+            )
+        };
+
+        if let Some(text) = changes.pop() {
+            if let Some(value) = self.parse(&text) {
+                if value != self.value {
+                    messages.push((self.on_change)(value));
+                }
+            }
+
+            self.state.text = Some(text);
+        }
+
+        // Once unfocused, the field shows the current value again
+        if !self.state.input.is_focused() {
+            self.state.text = None;
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let mut children = layout.children();
+        let input_layout = children.next().unwrap();
+        let increment_layout = children.next().unwrap();
+        let decrement_layout = children.next().unwrap();
+
+        let text_bounds = input_layout.children().next().unwrap().bounds();
+
+        let input = text_input::Renderer::draw(
+            renderer,
+            input_layout.bounds(),
+            text_bounds,
+            cursor_position,
+            self.font.clone(),
+            self.size.unwrap_or(renderer.default_size()),
+            "",
+            &text_input::Value::new(&self.text()),
+            &self.state.input,
+            &self.input_style,
+        );
+
+        let is_valid = match &self.state.text {
+            Some(text) => self.parse(text).is_some(),
+            None => true,
+        };
+
+        self::Renderer::draw(
+            renderer,
+            layout.bounds(),
+            cursor_position,
+            input,
+            increment_layout.bounds(),
+            decrement_layout.bounds(),
+            self.value < *self.bounds.end(),
+            self.value > *self.bounds.start(),
+            is_valid,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        use std::{any::TypeId, hash::Hash};
+        struct Marker;
+        TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.max_width.hash(state);
+        self.padding.hash(state);
+        self.size.hash(state);
+    }
+}
+
+/// The renderer of a [`NumberInput`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`NumberInput`] in your user interface.
+///
+/// [`NumberInput`]: struct.NumberInput.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`NumberInput`].
+    ///
+    /// It receives:
+    /// - the bounds of the [`NumberInput`]
+    /// - the cursor position
+    /// - the field of the [`NumberInput`], already drawn as a [`TextInput`]
+    /// - the bounds of the increment and decrement buttons
+    /// - whether the value can be incremented and decremented
+    /// - whether the typed text is a valid number between the bounds
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    /// [`TextInput`]: ../text_input/struct.TextInput.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        input: Self::Output,
+        increment_bounds: Rectangle,
+        decrement_bounds: Rectangle,
+        can_increment: bool,
+        can_decrement: bool,
+        is_valid: bool,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, T, Message, Renderer> From<NumberInput<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Number,
+    Message: 'a,
+    Renderer: 'a + self::Renderer + text_input::Renderer,
+{
+    fn from(
+        number_input: NumberInput<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(number_input)
+    }
+}
+
+/// The state of a [`NumberInput`].
+///
+/// [`NumberInput`]: struct.NumberInput.html
+#[derive(Debug, Default, Clone)]
+pub struct State {
+    input: text_input::State,
+    text: Option<String>,
+}
+
+impl State {
+    /// Creates a new [`State`], representing an unfocused [`NumberInput`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the [`NumberInput`] is currently focused or not.
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn is_focused(&self) -> bool {
+        self.input.is_focused()
+    }
+
+    /// Returns the text being typed in the [`NumberInput`], if it differs
+    /// from its current value.
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_ref().map(String::as_str)
+    }
+}
+
+/// A number that can be used in a [`NumberInput`].
+///
+/// It is implemented for all the primitive integer and floating point types.
+///
+/// [`NumberInput`]: struct.NumberInput.html
+pub trait Number:
+    'static + Copy + PartialOrd + std::fmt::Display + std::str::FromStr
+{
+    /// The smallest value of the type.
+    const MIN: Self;
+
+    /// The largest value of the type.
+    const MAX: Self;
+
+    /// The default step of a [`NumberInput`].
+    ///
+    /// [`NumberInput`]: struct.NumberInput.html
+    const ONE: Self;
+
+    /// Adds the given step, saturating at the largest value.
+    fn increment(self, step: Self) -> Self;
+
+    /// Subtracts the given step, saturating at the smallest value.
+    fn decrement(self, step: Self) -> Self;
+}
+
+macro_rules! integer {
+    ($($type:ident),*) => {
+        $(
+            impl Number for $type {
+                const MIN: Self = std::$type::MIN;
+                const MAX: Self = std::$type::MAX;
+                const ONE: Self = 1;
+
+                fn increment(self, step: Self) -> Self {
+                    self.saturating_add(step)
+                }
+
+                fn decrement(self, step: Self) -> Self {
+                    self.saturating_sub(step)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! float {
+    ($($type:ident),*) => {
+        $(
+            impl Number for $type {
+                const MIN: Self = std::$type::MIN;
+                const MAX: Self = std::$type::MAX;
+                const ONE: Self = 1.0;
+
+                fn increment(self, step: Self) -> Self {
+                    round((self + step).min(Self::MAX), self, step)
+                }
+
+                fn decrement(self, step: Self) -> Self {
+                    round((self - step).max(Self::MIN), self, step)
+                }
+            }
+        )*
+    };
+}
+
+integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+float!(f32, f64);
+
+/// Rounds the result of stepping to the precision of its operands, dropping
+/// the error accumulated by floating point arithmetic.
+fn round<T: Number>(result: T, value: T, step: T) -> T {
+    let decimals = decimals(value).max(decimals(step));
+
+    format!("{:.*}", decimals, result).parse().unwrap_or(result)
+}
+
+fn decimals<T: Number>(number: T) -> usize {
+    let text = number.to_string();
+
+    text.find('.').map_or(0, |dot| text.len() - dot - 1)
+}
+
+fn clamp<T: Number>(value: T, bounds: &RangeInclusive<T>) -> T {
+    if value < *bounds.start() {
+        *bounds.start()
+    } else if value > *bounds.end() {
+        *bounds.end()
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+
+    type NumberInput<'a, T> = super::NumberInput<'a, T, T, Null>;
+
+    #[test]
+    fn parsing() {
+        let mut state = State::new();
+        let input =
+            NumberInput::new(&mut state, 5, |value| value).bounds(1..=10);
+
+        assert_eq!(input.parse("7"), Some(7));
+        assert_eq!(input.parse(" 10 "), Some(10));
+        assert_eq!(input.parse("11"), None);
+        assert_eq!(input.parse("0"), None);
+        assert_eq!(input.parse("7.5"), None);
+        assert_eq!(input.parse("seven"), None);
+
+        let mut state = State::new();
+        let input =
+            NumberInput::new(&mut state, 0.5, |value| value).bounds(0.0..=1.0);
+
+        assert_eq!(input.parse("0.25"), Some(0.25));
+        assert_eq!(input.parse(".5"), Some(0.5));
+        assert_eq!(input.parse("0,5"), None);
+        assert_eq!(input.parse("1.5"), None);
+    }
+
+    #[test]
+    fn clamping() {
+        assert_eq!(clamp(5, &(1..=10)), 5);
+        assert_eq!(clamp(0, &(1..=10)), 1);
+        assert_eq!(clamp(11, &(1..=10)), 10);
+        assert_eq!(clamp(-0.5, &(0.0..=1.0)), 0.0);
+    }
+
+    #[test]
+    fn stepping() {
+        assert_eq!(u8::MAX.increment(1), u8::MAX);
+        assert_eq!(0u8.decrement(1), 0);
+        assert_eq!(f32::MAX.increment(1.0), f32::MAX);
+
+        let mut value = 0.0f64;
+
+        for _ in 0..3 {
+            value = value.increment(0.1);
+        }
+
+        assert_eq!(value, 0.3);
+        assert_eq!(0.3f64.decrement(0.1), 0.2);
+        assert_eq!(0.15f32.increment(0.1), 0.25);
+        assert_eq!(1.0f32.increment(0.25), 1.25);
+    }
+
+    #[test]
+    fn stepping_messages() {
+        let mut state = State::new();
+        let mut messages = Vec::new();
+
+        let mut input = NumberInput::new(&mut state, 0.2, |value| value)
+            .bounds(0.0..=0.3)
+            .step(0.1);
+
+        input.step_by(true, &mut messages);
+        input.value = messages[0];
+        input.step_by(true, &mut messages);
+
+        assert_eq!(messages, vec![0.3]);
+    }
+}
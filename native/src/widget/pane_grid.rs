@@ -25,8 +25,8 @@ pub use split::Split;
 pub use state::{Focus, State};
 
 use crate::{
-    keyboard, layout, mouse, Clipboard, Element, Event, Hasher, Layout, Length,
-    Point, Rectangle, Size, Widget,
+    event, keyboard, layout, mouse, Clipboard, Element, Event, Hasher, Layout,
+    Length, Point, Rectangle, Size, Widget,
 };
 
 /// A collection of panes distributed using either vertical or horizontal splits
@@ -283,7 +283,7 @@ impl<'a, Message, Renderer> PaneGrid<'a, Message, Renderer> {
         layout: Layout<'_>,
         cursor_position: Point,
         messages: &mut Vec<Message>,
-    ) {
+    ) -> event::Status {
         if let Some((_, on_resize)) = &self.on_resize {
             if let Some((split, _)) = self.state.picked_split() {
                 let bounds = layout.bounds();
@@ -310,9 +310,13 @@ impl<'a, Message, Renderer> PaneGrid<'a, Message, Renderer> {
                     };
 
                     messages.push(on_resize(ResizeEvent { split, ratio }));
+
+                    return event::Status::Captured;
                 }
             }
         }
+
+        event::Status::Ignored
     }
 }
 
@@ -439,7 +443,9 @@ where
         messages: &mut Vec<Message>,
         renderer: &Renderer,
         clipboard: Option<&dyn Clipboard>,
-    ) {
+    ) -> event::Status {
+        let mut event_status = event::Status::Ignored;
+
         match event {
             Event::Mouse(mouse_event) => match mouse_event {
                 mouse::Event::ButtonPressed(mouse::Button::Left) => {
@@ -466,6 +472,8 @@ where
 
                                 if let Some((split, axis)) = clicked_split {
                                     self.state.pick_split(&split, axis);
+
+                                    event_status = event::Status::Captured;
                                 } else {
                                     self.click_pane(
                                         layout,
@@ -509,12 +517,17 @@ where
 
                             messages.push(on_drag(event));
                         }
+
+                        event_status = event::Status::Captured;
                     } else if self.state.picked_split().is_some() {
                         self.state.drop_split();
+
+                        event_status = event::Status::Captured;
                     }
                 }
                 mouse::Event::CursorMoved { .. } => {
-                    self.trigger_resize(layout, cursor_position, messages);
+                    event_status =
+                        self.trigger_resize(layout, cursor_position, messages);
                 }
                 _ => {}
             },
@@ -535,6 +548,8 @@ where
                                         })
                                     {
                                         messages.push(message);
+
+                                        event_status = event::Status::Captured;
                                     }
                                 }
                             }
@@ -552,20 +567,22 @@ where
         }
 
         if self.state.picked_pane().is_none() {
-            {
-                self.elements.iter_mut().zip(layout.children()).for_each(
-                    |((_, pane), layout)| {
-                        pane.widget.on_event(
-                            event.clone(),
-                            layout,
-                            cursor_position,
-                            messages,
-                            renderer,
-                            clipboard,
-                        )
-                    },
-                );
-            }
+            self.elements
+                .iter_mut()
+                .zip(layout.children())
+                .map(|((_, pane), layout)| {
+                    pane.widget.on_event(
+                        event.clone(),
+                        layout,
+                        cursor_position,
+                        messages,
+                        renderer,
+                        clipboard,
+                    )
+                })
+                .fold(event_status, event::Status::merge)
+        } else {
+            event::Status::Captured
         }
     }
 
@@ -1,6 +1,6 @@
 //! Create choices using radio buttons.
 use crate::{
-    layout, mouse, row, text, Align, Clipboard, Element, Event, Hasher,
+    event, layout, mouse, row, text, Align, Clipboard, Element, Event, Hasher,
     HorizontalAlignment, Layout, Length, Point, Rectangle, Row, Text,
     VerticalAlignment, Widget,
 };
@@ -164,15 +164,19 @@ where
         messages: &mut Vec<Message>,
         _renderer: &Renderer,
         _clipboard: Option<&dyn Clipboard>,
-    ) {
+    ) -> event::Status {
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 if layout.bounds().contains(cursor_position) {
                     messages.push(self.on_click.clone());
+
+                    return event::Status::Captured;
                 }
             }
             _ => {}
         }
+
+        event::Status::Ignored
     }
 
     fn draw(
@@ -199,6 +203,9 @@ where
             None,
             HorizontalAlignment::Left,
             VerticalAlignment::Center,
+            Default::default(),
+            Default::default(),
+            Default::default(),
         );
 
         let is_mouse_over = bounds.contains(cursor_position);
@@ -0,0 +1,445 @@
+//! Write paragraphs mixing different styles and links.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::text;
+use crate::{
+    Clipboard, Color, Element, Hasher, HorizontalAlignment, Layout, Length,
+    Point, Rectangle, Size, VerticalAlignment, Widget,
+};
+
+use std::hash::Hash;
+
+/// A paragraph of text made of [`Span`]s, each one with its own style.
+///
+/// The spans are laid out and wrapped together, as a single paragraph.
+/// Clicking a span with a link produces its message.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{renderer::Null, rich_text::Span};
+/// #
+/// # pub type RichText<Message> = iced_native::RichText<Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     OpenDocs,
+/// }
+///
+/// let text = RichText::new()
+///     .push(Span::new("Read the "))
+///     .push(
+///         Span::new("documentation")
+///             .color([0.0, 0.0, 1.0])
+///             .link(Message::OpenDocs),
+///     )
+///     .push(Span::new(" to learn more!"))
+///     .size(20);
+/// ```
+///
+/// [`Span`]: struct.Span.html
+#[allow(missing_debug_implementations)]
+pub struct RichText<Message, Renderer: text::Renderer> {
+    spans: Vec<Span<Message, Renderer::Font>>,
+    size: Option<u16>,
+    color: Option<Color>,
+    font: Renderer::Font,
+    width: Length,
+    height: Length,
+    horizontal_alignment: HorizontalAlignment,
+    vertical_alignment: VerticalAlignment,
+}
+
+impl<Message, Renderer: self::Renderer> RichText<Message, Renderer> {
+    /// Creates an empty [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn new() -> Self {
+        Self::with_spans(Vec::new())
+    }
+
+    /// Creates a [`RichText`] with the given spans.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn with_spans(spans: Vec<Span<Message, Renderer::Font>>) -> Self {
+        RichText {
+            spans,
+            size: None,
+            color: None,
+            font: Default::default(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            horizontal_alignment: HorizontalAlignment::Left,
+            vertical_alignment: VerticalAlignment::Top,
+        }
+    }
+
+    /// Adds a [`Span`] to the end of the [`RichText`].
+    ///
+    /// [`Span`]: struct.Span.html
+    /// [`RichText`]: struct.RichText.html
+    pub fn push(mut self, span: Span<Message, Renderer::Font>) -> Self {
+        self.spans.push(span);
+        self
+    }
+
+    /// Sets the default size of the spans of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the default [`Color`] of the spans of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    /// [`Color`]: ../../struct.Color.html
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the default [`Font`] of the spans of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    /// [`Font`]: ../../struct.Font.html
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the width of the [`RichText`] boundaries.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`RichText`] boundaries.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`HorizontalAlignment`] of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    /// [`HorizontalAlignment`]: ../../enum.HorizontalAlignment.html
+    pub fn horizontal_alignment(
+        mut self,
+        alignment: HorizontalAlignment,
+    ) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the [`VerticalAlignment`] of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    /// [`VerticalAlignment`]: ../../enum.VerticalAlignment.html
+    pub fn vertical_alignment(mut self, alignment: VerticalAlignment) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Returns the index of the span with a link under the given point.
+    fn link_at(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        point: Point,
+    ) -> Option<usize> {
+        if !bounds.contains(point) {
+            return None;
+        }
+
+        self::Renderer::hit_test(
+            renderer,
+            &self.spans,
+            self.size.unwrap_or(Renderer::DEFAULT_SIZE),
+            self.font.clone(),
+            bounds,
+            self.horizontal_alignment,
+            self.vertical_alignment,
+            point,
+        )
+        .filter(|index| self.spans[*index].link.is_some())
+    }
+}
+
+impl<Message, Renderer: self::Renderer> Default
+    for RichText<Message, Renderer>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer>
+    for RichText<Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = self.size.unwrap_or(Renderer::DEFAULT_SIZE);
+
+        let bounds = limits.max();
+
+        let (width, height) = self::Renderer::measure(
+            renderer,
+            &self.spans,
+            size,
+            self.font.clone(),
+            bounds,
+        );
+
+        let size = limits.resolve(Size::new(width, height));
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let link =
+                    self.link_at(renderer, layout.bounds(), cursor_position);
+
+                if let Some(index) = link {
+                    if let Some(message) = &self.spans[index].link {
+                        messages.push(message.clone());
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let hovered_link = self.link_at(renderer, bounds, cursor_position);
+
+        self::Renderer::draw(
+            renderer,
+            defaults,
+            bounds,
+            &self.spans,
+            self.size.unwrap_or(Renderer::DEFAULT_SIZE),
+            self.font.clone(),
+            self.color,
+            self.horizontal_alignment,
+            self.vertical_alignment,
+            hovered_link,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        for span in &self.spans {
+            span.content.hash(state);
+            span.size.hash(state);
+        }
+
+        self.size.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// A piece of a [`RichText`] with its own style and an optional link.
+///
+/// Any style left unset falls back to the style of the [`RichText`].
+///
+/// [`RichText`]: struct.RichText.html
+#[derive(Debug, Clone)]
+pub struct Span<Message, Font> {
+    /// The contents of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    pub content: String,
+
+    /// The size of the [`Span`], if any.
+    ///
+    /// [`Span`]: struct.Span.html
+    pub size: Option<u16>,
+
+    /// The [`Color`] of the [`Span`], if any.
+    ///
+    /// [`Span`]: struct.Span.html
+    /// [`Color`]: ../../struct.Color.html
+    pub color: Option<Color>,
+
+    /// The font of the [`Span`], if any.
+    ///
+    /// [`Span`]: struct.Span.html
+    pub font: Option<Font>,
+
+    /// The message produced when the [`Span`] is clicked, if any.
+    ///
+    /// [`Span`]: struct.Span.html
+    pub link: Option<Message>,
+}
+
+impl<Message, Font> Span<Message, Font> {
+    /// Creates a new [`Span`] with the given contents.
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn new<T: Into<String>>(content: T) -> Self {
+        Span {
+            content: content.into(),
+            size: None,
+            color: None,
+            font: None,
+            link: None,
+        }
+    }
+
+    /// Sets the size of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the [`Color`] of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    /// [`Color`]: ../../struct.Color.html
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the font of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the message produced when the [`Span`] is clicked.
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn link(mut self, message: Message) -> Self {
+        self.link = Some(message);
+        self
+    }
+}
+
+/// The renderer of a [`RichText`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`RichText`] in your user interface.
+///
+/// [`RichText`]: struct.RichText.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer {
+    /// Measures the spans of a [`RichText`] laid out as a single paragraph
+    /// in the given bounds and returns the minimum boundaries that can fit
+    /// the contents.
+    ///
+    /// Spans without a size or a font use the provided ones.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    fn measure<Message>(
+        &self,
+        spans: &[Span<Message, Self::Font>],
+        size: u16,
+        font: Self::Font,
+        bounds: Size,
+    ) -> (f32, f32);
+
+    /// Returns the index of the span of a [`RichText`] found at the given
+    /// point, if any.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    fn hit_test<Message>(
+        &self,
+        spans: &[Span<Message, Self::Font>],
+        size: u16,
+        font: Self::Font,
+        bounds: Rectangle,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+        point: Point,
+    ) -> Option<usize>;
+
+    /// Draws a [`RichText`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`RichText`]
+    ///   * the spans of the [`RichText`]
+    ///   * the default size, font and color of the spans
+    ///   * the [`HorizontalAlignment`] of the [`RichText`]
+    ///   * the [`VerticalAlignment`] of the [`RichText`]
+    ///   * the index of the link under the mouse cursor, if any
+    ///
+    /// [`RichText`]: struct.RichText.html
+    /// [`HorizontalAlignment`]: ../../enum.HorizontalAlignment.html
+    /// [`VerticalAlignment`]: ../../enum.VerticalAlignment.html
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        spans: &[Span<Message, Self::Font>],
+        size: u16,
+        font: Self::Font,
+        color: Option<Color>,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+        hovered_link: Option<usize>,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<RichText<Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: self::Renderer + 'a,
+{
+    fn from(
+        rich_text: RichText<Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(rich_text)
+    }
+}
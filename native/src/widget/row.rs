@@ -2,8 +2,8 @@
 use std::hash::Hash;
 
 use crate::{
-    layout, Align, Clipboard, Element, Event, Hasher, Layout, Length, Point,
-    Widget,
+    event, layout, Align, Clipboard, Element, Event, Hasher, Layout, Length,
+    Point, Widget,
 };
 
 use std::u32;
@@ -165,9 +165,11 @@ where
         messages: &mut Vec<Message>,
         renderer: &Renderer,
         clipboard: Option<&dyn Clipboard>,
-    ) {
-        self.children.iter_mut().zip(layout.children()).for_each(
-            |(child, layout)| {
+    ) -> event::Status {
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .map(|(child, layout)| {
                 child.widget.on_event(
                     event.clone(),
                     layout,
@@ -176,8 +178,8 @@ where
                     renderer,
                     clipboard,
                 )
-            },
-        );
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
     }
 
     fn draw(
@@ -1,19 +1,28 @@
 //! Navigate an endless amount of content with a scrollbar.
 use crate::{
-    column, layout, mouse, Align, Clipboard, Column, Element, Event, Hasher,
-    Layout, Length, Point, Rectangle, Size, Widget,
+    column, event, keyboard, layout, mouse, window, Align, Clipboard, Column,
+    Element, Event, Hasher, Layout, Length, Point, Rectangle, Size, Vector,
+    Widget,
 };
 
+use std::cell::RefCell;
+use std::sync::Arc;
+use std::time::Instant;
 use std::{f32, hash::Hash, u32};
 
-/// A widget that can vertically display an infinite amount of content with a
-/// scrollbar.
+/// A widget that can display an infinite amount of content with
+/// scrollbars, vertically, horizontally or in both directions.
 #[allow(missing_debug_implementations)]
 pub struct Scrollable<'a, Message, Renderer: self::Renderer> {
     state: &'a mut State,
+    direction: Direction,
+    smooth: bool,
+    kinetic: bool,
+    width: Length,
     height: Length,
     max_height: u32,
     content: Column<'a, Message, Renderer>,
+    on_scroll: Option<Box<dyn Fn(Viewport) -> Message + 'a>>,
     style: Renderer::Style,
 }
 
@@ -25,9 +34,14 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
     pub fn new(state: &'a mut State) -> Self {
         Scrollable {
             state,
+            direction: Direction::Vertical,
+            smooth: false,
+            kinetic: false,
+            width: Length::Shrink,
             height: Length::Shrink,
             max_height: u32::MAX,
             content: Column::new(),
+            on_scroll: None,
             style: Renderer::Style::default(),
         }
     }
@@ -50,11 +64,56 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the [`Direction`] in which the [`Scrollable`] can be scrolled.
+    ///
+    /// By default, a [`Scrollable`] only scrolls vertically.
+    ///
+    /// When scrolling horizontally, the contents of the [`Scrollable`] are
+    /// laid out with unbounded width, so they should not fill the available
+    /// horizontal space.
+    ///
+    /// [`Direction`]: enum.Direction.html
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+
+        if direction.is_horizontal() {
+            self.content = self.content.width(Length::Shrink);
+        }
+
+        self
+    }
+
+    /// Sets whether the [`Scrollable`] should animate towards its new scroll
+    /// position when scrolled with a mouse wheel.
+    ///
+    /// By default, a [`Scrollable`] jumps to its new position immediately.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn smooth(mut self, smooth: bool) -> Self {
+        self.smooth = smooth;
+        self
+    }
+
+    /// Sets whether the [`Scrollable`] should keep scrolling and slowly
+    /// decelerate after a touchpad gesture ends.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn kinetic(mut self, kinetic: bool) -> Self {
+        self.kinetic = kinetic;
+        self
+    }
+
     /// Sets the width of the [`Scrollable`].
     ///
     /// [`Scrollable`]: struct.Scrollable.html
     pub fn width(mut self, width: Length) -> Self {
-        self.content = self.content.width(width);
+        self.width = width;
+
+        if !self.direction.is_horizontal() {
+            self.content = self.content.width(width);
+        }
+
         self
     }
 
@@ -90,6 +149,21 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the message that should be produced when the [`Scrollable`] is
+    /// scrolled.
+    ///
+    /// The closure receives the new [`Viewport`] of the [`Scrollable`].
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`Viewport`]: struct.Viewport.html
+    pub fn on_scroll<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(Viewport) -> Message,
+    {
+        self.on_scroll = Some(Box::new(f));
+        self
+    }
+
     /// Sets the style of the [`Scrollable`] .
     ///
     /// [`Scrollable`]: struct.Scrollable.html
@@ -110,13 +184,103 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
     }
 }
 
+impl<'a, Message, Renderer> Scrollable<'a, Message, Renderer>
+where
+    Renderer: self::Renderer + column::Renderer,
+{
+    fn offset(&self, layout: Layout<'_>) -> Vector<u32> {
+        let bounds = layout.bounds();
+        let content = layout.children().next().unwrap();
+
+        self.direction.mask(self.state.requested_offset(
+            bounds,
+            content.bounds(),
+            |index| content.children().nth(index).map(|child| child.bounds()),
+        ))
+    }
+
+    fn update(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let content = layout.children().next().unwrap();
+        let content_bounds = content.bounds();
+
+        self.state.resolve(
+            bounds,
+            content_bounds,
+            |index| content.children().nth(index).map(|child| child.bounds()),
+            self.direction,
+        );
+
+        if let Event::Keyboard(keyboard_event) = event {
+            self.state.track_modifiers(keyboard_event);
+        }
+
+        let offset = self.offset(layout);
+        let scrollbars =
+            renderer.scrollbars(bounds, content_bounds, offset, self.direction);
+        let mouse_over_scrollbar = scrollbars.mouse_over(cursor_position);
+
+        let event_status = {
+            let cursor_position =
+                if is_mouse_over && mouse_over_scrollbar.is_none() {
+                    Point::new(
+                        cursor_position.x + offset.x as f32,
+                        cursor_position.y + offset.y as f32,
+                    )
+                } else {
+                    // TODO: Make `cursor_position` an `Option<Point>` so we can encode
+                    // cursor availability.
+                    // This will probably happen naturally once we add multi-window
+                    // support.
+                    Point::new(cursor_position.x, -1.0)
+                };
+
+            self.content.on_event(
+                event.clone(),
+                content,
+                cursor_position,
+                messages,
+                renderer,
+                clipboard,
+            )
+        };
+
+        if let event::Status::Captured = event_status {
+            return event::Status::Captured;
+        }
+
+        self.state.on_event(
+            event,
+            bounds,
+            content_bounds,
+            cursor_position,
+            &scrollbars,
+            Behavior {
+                direction: self.direction,
+                smooth: self.smooth,
+                kinetic: self.kinetic,
+            },
+        )
+    }
+}
+
 impl<'a, Message, Renderer> Widget<Message, Renderer>
     for Scrollable<'a, Message, Renderer>
 where
     Renderer: self::Renderer + column::Renderer,
 {
     fn width(&self) -> Length {
-        Widget::<Message, Renderer>::width(&self.content)
+        self.width
     }
 
     fn height(&self) -> Length {
@@ -130,12 +294,24 @@ where
     ) -> layout::Node {
         let limits = limits
             .max_height(self.max_height)
-            .width(Widget::<Message, Renderer>::width(&self.content))
+            .width(self.width)
             .height(self.height);
 
+        let max_width = if self.direction.is_horizontal() {
+            f32::INFINITY
+        } else {
+            limits.max().width
+        };
+
+        let max_height = if self.direction.is_vertical() {
+            f32::INFINITY
+        } else {
+            limits.max().height
+        };
+
         let child_limits = layout::Limits::new(
             Size::new(limits.min().width, 0.0),
-            Size::new(limits.max().width, f32::INFINITY),
+            Size::new(max_width, max_height),
         );
 
         let content = self.content.layout(renderer, &child_limits);
@@ -152,110 +328,34 @@ where
         messages: &mut Vec<Message>,
         renderer: &Renderer,
         clipboard: Option<&dyn Clipboard>,
-    ) {
-        let bounds = layout.bounds();
-        let is_mouse_over = bounds.contains(cursor_position);
+    ) -> event::Status {
+        let previous_offset = self.offset(layout);
 
-        let content = layout.children().next().unwrap();
-        let content_bounds = content.bounds();
-
-        // TODO: Event capture. Nested scrollables should capture scroll events.
-        if is_mouse_over {
-            match event {
-                Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
-                    match delta {
-                        mouse::ScrollDelta::Lines { y, .. } => {
-                            // TODO: Configurable speed (?)
-                            self.state.scroll(y * 60.0, bounds, content_bounds);
-                        }
-                        mouse::ScrollDelta::Pixels { y, .. } => {
-                            self.state.scroll(y, bounds, content_bounds);
-                        }
-                    }
-                }
-                _ => {}
-            }
-        }
+        let event_status = self.update(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
 
-        let offset = self.state.offset(bounds, content_bounds);
-        let scrollbar = renderer.scrollbar(bounds, content_bounds, offset);
-        let is_mouse_over_scrollbar = scrollbar
-            .as_ref()
-            .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
-            .unwrap_or(false);
+        let offset = self.offset(layout);
 
-        if self.state.is_scroller_grabbed() {
-            match event {
-                Event::Mouse(mouse::Event::ButtonReleased(
-                    mouse::Button::Left,
-                )) => {
-                    self.state.scroller_grabbed_at = None;
-                }
-                Event::Mouse(mouse::Event::CursorMoved { .. }) => {
-                    if let (Some(scrollbar), Some(scroller_grabbed_at)) =
-                        (scrollbar, self.state.scroller_grabbed_at)
-                    {
-                        self.state.scroll_to(
-                            scrollbar.scroll_percentage(
-                                scroller_grabbed_at,
-                                cursor_position,
-                            ),
-                            bounds,
-                            content_bounds,
-                        );
-                    }
-                }
-                _ => {}
-            }
-        } else if is_mouse_over_scrollbar {
-            match event {
-                Event::Mouse(mouse::Event::ButtonPressed(
-                    mouse::Button::Left,
-                )) => {
-                    if let Some(scrollbar) = scrollbar {
-                        if let Some(scroller_grabbed_at) =
-                            scrollbar.grab_scroller(cursor_position)
-                        {
-                            self.state.scroll_to(
-                                scrollbar.scroll_percentage(
-                                    scroller_grabbed_at,
-                                    cursor_position,
-                                ),
-                                bounds,
-                                content_bounds,
-                            );
+        if offset != previous_offset {
+            if let Some(on_scroll) = &self.on_scroll {
+                let bounds = layout.bounds();
+                let content_bounds = layout.children().next().unwrap().bounds();
 
-                            self.state.scroller_grabbed_at =
-                                Some(scroller_grabbed_at);
-                        }
-                    }
-                }
-                _ => {}
+                messages.push(on_scroll(Viewport {
+                    offset: Vector::new(offset.x as f32, offset.y as f32),
+                    size: bounds.size(),
+                    content_size: content_bounds.size(),
+                }));
             }
         }
 
-        let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
-            Point::new(
-                cursor_position.x,
-                cursor_position.y
-                    + self.state.offset(bounds, content_bounds) as f32,
-            )
-        } else {
-            // TODO: Make `cursor_position` an `Option<Point>` so we can encode
-            // cursor availability.
-            // This will probably happen naturally once we add multi-window
-            // support.
-            Point::new(cursor_position.x, -1.0)
-        };
-
-        self.content.on_event(
-            event,
-            content,
-            cursor_position,
-            messages,
-            renderer,
-            clipboard,
-        )
+        event_status
     }
 
     fn draw(
@@ -268,21 +368,23 @@ where
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
         let content_bounds = content_layout.bounds();
-        let offset = self.state.offset(bounds, content_bounds);
-        let scrollbar = renderer.scrollbar(bounds, content_bounds, offset);
+        let offset = self.offset(layout);
+        let scrollbars =
+            renderer.scrollbars(bounds, content_bounds, offset, self.direction);
 
         let is_mouse_over = bounds.contains(cursor_position);
-        let is_mouse_over_scrollbar = scrollbar
-            .as_ref()
-            .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
-            .unwrap_or(false);
+        let mouse_over_scrollbar = scrollbars.mouse_over(cursor_position);
 
         let content = {
-            let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
-                Point::new(cursor_position.x, cursor_position.y + offset as f32)
-            } else {
-                Point::new(cursor_position.x, -1.0)
-            };
+            let cursor_position =
+                if is_mouse_over && mouse_over_scrollbar.is_none() {
+                    Point::new(
+                        cursor_position.x + offset.x as f32,
+                        cursor_position.y + offset.y as f32,
+                    )
+                } else {
+                    Point::new(cursor_position.x, -1.0)
+                };
 
             self.content.draw(
                 renderer,
@@ -298,8 +400,8 @@ where
             bounds,
             content_layout.bounds(),
             is_mouse_over,
-            is_mouse_over_scrollbar,
-            scrollbar,
+            mouse_over_scrollbar,
+            scrollbars,
             offset,
             &self.style,
             content,
@@ -310,6 +412,8 @@ where
         struct Marker;
         std::any::TypeId::of::<Marker>().hash(state);
 
+        self.direction.hash(state);
+        self.width.hash(state);
         self.height.hash(state);
         self.max_height.hash(state);
 
@@ -317,17 +421,184 @@ where
     }
 }
 
+/// The directions in which a [`Scrollable`] can be scrolled.
+///
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Scroll vertically only.
+    Vertical,
+
+    /// Scroll horizontally only.
+    Horizontal,
+
+    /// Scroll both vertically and horizontally.
+    Both,
+}
+
+impl Direction {
+    /// Returns true if the [`Direction`] allows vertical scrolling.
+    ///
+    /// [`Direction`]: enum.Direction.html
+    pub fn is_vertical(&self) -> bool {
+        match self {
+            Direction::Vertical | Direction::Both => true,
+            Direction::Horizontal => false,
+        }
+    }
+
+    /// Returns true if the [`Direction`] allows horizontal scrolling.
+    ///
+    /// [`Direction`]: enum.Direction.html
+    pub fn is_horizontal(&self) -> bool {
+        match self {
+            Direction::Horizontal | Direction::Both => true,
+            Direction::Vertical => false,
+        }
+    }
+
+    fn mask(&self, offset: Vector<u32>) -> Vector<u32> {
+        Vector::new(
+            if self.is_horizontal() { offset.x } else { 0 },
+            if self.is_vertical() { offset.y } else { 0 },
+        )
+    }
+}
+
+impl Default for Direction {
+    fn default() -> Self {
+        Direction::Vertical
+    }
+}
+
+/// The orientation of a [`Scrollbar`].
+///
+/// [`Scrollbar`]: struct.Scrollbar.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    /// A vertical [`Scrollbar`], placed on the right side.
+    ///
+    /// [`Scrollbar`]: struct.Scrollbar.html
+    Vertical,
+
+    /// A horizontal [`Scrollbar`], placed on the bottom side.
+    ///
+    /// [`Scrollbar`]: struct.Scrollbar.html
+    Horizontal,
+}
+
+/// The visible region of the contents of a [`Scrollable`].
+///
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    /// The absolute scrolling offset of the contents, in pixels.
+    pub offset: Vector,
+
+    /// The size of the visible region.
+    pub size: Size,
+
+    /// The size of the contents.
+    pub content_size: Size,
+}
+
+impl Viewport {
+    /// Returns the scrolling offset of the [`Viewport`] relative to the
+    /// scrollable amount of content in each direction.
+    ///
+    /// `0` represents the contents scrolled to the top (or left), while `1`
+    /// represents the contents scrolled to the bottom (or right).
+    ///
+    /// [`Viewport`]: struct.Viewport.html
+    pub fn relative_offset(&self) -> Vector {
+        let hidden_width = self.content_size.width - self.size.width;
+        let hidden_height = self.content_size.height - self.size.height;
+
+        Vector::new(
+            if hidden_width > 0.0 {
+                self.offset.x / hidden_width
+            } else {
+                0.0
+            },
+            if hidden_height > 0.0 {
+                self.offset.y / hidden_height
+            } else {
+                0.0
+            },
+        )
+    }
+
+    /// Returns the remaining amount of content below the [`Viewport`], in
+    /// pixels.
+    ///
+    /// [`Viewport`]: struct.Viewport.html
+    pub fn distance_to_bottom(&self) -> f32 {
+        (self.content_size.height - self.size.height - self.offset.y).max(0.0)
+    }
+}
+
+/// How a scrollable widget reacts to scrolling.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Behavior {
+    pub(crate) direction: Direction,
+    pub(crate) smooth: bool,
+    pub(crate) kinetic: bool,
+}
+
 /// The local state of a [`Scrollable`].
 ///
 /// [`Scrollable`]: struct.Scrollable.html
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct State {
-    scroller_grabbed_at: Option<f32>,
-    offset: f32,
+    scroller_grabbed_at: Option<(Orientation, f32)>,
+    offset: Vector,
+    request: Option<Request>,
+    animation: Option<Animation>,
+    keyboard_modifiers: keyboard::ModifiersState,
+    row_offsets: RefCell<Option<(u64, Arc<Vec<f32>>)>>,
+}
+
+/// A scrolling position requested without knowing the bounds of the
+/// [`Scrollable`], resolved once its layout is available.
+///
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Request {
+    RelativeOffset(Vector),
+    Child(usize),
 }
 
+/// An ongoing scrolling animation of a [`State`].
+///
+/// [`State`]: struct.State.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Animation {
+    /// Easing towards a target offset.
+    Smooth { target: Vector, tick: Instant },
+
+    /// Following a touchpad gesture, and coasting once it ends.
+    Kinetic {
+        velocity: Vector,
+        last_input: Instant,
+        tick: Instant,
+    },
+}
+
+/// The time it takes for a smooth scroll to cover ~63% of its distance.
+const SMOOTH_SCROLLING_TIME_CONSTANT: f32 = 0.05;
+
+/// The time without touchpad input, in seconds, after which a gesture is
+/// considered over.
+const KINETIC_GESTURE_TIMEOUT: f32 = 0.05;
+
+/// The rate at which kinetic scrolling loses speed, per second.
+const KINETIC_FRICTION: f32 = 4.0;
+
+/// The speed, in pixels per second, below which kinetic scrolling stops.
+const KINETIC_MIN_SPEED: f32 = 10.0;
+
 impl State {
-    /// Creates a new [`State`] with the scrollbar located at the top.
+    /// Creates a new [`State`] with the scrollbars located at the top left.
     ///
     /// [`State`]: struct.State.html
     pub fn new() -> Self {
@@ -341,21 +612,28 @@ impl State {
     /// [`State`]: struct.State.html
     pub fn scroll(
         &mut self,
-        delta_y: f32,
+        delta: Vector,
         bounds: Rectangle,
         content_bounds: Rectangle,
     ) {
-        if bounds.height >= content_bounds.height {
-            return;
+        self.settle(bounds, content_bounds);
+        self.animation = None;
+
+        if bounds.height < content_bounds.height {
+            self.offset.y = (self.offset.y - delta.y)
+                .max(0.0)
+                .min(content_bounds.height - bounds.height);
         }
 
-        self.offset = (self.offset - delta_y)
-            .max(0.0)
-            .min((content_bounds.height - bounds.height) as f32);
+        if bounds.width < content_bounds.width {
+            self.offset.x = (self.offset.x - delta.x)
+                .max(0.0)
+                .min(content_bounds.width - bounds.width);
+        }
     }
 
-    /// Moves the scroll position to a relative amount, given the bounds of
-    /// the [`Scrollable`] and its contents.
+    /// Moves the vertical scroll position to a relative amount, given the
+    /// bounds of the [`Scrollable`] and its contents.
     ///
     /// `0` represents scrollbar at the top, while `1` represents scrollbar at
     /// the bottom.
@@ -368,56 +646,673 @@ impl State {
         bounds: Rectangle,
         content_bounds: Rectangle,
     ) {
-        self.offset =
+        self.settle(bounds, content_bounds);
+        self.animation = None;
+
+        self.offset.y =
             ((content_bounds.height - bounds.height) * percentage).max(0.0);
     }
 
-    /// Returns the current scrolling offset of the [`State`], given the bounds
-    /// of the [`Scrollable`] and its contents.
+    /// Moves the horizontal scroll position to a relative amount, given the
+    /// bounds of the [`Scrollable`] and its contents.
+    ///
+    /// `0` represents scrollbar at the left, while `1` represents scrollbar at
+    /// the right.
     ///
     /// [`Scrollable`]: struct.Scrollable.html
     /// [`State`]: struct.State.html
-    pub fn offset(&self, bounds: Rectangle, content_bounds: Rectangle) -> u32 {
-        let hidden_content =
-            (content_bounds.height - bounds.height).max(0.0).round() as u32;
+    pub fn scroll_horizontally_to(
+        &mut self,
+        percentage: f32,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        self.settle(bounds, content_bounds);
+        self.animation = None;
 
-        self.offset.min(hidden_content as f32) as u32
+        self.offset.x =
+            ((content_bounds.width - bounds.width) * percentage).max(0.0);
     }
 
-    /// Returns whether the scroller is currently grabbed or not.
-    pub fn is_scroller_grabbed(&self) -> bool {
-        self.scroller_grabbed_at.is_some()
+    /// Moves the scroll position to the given absolute offset, in pixels.
+    ///
+    /// The offset is clamped to the contents of the [`Scrollable`] once its
+    /// bounds are known.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn scroll_to_offset(&mut self, offset: Vector) {
+        self.offset = Vector::new(offset.x.max(0.0), offset.y.max(0.0));
+        self.request = None;
+        self.animation = None;
     }
-}
 
-/// The scrollbar of a [`Scrollable`].
-///
-/// [`Scrollable`]: struct.Scrollable.html
-#[derive(Debug)]
-pub struct Scrollbar {
-    /// The bounds of the [`Scrollbar`].
+    /// Snaps the scroll position to a relative amount in each direction.
     ///
-    /// [`Scrollbar`]: struct.Scrollbar.html
-    pub bounds: Rectangle,
-
-    /// The bounds of the [`Scroller`].
+    /// `0` represents the contents scrolled to the top (or left), while `1`
+    /// represents the contents scrolled to the bottom (or right).
     ///
-    /// [`Scroller`]: struct.Scroller.html
-    pub scroller: Scroller,
-}
-
-impl Scrollbar {
-    fn is_mouse_over(&self, cursor_position: Point) -> bool {
-        self.bounds.contains(cursor_position)
+    /// Unlike [`scroll_to`], this does not need the bounds of the
+    /// [`Scrollable`]. The position is resolved the next time it is laid out.
+    ///
+    /// [`scroll_to`]: #method.scroll_to
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn snap_to(&mut self, relative_offset: Vector) {
+        self.request = Some(Request::RelativeOffset(relative_offset));
+        self.animation = None;
     }
 
-    fn grab_scroller(&self, cursor_position: Point) -> Option<f32> {
-        if self.bounds.contains(cursor_position) {
-            Some(if self.scroller.bounds.contains(cursor_position) {
-                (cursor_position.y - self.scroller.bounds.y)
-                    / self.scroller.bounds.height
-            } else {
-                0.5
+    /// Scrolls the minimum amount necessary for the child of the
+    /// [`Scrollable`] at the given index to become visible.
+    ///
+    /// Children are indexed in the order they were pushed. The position is
+    /// resolved the next time the [`Scrollable`] is laid out, and the request
+    /// is ignored if no child exists at that index.
+    ///
+    /// When the [`State`] belongs to a [`List`], the index refers to a row.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`State`]: struct.State.html
+    /// [`List`]: ../list/struct.List.html
+    pub fn scroll_to_child(&mut self, index: usize) {
+        self.request = Some(Request::Child(index));
+        self.animation = None;
+    }
+
+    fn scroll_to_percentage(
+        &mut self,
+        orientation: Orientation,
+        percentage: f32,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        match orientation {
+            Orientation::Vertical => {
+                self.scroll_to(percentage, bounds, content_bounds)
+            }
+            Orientation::Horizontal => {
+                self.scroll_horizontally_to(percentage, bounds, content_bounds)
+            }
+        }
+    }
+
+    /// Returns the current scrolling offset of the [`State`], given the bounds
+    /// of the [`Scrollable`] and its contents.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`State`]: struct.State.html
+    pub fn offset(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Vector<u32> {
+        let offset = match self.request {
+            Some(Request::RelativeOffset(relative_offset)) => {
+                relative_to_absolute(relative_offset, bounds, content_bounds)
+            }
+            _ => self.offset,
+        };
+
+        clamp(offset, bounds, content_bounds)
+    }
+
+    /// Returns the offsets of the rows of a [`List`] cached with the given
+    /// key, computing them again if the key changed or `refresh` is set.
+    ///
+    /// [`List`]: ../list/struct.List.html
+    pub(crate) fn row_offsets(
+        &self,
+        key: u64,
+        refresh: bool,
+        compute: impl FnOnce() -> Vec<f32>,
+    ) -> Arc<Vec<f32>> {
+        let mut row_offsets = self.row_offsets.borrow_mut();
+
+        match &*row_offsets {
+            Some((cached, offsets)) if *cached == key && !refresh => {
+                offsets.clone()
+            }
+            _ => {
+                let offsets = Arc::new(compute());
+                *row_offsets = Some((key, offsets.clone()));
+
+                offsets
+            }
+        }
+    }
+
+    pub(crate) fn requested_offset(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        child_bounds: impl Fn(usize) -> Option<Rectangle>,
+    ) -> Vector<u32> {
+        match self.request {
+            Some(Request::Child(index)) => {
+                let current = self.offset(bounds, content_bounds);
+
+                match child_bounds(index) {
+                    Some(child_bounds) => clamp(
+                        Vector::new(
+                            reveal(
+                                current.x as f32,
+                                bounds.width,
+                                child_bounds.x - content_bounds.x,
+                                child_bounds.width,
+                            ),
+                            reveal(
+                                current.y as f32,
+                                bounds.height,
+                                child_bounds.y - content_bounds.y,
+                                child_bounds.height,
+                            ),
+                        ),
+                        bounds,
+                        content_bounds,
+                    ),
+                    None => current,
+                }
+            }
+            _ => self.offset(bounds, content_bounds),
+        }
+    }
+
+    pub(crate) fn resolve(
+        &mut self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        child_bounds: impl Fn(usize) -> Option<Rectangle>,
+        direction: Direction,
+    ) {
+        if self.request.is_some() {
+            let offset = direction.mask(self.requested_offset(
+                bounds,
+                content_bounds,
+                child_bounds,
+            ));
+
+            self.offset = Vector::new(offset.x as f32, offset.y as f32);
+            self.request = None;
+        }
+    }
+
+    fn settle(&mut self, bounds: Rectangle, content_bounds: Rectangle) {
+        if let Some(Request::RelativeOffset(relative_offset)) = self.request {
+            self.offset =
+                relative_to_absolute(relative_offset, bounds, content_bounds);
+        }
+
+        self.request = None;
+    }
+
+    /// Returns whether a scroller is currently grabbed or not.
+    pub fn is_scroller_grabbed(&self) -> bool {
+        self.scroller_grabbed_at.is_some()
+    }
+
+    /// Returns the [`Orientation`] of the scroller currently grabbed, if any.
+    ///
+    /// [`Orientation`]: enum.Orientation.html
+    pub fn grabbed_scroller(&self) -> Option<Orientation> {
+        self.scroller_grabbed_at.map(|(orientation, _)| orientation)
+    }
+
+    /// Processes an [`Event`] that scrolls the contents of a scrollable
+    /// widget, given its bounds, the bounds of its contents and its
+    /// [`Scrollbars`].
+    ///
+    /// [`Event`]: ../../enum.Event.html
+    /// [`Scrollbars`]: struct.Scrollbars.html
+    pub(crate) fn on_event(
+        &mut self,
+        event: Event,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        cursor_position: Point,
+        scrollbars: &Scrollbars,
+        behavior: Behavior,
+    ) -> event::Status {
+        let is_mouse_over = bounds.contains(cursor_position);
+        let mouse_over_scrollbar = scrollbars.mouse_over(cursor_position);
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            self.animate(now, bounds, content_bounds);
+
+            return event::Status::Ignored;
+        }
+
+        if is_mouse_over {
+            match event {
+                Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                    let (delta, is_precise) = match delta {
+                        mouse::ScrollDelta::Lines { x, y } => {
+                            // TODO: Configurable speed (?)
+                            (Vector::new(x, y) * 60.0, false)
+                        }
+                        mouse::ScrollDelta::Pixels { x, y } => {
+                            (Vector::new(x, y), true)
+                        }
+                    };
+
+                    // Shift + wheel scrolls horizontally, but only where
+                    // horizontal scrolling is possible
+                    let delta = if self.keyboard_modifiers.shift
+                        && delta.x == 0.0
+                        && behavior.direction.is_horizontal()
+                    {
+                        Vector::new(delta.y, 0.0)
+                    } else {
+                        delta
+                    };
+
+                    let delta = Vector::new(
+                        if behavior.direction.is_horizontal() {
+                            delta.x
+                        } else {
+                            0.0
+                        },
+                        if behavior.direction.is_vertical() {
+                            delta.y
+                        } else {
+                            0.0
+                        },
+                    );
+
+                    // Let the event reach any outer scrollable when this
+                    // one cannot move any further
+                    let target = match self.animation {
+                        Some(Animation::Smooth { target, .. }) => target,
+                        _ => self.offset,
+                    };
+
+                    if limit(target - delta, bounds, content_bounds) == target {
+                        return event::Status::Ignored;
+                    }
+
+                    if is_precise && behavior.kinetic {
+                        self.scroll_kinetically(delta, bounds, content_bounds);
+                    } else if !is_precise && behavior.smooth {
+                        self.scroll_smoothly(delta, bounds, content_bounds);
+                    } else {
+                        self.scroll(delta, bounds, content_bounds);
+                    }
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        if let Some((orientation, scroller_grabbed_at)) =
+            self.scroller_grabbed_at
+        {
+            match event {
+                Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Left,
+                )) => {
+                    self.scroller_grabbed_at = None;
+
+                    return event::Status::Captured;
+                }
+                Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                    if let Some(scrollbar) = scrollbars.get(orientation) {
+                        self.scroll_to_percentage(
+                            orientation,
+                            scrollbar.scroll_percentage(
+                                scroller_grabbed_at,
+                                cursor_position,
+                            ),
+                            bounds,
+                            content_bounds,
+                        );
+
+                        return event::Status::Captured;
+                    }
+                }
+                _ => {}
+            }
+        } else if let Some(orientation) = mouse_over_scrollbar {
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(
+                    mouse::Button::Left,
+                )) => {
+                    self.animation = None;
+
+                    if let Some(scrollbar) = scrollbars.get(orientation) {
+                        if let Some(scroller_grabbed_at) =
+                            scrollbar.grab_scroller(cursor_position)
+                        {
+                            self.scroll_to_percentage(
+                                orientation,
+                                scrollbar.scroll_percentage(
+                                    scroller_grabbed_at,
+                                    cursor_position,
+                                ),
+                                bounds,
+                                content_bounds,
+                            );
+
+                            self.scroller_grabbed_at =
+                                Some((orientation, scroller_grabbed_at));
+
+                            return event::Status::Captured;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    /// Returns whether the [`State`] is animating its scroll position.
+    ///
+    /// Renderers should keep requesting new frames while this is true.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn is_animating(&self) -> bool {
+        self.animation.is_some()
+    }
+
+    fn scroll_smoothly(
+        &mut self,
+        delta: Vector,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        self.settle(bounds, content_bounds);
+
+        let target = match self.animation {
+            Some(Animation::Smooth { target, .. }) => target,
+            _ => self.offset,
+        };
+
+        self.animation = Some(Animation::Smooth {
+            target: limit(target - delta, bounds, content_bounds),
+            tick: Instant::now(),
+        });
+    }
+
+    fn scroll_kinetically(
+        &mut self,
+        delta: Vector,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        let now = Instant::now();
+
+        let velocity = match self.animation {
+            Some(Animation::Kinetic {
+                velocity,
+                last_input,
+                ..
+            }) => {
+                let elapsed = duration_between(last_input, now);
+
+                if elapsed > KINETIC_GESTURE_TIMEOUT {
+                    Vector::new(0.0, 0.0)
+                } else if elapsed > 0.001 {
+                    velocity * 0.2 + delta * (0.8 / elapsed)
+                } else {
+                    velocity
+                }
+            }
+            _ => Vector::new(0.0, 0.0),
+        };
+
+        self.scroll(delta, bounds, content_bounds);
+
+        self.animation = Some(Animation::Kinetic {
+            velocity,
+            last_input: now,
+            tick: now,
+        });
+    }
+
+    fn animate(
+        &mut self,
+        now: Instant,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        self.animation = match self.animation {
+            Some(Animation::Smooth { target, tick }) => {
+                let elapsed = duration_between(tick, now);
+                let progress =
+                    1.0 - (-elapsed / SMOOTH_SCROLLING_TIME_CONSTANT).exp();
+
+                let offset = self.offset + (target - self.offset) * progress;
+
+                if (target.x - offset.x).abs() < 0.5
+                    && (target.y - offset.y).abs() < 0.5
+                {
+                    self.offset = target;
+
+                    None
+                } else {
+                    self.offset = offset;
+
+                    Some(Animation::Smooth { target, tick: now })
+                }
+            }
+            Some(Animation::Kinetic {
+                velocity,
+                last_input,
+                tick,
+            }) => {
+                if duration_between(last_input, now) < KINETIC_GESTURE_TIMEOUT {
+                    // The gesture is still going on
+                    Some(Animation::Kinetic {
+                        velocity,
+                        last_input,
+                        tick: now,
+                    })
+                } else {
+                    let elapsed = duration_between(tick, now);
+                    let previous = self.offset;
+
+                    self.offset = limit(
+                        self.offset - velocity * elapsed,
+                        bounds,
+                        content_bounds,
+                    );
+
+                    let velocity =
+                        velocity * (-elapsed * KINETIC_FRICTION).exp();
+                    let speed = velocity.x.hypot(velocity.y);
+
+                    if speed < KINETIC_MIN_SPEED
+                        || (elapsed > 0.0 && self.offset == previous)
+                    {
+                        None
+                    } else {
+                        Some(Animation::Kinetic {
+                            velocity,
+                            last_input,
+                            tick: now,
+                        })
+                    }
+                }
+            }
+            None => None,
+        };
+    }
+
+    pub(crate) fn track_modifiers(&mut self, event: keyboard::Event) {
+        match event {
+            keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::LShift,
+                ..
+            }
+            | keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::RShift,
+                ..
+            } => {
+                self.keyboard_modifiers.shift = true;
+            }
+            keyboard::Event::KeyReleased {
+                key_code: keyboard::KeyCode::LShift,
+                ..
+            }
+            | keyboard::Event::KeyReleased {
+                key_code: keyboard::KeyCode::RShift,
+                ..
+            } => {
+                self.keyboard_modifiers.shift = false;
+            }
+            keyboard::Event::KeyPressed { modifiers, .. }
+            | keyboard::Event::KeyReleased { modifiers, .. }
+            | keyboard::Event::ModifiersChanged(modifiers) => {
+                self.keyboard_modifiers = modifiers;
+            }
+            keyboard::Event::CharacterReceived(_) => {}
+        }
+    }
+}
+
+fn clamp(
+    offset: Vector,
+    bounds: Rectangle,
+    content_bounds: Rectangle,
+) -> Vector<u32> {
+    let hidden_width =
+        (content_bounds.width - bounds.width).max(0.0).round() as u32;
+
+    let hidden_height =
+        (content_bounds.height - bounds.height).max(0.0).round() as u32;
+
+    Vector::new(
+        offset.x.max(0.0).min(hidden_width as f32) as u32,
+        offset.y.max(0.0).min(hidden_height as f32) as u32,
+    )
+}
+
+fn limit(
+    offset: Vector,
+    bounds: Rectangle,
+    content_bounds: Rectangle,
+) -> Vector {
+    Vector::new(
+        offset
+            .x
+            .max(0.0)
+            .min((content_bounds.width - bounds.width).max(0.0)),
+        offset
+            .y
+            .max(0.0)
+            .min((content_bounds.height - bounds.height).max(0.0)),
+    )
+}
+
+fn duration_between(start: Instant, end: Instant) -> f32 {
+    if end > start {
+        end.duration_since(start).as_secs_f32()
+    } else {
+        0.0
+    }
+}
+
+fn relative_to_absolute(
+    relative_offset: Vector,
+    bounds: Rectangle,
+    content_bounds: Rectangle,
+) -> Vector {
+    Vector::new(
+        (content_bounds.width - bounds.width).max(0.0) * relative_offset.x,
+        (content_bounds.height - bounds.height).max(0.0) * relative_offset.y,
+    )
+}
+
+/// Returns the offset, along a single axis, that makes the given span of the
+/// contents visible while moving as little as possible from `offset`.
+fn reveal(offset: f32, visible: f32, start: f32, length: f32) -> f32 {
+    if start < offset || length > visible {
+        start
+    } else if start + length > offset + visible {
+        start + length - visible
+    } else {
+        offset
+    }
+}
+
+/// The scrollbars of a [`Scrollable`].
+///
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Scrollbars {
+    /// The vertical [`Scrollbar`], if the content overflows vertically.
+    ///
+    /// [`Scrollbar`]: struct.Scrollbar.html
+    pub vertical: Option<Scrollbar>,
+
+    /// The horizontal [`Scrollbar`], if the content overflows horizontally.
+    ///
+    /// [`Scrollbar`]: struct.Scrollbar.html
+    pub horizontal: Option<Scrollbar>,
+}
+
+impl Scrollbars {
+    fn get(&self, orientation: Orientation) -> Option<Scrollbar> {
+        match orientation {
+            Orientation::Vertical => self.vertical,
+            Orientation::Horizontal => self.horizontal,
+        }
+    }
+
+    pub(crate) fn mouse_over(
+        &self,
+        cursor_position: Point,
+    ) -> Option<Orientation> {
+        self.vertical
+            .iter()
+            .chain(self.horizontal.iter())
+            .find(|scrollbar| scrollbar.is_mouse_over(cursor_position))
+            .map(|scrollbar| scrollbar.orientation)
+    }
+}
+
+/// A scrollbar of a [`Scrollable`].
+///
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug, Clone, Copy)]
+pub struct Scrollbar {
+    /// The [`Orientation`] of the [`Scrollbar`].
+    ///
+    /// [`Orientation`]: enum.Orientation.html
+    /// [`Scrollbar`]: struct.Scrollbar.html
+    pub orientation: Orientation,
+
+    /// The bounds of the [`Scrollbar`].
+    ///
+    /// [`Scrollbar`]: struct.Scrollbar.html
+    pub bounds: Rectangle,
+
+    /// The bounds of the [`Scroller`].
+    ///
+    /// [`Scroller`]: struct.Scroller.html
+    pub scroller: Scroller,
+}
+
+impl Scrollbar {
+    fn is_mouse_over(&self, cursor_position: Point) -> bool {
+        self.bounds.contains(cursor_position)
+    }
+
+    fn grab_scroller(&self, cursor_position: Point) -> Option<f32> {
+        if self.bounds.contains(cursor_position) {
+            let scroller = self.scroller.bounds;
+
+            Some(if scroller.contains(cursor_position) {
+                match self.orientation {
+                    Orientation::Vertical => {
+                        (cursor_position.y - scroller.y) / scroller.height
+                    }
+                    Orientation::Horizontal => {
+                        (cursor_position.x - scroller.x) / scroller.width
+                    }
+                }
+            } else {
+                0.5
             })
         } else {
             None
@@ -429,10 +1324,20 @@ impl Scrollbar {
         grabbed_at: f32,
         cursor_position: Point,
     ) -> f32 {
-        (cursor_position.y
-            - self.bounds.y
-            - self.scroller.bounds.height * grabbed_at)
-            / (self.bounds.height - self.scroller.bounds.height)
+        match self.orientation {
+            Orientation::Vertical => {
+                (cursor_position.y
+                    - self.bounds.y
+                    - self.scroller.bounds.height * grabbed_at)
+                    / (self.bounds.height - self.scroller.bounds.height)
+            }
+            Orientation::Horizontal => {
+                (cursor_position.x
+                    - self.bounds.x
+                    - self.scroller.bounds.width * grabbed_at)
+                    / (self.bounds.width - self.scroller.bounds.width)
+            }
+        }
     }
 }
 
@@ -458,17 +1363,20 @@ pub trait Renderer: crate::Renderer + Sized {
     /// The style supported by this renderer.
     type Style: Default;
 
-    /// Returns the [`Scrollbar`] given the bounds and content bounds of a
-    /// [`Scrollable`].
+    /// Returns the [`Scrollbars`] given the bounds and content bounds of a
+    /// [`Scrollable`], its scrolling offset and the [`Direction`] in which
+    /// it can be scrolled.
     ///
-    /// [`Scrollbar`]: struct.Scrollbar.html
+    /// [`Scrollbars`]: struct.Scrollbars.html
     /// [`Scrollable`]: struct.Scrollable.html
-    fn scrollbar(
+    /// [`Direction`]: enum.Direction.html
+    fn scrollbars(
         &self,
         bounds: Rectangle,
         content_bounds: Rectangle,
-        offset: u32,
-    ) -> Option<Scrollbar>;
+        offset: Vector<u32>,
+        direction: Direction,
+    ) -> Scrollbars;
 
     /// Draws the [`Scrollable`].
     ///
@@ -477,12 +1385,14 @@ pub trait Renderer: crate::Renderer + Sized {
     /// - the bounds of the [`Scrollable`] widget
     /// - the bounds of the [`Scrollable`] content
     /// - whether the mouse is over the [`Scrollable`] or not
-    /// - whether the mouse is over the [`Scrollbar`] or not
-    /// - a optional [`Scrollbar`] to be rendered
+    /// - the [`Orientation`] of the [`Scrollbar`] under the mouse, if any
+    /// - the [`Scrollbars`] to be rendered
     /// - the scrolling offset
     /// - the drawn content
     ///
+    /// [`Orientation`]: enum.Orientation.html
     /// [`Scrollbar`]: struct.Scrollbar.html
+    /// [`Scrollbars`]: struct.Scrollbars.html
     /// [`Scrollable`]: struct.Scrollable.html
     /// [`State`]: struct.State.html
     fn draw(
@@ -491,9 +1401,9 @@ pub trait Renderer: crate::Renderer + Sized {
         bounds: Rectangle,
         content_bounds: Rectangle,
         is_mouse_over: bool,
-        is_mouse_over_scrollbar: bool,
-        scrollbar: Option<Scrollbar>,
-        offset: u32,
+        mouse_over_scrollbar: Option<Orientation>,
+        scrollbars: Scrollbars,
+        offset: Vector<u32>,
         style: &Self::Style,
         content: Self::Output,
     ) -> Self::Output;
@@ -511,3 +1421,139 @@ where
         Element::new(scrollable)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 100.0,
+    };
+
+    const CONTENT_BOUNDS: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 300.0,
+        height: 500.0,
+    };
+
+    #[test]
+    fn relative_offset() {
+        let viewport = |x, y| Viewport {
+            offset: Vector::new(x, y),
+            size: BOUNDS.size(),
+            content_size: CONTENT_BOUNDS.size(),
+        };
+
+        assert_eq!(viewport(0.0, 0.0).relative_offset(), Vector::new(0.0, 0.0));
+        assert_eq!(
+            viewport(100.0, 200.0).relative_offset(),
+            Vector::new(0.5, 0.5)
+        );
+        assert_eq!(
+            viewport(200.0, 400.0).relative_offset(),
+            Vector::new(1.0, 1.0)
+        );
+        assert_eq!(viewport(0.0, 400.0).distance_to_bottom(), 0.0);
+        assert_eq!(viewport(0.0, 100.0).distance_to_bottom(), 300.0);
+    }
+
+    #[test]
+    fn relative_offset_of_small_contents() {
+        let viewport = Viewport {
+            offset: Vector::new(0.0, 0.0),
+            size: BOUNDS.size(),
+            content_size: Size::new(50.0, 50.0),
+        };
+
+        assert_eq!(viewport.relative_offset(), Vector::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn snap_to() {
+        let mut state = State::new();
+
+        state.snap_to(Vector::new(0.5, 1.0));
+
+        // The offset is known as soon as the bounds are
+        assert_eq!(state.offset(BOUNDS, CONTENT_BOUNDS), Vector::new(100, 400));
+
+        state.resolve(BOUNDS, CONTENT_BOUNDS, |_| None, Direction::Vertical);
+
+        // Only the scrollable directions are resolved
+        assert_eq!(state.offset(BOUNDS, CONTENT_BOUNDS), Vector::new(0, 400));
+    }
+
+    #[test]
+    fn snap_to_is_clamped() {
+        let mut state = State::new();
+
+        state.snap_to(Vector::new(2.0, -1.0));
+        state.resolve(BOUNDS, CONTENT_BOUNDS, |_| None, Direction::Both);
+
+        assert_eq!(state.offset(BOUNDS, CONTENT_BOUNDS), Vector::new(200, 0));
+    }
+
+    fn scroll(
+        state: &mut State,
+        delta: Vector,
+        direction: Direction,
+    ) -> event::Status {
+        state.on_event(
+            Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Pixels {
+                    x: delta.x,
+                    y: delta.y,
+                },
+            }),
+            BOUNDS,
+            CONTENT_BOUNDS,
+            Point::new(50.0, 50.0),
+            &Scrollbars {
+                vertical: None,
+                horizontal: None,
+            },
+            Behavior {
+                direction,
+                smooth: false,
+                kinetic: false,
+            },
+        )
+    }
+
+    #[test]
+    fn shift_wheel_scrolls_horizontally_when_possible() {
+        let mut state = State::new();
+        state.keyboard_modifiers.shift = true;
+
+        assert_eq!(
+            scroll(&mut state, Vector::new(0.0, -10.0), Direction::Vertical),
+            event::Status::Captured
+        );
+        assert_eq!(state.offset(BOUNDS, CONTENT_BOUNDS), Vector::new(0, 10));
+
+        assert_eq!(
+            scroll(&mut state, Vector::new(0.0, -10.0), Direction::Horizontal),
+            event::Status::Captured
+        );
+        assert_eq!(state.offset(BOUNDS, CONTENT_BOUNDS), Vector::new(10, 10));
+    }
+
+    #[test]
+    fn scrolling_past_the_edge_is_ignored() {
+        let mut state = State::new();
+
+        assert_eq!(
+            scroll(&mut state, Vector::new(0.0, 10.0), Direction::Vertical),
+            event::Status::Ignored
+        );
+        assert_eq!(
+            scroll(&mut state, Vector::new(0.0, -10.0), Direction::Vertical),
+            event::Status::Captured
+        );
+        assert_eq!(state.offset(BOUNDS, CONTENT_BOUNDS), Vector::new(0, 10));
+    }
+}
@@ -1,5 +1,9 @@
 //! Display vector graphics in your application.
-use crate::{layout, Element, Hasher, Layout, Length, Point, Size, Widget};
+use crate::image;
+use crate::{
+    layout, Color, ContentFit, Element, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
 
 use std::{
     hash::{Hash, Hasher as _},
@@ -20,6 +24,8 @@ pub struct Svg {
     handle: Handle,
     width: Length,
     height: Length,
+    content_fit: ContentFit,
+    color: Option<Color>,
 }
 
 impl Svg {
@@ -32,6 +38,8 @@ impl Svg {
             handle: handle.into(),
             width: Length::Fill,
             height: Length::Shrink,
+            content_fit: ContentFit::default(),
+            color: None,
         }
     }
 
@@ -58,6 +66,31 @@ impl Svg {
         self.height = height;
         self
     }
+
+    /// Sets the [`ContentFit`] of the [`Svg`].
+    ///
+    /// Defaults to [`ContentFit::Contain`].
+    ///
+    /// [`ContentFit`]: ../../enum.ContentFit.html
+    /// [`ContentFit::Contain`]: ../../enum.ContentFit.html#variant.Contain
+    /// [`Svg`]: struct.Svg.html
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the [`Color`] of the [`Svg`], replacing the colors of its
+    /// contents while keeping their opacity.
+    ///
+    /// This is useful to make monochrome icons follow the colors of your
+    /// theme.
+    ///
+    /// [`Color`]: ../../struct.Color.html
+    /// [`Svg`]: struct.Svg.html
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Svg
@@ -79,22 +112,13 @@ where
     ) -> layout::Node {
         let (width, height) = renderer.dimensions(&self.handle);
 
-        let aspect_ratio = width as f32 / height as f32;
-
-        let mut size = limits
-            .width(self.width)
-            .height(self.height)
-            .resolve(Size::new(width as f32, height as f32));
-
-        let viewport_aspect_ratio = size.width / size.height;
-
-        if viewport_aspect_ratio > aspect_ratio {
-            size.width = width as f32 * size.height / height as f32;
-        } else {
-            size.height = height as f32 * size.width / width as f32;
-        }
-
-        layout::Node::new(size)
+        image::layout(
+            limits,
+            Size::new(width as f32, height as f32),
+            self.width,
+            self.height,
+            self.content_fit,
+        )
     }
 
     fn draw(
@@ -104,7 +128,15 @@ where
         layout: Layout<'_>,
         _cursor_position: Point,
     ) -> Renderer::Output {
-        renderer.draw(self.handle.clone(), layout)
+        let (width, height) = renderer.dimensions(&self.handle);
+
+        let image_bounds = image::fit(
+            Size::new(width as f32, height as f32),
+            layout.bounds(),
+            self.content_fit,
+        );
+
+        renderer.draw(self.handle.clone(), self.color, layout, image_bounds)
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
@@ -113,6 +145,7 @@ where
         self.handle.hash(state);
         self.width.hash(state);
         self.height.hash(state);
+        self.content_fit.hash(state);
     }
 }
 
@@ -215,8 +248,22 @@ pub trait Renderer: crate::Renderer {
 
     /// Draws an [`Svg`].
     ///
+    /// It receives the [`Handle`], the [`Color`] overriding its contents, if
+    /// any, and the [`Layout`] of the [`Svg`], and the bounds where the image
+    /// should be drawn, which may overflow the [`Layout`] and should be
+    /// clipped to it.
+    ///
     /// [`Svg`]: struct.Svg.html
-    fn draw(&mut self, handle: Handle, layout: Layout<'_>) -> Self::Output;
+    /// [`Handle`]: struct.Handle.html
+    /// [`Color`]: ../../struct.Color.html
+    /// [`Layout`]: ../../layout/struct.Layout.html
+    fn draw(
+        &mut self,
+        handle: Handle,
+        color: Option<Color>,
+        layout: Layout<'_>,
+        image_bounds: Rectangle,
+    ) -> Self::Output;
 }
 
 impl<'a, Message, Renderer> From<Svg> for Element<'a, Message, Renderer>
@@ -1,7 +1,8 @@
 //! Write some text for your users to read.
 use crate::{
-    layout, Color, Element, Hasher, HorizontalAlignment, Layout, Length, Point,
-    Rectangle, Size, VerticalAlignment, Widget,
+    layout, Color, Element, Hasher, HorizontalAlignment, Layout, Length,
+    LineHeight, Overflow, Point, Rectangle, Size, VerticalAlignment, Widget,
+    Wrapping,
 };
 
 use std::hash::Hash;
@@ -18,6 +19,33 @@ use std::hash::Hash;
 ///     .size(40);
 /// ```
 ///
+/// Text that does not fit in its bounds can be kept in a single line and
+/// truncated with an ellipsis:
+///
+/// ```
+/// # use iced_native::{Overflow, Wrapping};
+/// # type Text = iced_native::Text<iced_native::renderer::Null>;
+/// #
+/// Text::new("A very long title that may not fit")
+///     .wrapping(Wrapping::None)
+///     .overflow(Overflow::Ellipsis);
+/// ```
+///
+/// There is no limit on the number of lines of text. Instead, a
+/// fixed line height and a height fitting the lines to show will clamp
+/// its contents, ending the last line with an ellipsis:
+///
+/// ```
+/// # use iced_native::{Length, LineHeight, Overflow};
+/// # type Text = iced_native::Text<iced_native::renderer::Null>;
+/// #
+/// // At most 3 lines of 20 pixels each
+/// Text::new("A long description that may span many lines")
+///     .line_height(LineHeight::Absolute(20.0))
+///     .height(Length::Units(3 * 20))
+///     .overflow(Overflow::Ellipsis);
+/// ```
+///
 /// ![Text drawn by `iced_wgpu`](https://github.com/hecrj/iced/blob/7760618fb112074bc40b148944521f312152012a/docs/images/text.png?raw=true)
 #[derive(Debug)]
 pub struct Text<Renderer: self::Renderer> {
@@ -29,6 +57,9 @@ pub struct Text<Renderer: self::Renderer> {
     height: Length,
     horizontal_alignment: HorizontalAlignment,
     vertical_alignment: VerticalAlignment,
+    line_height: LineHeight,
+    wrapping: Wrapping,
+    overflow: Overflow,
 }
 
 impl<Renderer: self::Renderer> Text<Renderer> {
@@ -45,6 +76,9 @@ impl<Renderer: self::Renderer> Text<Renderer> {
             height: Length::Shrink,
             horizontal_alignment: HorizontalAlignment::Left,
             vertical_alignment: VerticalAlignment::Top,
+            line_height: LineHeight::default(),
+            wrapping: Wrapping::default(),
+            overflow: Overflow::default(),
         }
     }
 
@@ -110,6 +144,35 @@ impl<Renderer: self::Renderer> Text<Renderer> {
         self.vertical_alignment = alignment;
         self
     }
+
+    /// Sets the [`LineHeight`] of the [`Text`].
+    ///
+    /// A plain number sets a line height relative to the size of the text.
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`LineHeight`]: ../../enum.LineHeight.html
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the [`Wrapping`] strategy of the [`Text`].
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`Wrapping`]: ../../enum.Wrapping.html
+    pub fn wrapping(mut self, wrapping: Wrapping) -> Self {
+        self.wrapping = wrapping;
+        self
+    }
+
+    /// Sets the [`Overflow`] strategy of the [`Text`].
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`Overflow`]: ../../enum.Overflow.html
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Text<Renderer>
@@ -135,8 +198,14 @@ where
 
         let bounds = limits.max();
 
-        let (width, height) =
-            renderer.measure(&self.content, size, self.font, bounds);
+        let (width, height) = renderer.measure(
+            &self.content,
+            size,
+            self.font.clone(),
+            self.line_height,
+            self.wrapping,
+            bounds,
+        );
 
         let size = limits.resolve(Size::new(width, height));
 
@@ -155,10 +224,13 @@ where
             layout.bounds(),
             &self.content,
             self.size.unwrap_or(Renderer::DEFAULT_SIZE),
-            self.font,
+            self.font.clone(),
             self.color,
             self.horizontal_alignment,
             self.vertical_alignment,
+            self.line_height,
+            self.wrapping,
+            self.overflow,
         )
     }
 
@@ -170,6 +242,15 @@ where
         self.size.hash(state);
         self.width.hash(state);
         self.height.hash(state);
+        self.wrapping.hash(state);
+
+        std::mem::discriminant(&self.line_height).hash(state);
+
+        match self.line_height {
+            LineHeight::Natural => {}
+            LineHeight::Relative(factor) => factor.to_bits().hash(state),
+            LineHeight::Absolute(height) => height.to_bits().hash(state),
+        }
     }
 }
 
@@ -185,7 +266,7 @@ pub trait Renderer: crate::Renderer {
     /// The font type used for [`Text`].
     ///
     /// [`Text`]: struct.Text.html
-    type Font: Default + Copy;
+    type Font: Default + Clone;
 
     /// The default size of [`Text`].
     ///
@@ -195,12 +276,19 @@ pub trait Renderer: crate::Renderer {
     /// Measures the [`Text`] in the given bounds and returns the minimum
     /// boundaries that can fit the contents.
     ///
+    /// The lines of the [`Text`] are spaced with the given [`LineHeight`]
+    /// and broken following the given [`Wrapping`] strategy.
+    ///
     /// [`Text`]: struct.Text.html
+    /// [`LineHeight`]: ../../enum.LineHeight.html
+    /// [`Wrapping`]: ../../enum.Wrapping.html
     fn measure(
         &self,
         content: &str,
         size: u16,
         font: Self::Font,
+        line_height: LineHeight,
+        wrapping: Wrapping,
         bounds: Size,
     ) -> (f32, f32);
 
@@ -213,10 +301,16 @@ pub trait Renderer: crate::Renderer {
     ///   * the color of the [`Text`]
     ///   * the [`HorizontalAlignment`] of the [`Text`]
     ///   * the [`VerticalAlignment`] of the [`Text`]
+    ///   * the [`LineHeight`] of the [`Text`]
+    ///   * the [`Wrapping`] strategy of the [`Text`]
+    ///   * the [`Overflow`] strategy of the [`Text`]
     ///
     /// [`Text`]: struct.Text.html
     /// [`HorizontalAlignment`]: enum.HorizontalAlignment.html
     /// [`VerticalAlignment`]: enum.VerticalAlignment.html
+    /// [`LineHeight`]: ../../enum.LineHeight.html
+    /// [`Wrapping`]: ../../enum.Wrapping.html
+    /// [`Overflow`]: ../../enum.Overflow.html
     fn draw(
         &mut self,
         defaults: &Self::Defaults,
@@ -227,6 +321,9 @@ pub trait Renderer: crate::Renderer {
         color: Option<Color>,
         horizontal_alignment: HorizontalAlignment,
         vertical_alignment: VerticalAlignment,
+        line_height: LineHeight,
+        wrapping: Wrapping,
+        overflow: Overflow,
     ) -> Self::Output;
 }
 
@@ -246,11 +343,43 @@ impl<Renderer: self::Renderer> Clone for Text<Renderer> {
             content: self.content.clone(),
             size: self.size,
             color: self.color,
-            font: self.font,
+            font: self.font.clone(),
             width: self.width,
             height: self.height,
             horizontal_alignment: self.horizontal_alignment,
             vertical_alignment: self.vertical_alignment,
+            line_height: self.line_height,
+            wrapping: self.wrapping,
+            overflow: self.overflow,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+
+    use std::hash::Hasher as _;
+
+    fn layout_hash(line_height: LineHeight) -> u64 {
+        let text = Text::<Null>::new("Hello").line_height(line_height);
+        let mut hasher = Hasher::default();
+
+        Widget::<(), Null>::hash_layout(&text, &mut hasher);
+
+        hasher.finish()
+    }
+
+    #[test]
+    fn line_heights_hash_their_kind() {
+        assert_ne!(
+            layout_hash(LineHeight::Relative(1.5)),
+            layout_hash(LineHeight::Absolute(1.5))
+        );
+        assert_eq!(
+            layout_hash(LineHeight::Relative(1.5)),
+            layout_hash(LineHeight::Relative(1.5))
+        );
+    }
+}
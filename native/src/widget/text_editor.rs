@@ -0,0 +1,1239 @@
+//! Display fields that can be filled with multiple lines of text.
+//!
+//! A [`TextEditor`] has some local [`State`].
+//!
+//! [`TextEditor`]: struct.TextEditor.html
+//! [`State`]: struct.State.html
+use crate::text_input::{platform, Cursor, Editor, Value};
+use crate::{
+    event, keyboard, layout,
+    mouse::{self, click},
+    Clipboard, Element, Event, Hasher, Layout, Length, Point, Rectangle, Size,
+    Widget,
+};
+
+use std::cell::RefCell;
+use std::u32;
+
+/// A field that can be filled with multiple lines of text.
+///
+/// Long lines are wrapped to fit the width of the [`TextEditor`], and its
+/// contents are scrolled vertically to keep the cursor visible.
+///
+/// # Example
+/// ```
+/// # use iced_native::{text_editor, renderer::Null, Length};
+/// #
+/// # pub type TextEditor<'a, Message> = iced_native::TextEditor<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     NotesChanged(String),
+/// }
+///
+/// let mut state = text_editor::State::new();
+/// let notes = "Some notes\nspanning multiple lines";
+///
+/// let editor = TextEditor::new(
+///     &mut state,
+///     "Write your notes here...",
+///     notes,
+///     Message::NotesChanged,
+/// )
+/// .height(Length::Units(200))
+/// .padding(10);
+/// ```
+///
+/// [`TextEditor`]: struct.TextEditor.html
+#[allow(missing_debug_implementations)]
+pub struct TextEditor<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    placeholder: String,
+    value: Value,
+    font: Renderer::Font,
+    width: Length,
+    height: Length,
+    max_width: u32,
+    padding: u16,
+    size: Option<u16>,
+    on_change: Box<dyn Fn(String) -> Message>,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer> TextEditor<'a, Message, Renderer> {
+    /// Creates a new [`TextEditor`].
+    ///
+    /// It expects:
+    /// - some [`State`]
+    /// - a placeholder
+    /// - the current value
+    /// - a function that produces a message when the [`TextEditor`] changes
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(
+        state: &'a mut State,
+        placeholder: &str,
+        value: &str,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(String) -> Message,
+    {
+        TextEditor {
+            state,
+            placeholder: String::from(placeholder),
+            value: Value::new(value),
+            font: Default::default(),
+            width: Length::Fill,
+            height: Length::Shrink,
+            max_width: u32::MAX,
+            padding: 0,
+            size: None,
+            on_change: Box::new(on_change),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the [`Font`] of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    /// [`Font`]: ../../struct.Font.html
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the width of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`TextEditor`].
+    ///
+    /// By default, it grows with its contents. Its contents are scrolled when
+    /// they do not fit the height.
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the maximum width of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the padding of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn padding(mut self, units: u16) -> Self {
+        self.padding = units;
+        self
+    }
+
+    /// Sets the text size of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the style of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the wrapped [`Line`]s of the value, reusing the ones in the
+    /// [`State`] if the value, the width and the text size did not change.
+    ///
+    /// [`Line`]: struct.Line.html
+    /// [`State`]: struct.State.html
+    fn lines(&self, renderer: &Renderer, width: f32) -> Vec<Line> {
+        let size = self.size.unwrap_or(renderer.default_size());
+        let mut wrapped = self.state.wrapped.borrow_mut();
+
+        match &*wrapped {
+            Some(wrapped)
+                if wrapped.width == width
+                    && wrapped.size == size
+                    && wrapped.value == self.value =>
+            {
+                wrapped.lines.clone()
+            }
+            _ => {
+                let lines =
+                    renderer.wrap(&self.value, self.font.clone(), size, width);
+
+                *wrapped = Some(Wrapped {
+                    value: self.value.clone(),
+                    width,
+                    size,
+                    lines: lines.clone(),
+                });
+
+                lines
+            }
+        }
+    }
+
+    fn find_cursor_position(
+        &self,
+        renderer: &Renderer,
+        text_bounds: Rectangle,
+        lines: &[Line],
+        position: Point,
+    ) -> usize {
+        let size = self.size.unwrap_or(renderer.default_size());
+        let y = position.y - text_bounds.y + self.state.offset;
+
+        let index = if y < 0.0 {
+            0
+        } else {
+            ((y / f32::from(size)) as usize).min(lines.len() - 1)
+        };
+
+        find_column(
+            renderer,
+            &self.value,
+            lines,
+            index,
+            self.font.clone(),
+            size,
+            position.x - text_bounds.x,
+        )
+    }
+
+    fn move_vertically(
+        &mut self,
+        renderer: &Renderer,
+        text_bounds: Rectangle,
+        lines: &[Line],
+        amount: isize,
+        select: bool,
+    ) {
+        let size = self.size.unwrap_or(renderer.default_size());
+        let position = self.state.cursor.end(&self.value);
+        let current = find_line(lines, position);
+
+        let x = match self.state.preferred_x {
+            Some(x) => x,
+            None => renderer.measure_value(
+                &self
+                    .value
+                    .select(lines[current].start, position)
+                    .to_string(),
+                size,
+                self.font.clone(),
+            ),
+        };
+
+        let target = (current as isize + amount)
+            .max(0)
+            .min(lines.len() as isize - 1) as usize;
+
+        let position = if target == current && amount < 0 {
+            0
+        } else if target == current && amount > 0 {
+            self.value.len()
+        } else {
+            find_column(
+                renderer,
+                &self.value,
+                lines,
+                target,
+                self.font.clone(),
+                size,
+                x,
+            )
+        };
+
+        if select {
+            self.state
+                .cursor
+                .select_range(self.state.cursor.start(&self.value), position);
+        } else {
+            self.state.cursor.move_to(position);
+        }
+
+        self.state.preferred_x = Some(x);
+
+        self.scroll_to_cursor(renderer, text_bounds, lines);
+    }
+
+    fn scroll_to_cursor(
+        &mut self,
+        renderer: &Renderer,
+        text_bounds: Rectangle,
+        lines: &[Line],
+    ) {
+        let size = f32::from(self.size.unwrap_or(renderer.default_size()));
+        let line = find_line(lines, self.state.cursor.end(&self.value));
+
+        let top = line as f32 * size;
+        let bottom = top + size;
+
+        if top < self.state.offset {
+            self.state.offset = top;
+        } else if bottom > self.state.offset + text_bounds.height {
+            self.state.offset = bottom - text_bounds.height;
+        }
+
+        self.state
+            .clamp_offset(text_bounds, lines.len() as f32 * size);
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for TextEditor<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let padding = self.padding as f32;
+        let text_size = self.size.unwrap_or(renderer.default_size());
+
+        let limits = limits
+            .pad(padding)
+            .width(self.width)
+            .max_width(self.max_width)
+            .height(self.height)
+            .min_height(u32::from(text_size));
+
+        let lines = self.lines(renderer, limits.max().width);
+
+        let mut text = layout::Node::new(limits.resolve(Size::new(
+            0.0,
+            lines.len() as f32 * f32::from(text_size),
+        )));
+        text.move_to(Point::new(padding, padding));
+
+        layout::Node::with_children(text.size().pad(padding), vec![text])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        let text_bounds = layout.children().next().unwrap().bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let is_clicked = layout.bounds().contains(cursor_position);
+
+                if is_clicked {
+                    let lines = self.lines(renderer, text_bounds.width);
+
+                    let position = self.find_cursor_position(
+                        renderer,
+                        text_bounds,
+                        &lines,
+                        cursor_position,
+                    );
+
+                    let click = mouse::Click::new(
+                        cursor_position,
+                        self.state.last_click,
+                    );
+
+                    match click.kind() {
+                        click::Kind::Single => {
+                            self.state.cursor.move_to(position);
+                        }
+                        click::Kind::Double => {
+                            self.state.cursor.select_range(
+                                self.value.previous_start_of_word(position),
+                                self.value.next_end_of_word(position),
+                            );
+                        }
+                        click::Kind::Triple => {
+                            let line = &lines[find_line(&lines, position)];
+
+                            self.state
+                                .cursor
+                                .select_range(line.start, line.end);
+                        }
+                    }
+
+                    self.state.last_click = Some(click);
+                    self.state.preferred_x = None;
+                }
+
+                self.state.is_dragging = is_clicked;
+                self.state.is_focused = is_clicked;
+
+                if is_clicked {
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                self.state.is_dragging = false;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { x, y }) => {
+                if self.state.is_dragging {
+                    let lines = self.lines(renderer, text_bounds.width);
+
+                    let position = self.find_cursor_position(
+                        renderer,
+                        text_bounds,
+                        &lines,
+                        Point::new(x, y),
+                    );
+
+                    self.state.cursor.select_range(
+                        self.state.cursor.start(&self.value),
+                        position,
+                    );
+
+                    self.scroll_to_cursor(renderer, text_bounds, &lines);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if layout.bounds().contains(cursor_position) =>
+            {
+                let size =
+                    f32::from(self.size.unwrap_or(renderer.default_size()));
+
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * size * 3.0,
+                    mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+
+                let lines = self.lines(renderer, text_bounds.width);
+                let offset = self.state.offset;
+
+                self.state.offset -= delta;
+                self.state
+                    .clamp_offset(text_bounds, lines.len() as f32 * size);
+
+                if self.state.offset != offset {
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                if self.state.is_focused
+                    && self.state.is_pasting.is_none()
+                    && !c.is_control() =>
+            {
+                let mut editor =
+                    Editor::new(&mut self.value, &mut self.state.cursor);
+
+                let _ = editor.insert(c);
+
+                let message = (self.on_change)(editor.contents());
+                messages.push(message);
+
+                let lines = self.lines(renderer, text_bounds.width);
+
+                self.state.preferred_x = None;
+                self.scroll_to_cursor(renderer, text_bounds, &lines);
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if self.state.is_focused => {
+                let lines = self.lines(renderer, text_bounds.width);
+
+                match key_code {
+                    keyboard::KeyCode::Up => {
+                        self.move_vertically(
+                            renderer,
+                            text_bounds,
+                            &lines,
+                            -1,
+                            modifiers.shift,
+                        );
+
+                        return event::Status::Captured;
+                    }
+                    keyboard::KeyCode::Down => {
+                        self.move_vertically(
+                            renderer,
+                            text_bounds,
+                            &lines,
+                            1,
+                            modifiers.shift,
+                        );
+
+                        return event::Status::Captured;
+                    }
+                    keyboard::KeyCode::PageUp | keyboard::KeyCode::PageDown => {
+                        let size = f32::from(
+                            self.size.unwrap_or(renderer.default_size()),
+                        );
+
+                        let page =
+                            ((text_bounds.height / size) as isize).max(1);
+
+                        let amount = if key_code == keyboard::KeyCode::PageUp {
+                            -page
+                        } else {
+                            page
+                        };
+
+                        self.state.offset += amount as f32 * size;
+
+                        self.move_vertically(
+                            renderer,
+                            text_bounds,
+                            &lines,
+                            amount,
+                            modifiers.shift,
+                        );
+
+                        return event::Status::Captured;
+                    }
+                    keyboard::KeyCode::Tab => {
+                        return event::Status::Ignored;
+                    }
+                    _ => {}
+                }
+
+                self.state.preferred_x = None;
+
+                match key_code {
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter => {
+                        let mut editor = Editor::new(
+                            &mut self.value,
+                            &mut self.state.cursor,
+                        );
+
+                        let _ = editor.insert('\n');
+
+                        let message = (self.on_change)(editor.contents());
+                        messages.push(message);
+                    }
+                    keyboard::KeyCode::Backspace => {
+                        if platform::is_jump_modifier_pressed(modifiers)
+                            && self.state.cursor.selection().is_none()
+                        {
+                            self.state.cursor.select_left_by_words(&self.value);
+                        }
+
+                        let mut editor = Editor::new(
+                            &mut self.value,
+                            &mut self.state.cursor,
+                        );
+
+                        editor.backspace();
+
+                        let message = (self.on_change)(editor.contents());
+                        messages.push(message);
+                    }
+                    keyboard::KeyCode::Delete => {
+                        if platform::is_jump_modifier_pressed(modifiers)
+                            && self.state.cursor.selection().is_none()
+                        {
+                            self.state
+                                .cursor
+                                .select_right_by_words(&self.value);
+                        }
+
+                        let mut editor = Editor::new(
+                            &mut self.value,
+                            &mut self.state.cursor,
+                        );
+
+                        editor.delete();
+
+                        let message = (self.on_change)(editor.contents());
+                        messages.push(message);
+                    }
+                    keyboard::KeyCode::Left => {
+                        if platform::is_jump_modifier_pressed(modifiers) {
+                            if modifiers.shift {
+                                self.state
+                                    .cursor
+                                    .select_left_by_words(&self.value);
+                            } else {
+                                self.state
+                                    .cursor
+                                    .move_left_by_words(&self.value);
+                            }
+                        } else if modifiers.shift {
+                            self.state.cursor.select_left(&self.value)
+                        } else {
+                            self.state.cursor.move_left(&self.value);
+                        }
+                    }
+                    keyboard::KeyCode::Right => {
+                        if platform::is_jump_modifier_pressed(modifiers) {
+                            if modifiers.shift {
+                                self.state
+                                    .cursor
+                                    .select_right_by_words(&self.value);
+                            } else {
+                                self.state
+                                    .cursor
+                                    .move_right_by_words(&self.value);
+                            }
+                        } else if modifiers.shift {
+                            self.state.cursor.select_right(&self.value)
+                        } else {
+                            self.state.cursor.move_right(&self.value);
+                        }
+                    }
+                    keyboard::KeyCode::Home | keyboard::KeyCode::End => {
+                        // The jump modifier moves to the edges of the whole
+                        // text, instead of the current line
+                        let position =
+                            if platform::is_jump_modifier_pressed(modifiers) {
+                                if key_code == keyboard::KeyCode::Home {
+                                    0
+                                } else {
+                                    self.value.len()
+                                }
+                            } else {
+                                let index = find_line(
+                                    &lines,
+                                    self.state.cursor.end(&self.value),
+                                );
+
+                                if key_code == keyboard::KeyCode::Home {
+                                    lines[index].start
+                                } else {
+                                    last_column(&lines, index)
+                                }
+                            };
+
+                        if modifiers.shift {
+                            self.state.cursor.select_range(
+                                self.state.cursor.start(&self.value),
+                                position,
+                            );
+                        } else {
+                            self.state.cursor.move_to(position);
+                        }
+                    }
+                    keyboard::KeyCode::V => {
+                        if platform::is_copy_paste_modifier_pressed(modifiers) {
+                            if let Some(clipboard) = clipboard {
+                                let content = match self.state.is_pasting.take()
+                                {
+                                    Some(content) => content,
+                                    None => {
+                                        let content: String = clipboard
+                                            .content()
+                                            .unwrap_or(String::new())
+                                            .chars()
+                                            .filter(|c| {
+                                                *c == '\n' || !c.is_control()
+                                            })
+                                            .collect();
+
+                                        Value::new(&content)
+                                    }
+                                };
+
+                                let mut editor = Editor::new(
+                                    &mut self.value,
+                                    &mut self.state.cursor,
+                                );
+
+                                let _ = editor.paste(content.clone());
+
+                                let message =
+                                    (self.on_change)(editor.contents());
+                                messages.push(message);
+
+                                self.state.is_pasting = Some(content);
+                            }
+                        } else {
+                            self.state.is_pasting = None;
+                        }
+                    }
+                    keyboard::KeyCode::A => {
+                        if platform::is_copy_paste_modifier_pressed(modifiers) {
+                            self.state.cursor.select_all(&self.value);
+                        }
+                    }
+                    _ => {}
+                }
+
+                let lines = self.lines(renderer, text_bounds.width);
+                self.scroll_to_cursor(renderer, text_bounds, &lines);
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code, ..
+            }) => match key_code {
+                keyboard::KeyCode::V => {
+                    self.state.is_pasting = None;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let text_bounds = layout.children().next().unwrap().bounds();
+        let lines = self.lines(renderer, text_bounds.width);
+
+        renderer.draw(
+            bounds,
+            text_bounds,
+            cursor_position,
+            self.font.clone(),
+            self.size.unwrap_or(renderer.default_size()),
+            &self.placeholder,
+            &self.value,
+            &lines,
+            &self.state,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        use std::{any::TypeId, hash::Hash};
+        struct Marker;
+        TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.max_width.hash(state);
+        self.padding.hash(state);
+        self.size.hash(state);
+
+        // The amount of lines changes the height of the editor
+        if self.height == Length::Shrink {
+            self.value.to_string().hash(state);
+        }
+    }
+}
+
+/// The renderer of a [`TextEditor`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`TextEditor`] in your user interface.
+///
+/// [`TextEditor`]: struct.TextEditor.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// The font type used for [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    type Font: Default + Clone;
+
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Returns the default size of the text of the [`TextEditor`].
+    ///
+    /// Every line of the [`TextEditor`] is this tall.
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    fn default_size(&self) -> u16;
+
+    /// Returns the width of a single line of text.
+    fn measure_value(&self, value: &str, size: u16, font: Self::Font) -> f32;
+
+    /// Splits the given [`Value`] into the lines that fit the given width.
+    ///
+    /// Lines are broken at line breaks and, whenever a line is too long, at
+    /// the last word that fits.
+    ///
+    /// [`Value`]: ../text_input/struct.Value.html
+    fn wrap(
+        &self,
+        value: &Value,
+        font: Self::Font,
+        size: u16,
+        width: f32,
+    ) -> Vec<Line> {
+        wrap(self, value, font, size, width)
+    }
+
+    /// Draws a [`TextEditor`].
+    ///
+    /// It receives:
+    /// - the bounds of the [`TextEditor`]
+    /// - the bounds of the text (i.e. the visible part of the current value)
+    /// - the cursor position
+    /// - the placeholder to show when the value is empty
+    /// - the current [`Value`]
+    /// - the [`Line`]s of the current [`Value`]
+    /// - the current [`State`]
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    /// [`Value`]: ../text_input/struct.Value.html
+    /// [`Line`]: struct.Line.html
+    /// [`State`]: struct.State.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        text_bounds: Rectangle,
+        cursor_position: Point,
+        font: Self::Font,
+        size: u16,
+        placeholder: &str,
+        value: &Value,
+        lines: &[Line],
+        state: &State,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<TextEditor<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        text_editor: TextEditor<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(text_editor)
+    }
+}
+
+/// The state of a [`TextEditor`].
+///
+/// [`TextEditor`]: struct.TextEditor.html
+#[derive(Debug, Default, Clone)]
+pub struct State {
+    is_focused: bool,
+    is_dragging: bool,
+    is_pasting: Option<Value>,
+    last_click: Option<mouse::Click>,
+    cursor: Cursor,
+    offset: f32,
+    preferred_x: Option<f32>,
+    wrapped: RefCell<Option<Wrapped>>,
+}
+
+/// The [`Line`]s of a value, wrapped with some width and text size.
+///
+/// [`Line`]: struct.Line.html
+#[derive(Debug, Clone)]
+struct Wrapped {
+    value: Value,
+    width: f32,
+    size: u16,
+    lines: Vec<Line>,
+}
+
+impl State {
+    /// Creates a new [`State`], representing an unfocused [`TextEditor`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`State`], representing a focused [`TextEditor`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn focused() -> Self {
+        Self {
+            is_focused: true,
+            ..Self::default()
+        }
+    }
+
+    /// Returns whether the [`TextEditor`] is currently focused or not.
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Returns the [`Cursor`] of the [`TextEditor`].
+    ///
+    /// [`Cursor`]: ../text_input/struct.Cursor.html
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn cursor(&self) -> Cursor {
+        self.cursor
+    }
+
+    /// Returns the vertical scrolling offset of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+
+    /// Moves the [`Cursor`] of the [`TextEditor`] to the front of the text.
+    ///
+    /// [`Cursor`]: ../text_input/struct.Cursor.html
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn move_cursor_to_front(&mut self) {
+        self.cursor.move_to(0);
+    }
+
+    /// Moves the [`Cursor`] of the [`TextEditor`] to the end of the text.
+    ///
+    /// [`Cursor`]: ../text_input/struct.Cursor.html
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn move_cursor_to_end(&mut self) {
+        self.cursor.move_to(usize::MAX);
+    }
+
+    /// Moves the [`Cursor`] of the [`TextEditor`] to an arbitrary location.
+    ///
+    /// [`Cursor`]: ../text_input/struct.Cursor.html
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn move_cursor_to(&mut self, position: usize) {
+        self.cursor.move_to(position);
+    }
+
+    fn clamp_offset(&mut self, text_bounds: Rectangle, content_height: f32) {
+        self.offset = self
+            .offset
+            .min(content_height - text_bounds.height)
+            .max(0.0);
+    }
+}
+
+/// A line of a [`TextEditor`], once wrapped.
+///
+/// [`TextEditor`]: struct.TextEditor.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line {
+    /// The index of the first grapheme of the [`Line`].
+    ///
+    /// [`Line`]: struct.Line.html
+    pub start: usize,
+
+    /// The index right after the last grapheme of the [`Line`], excluding
+    /// any line break.
+    ///
+    /// [`Line`]: struct.Line.html
+    pub end: usize,
+}
+
+/// Returns the index of the [`Line`] containing the given grapheme
+/// `position`.
+///
+/// A position between two wrapped lines belongs to the latter.
+///
+/// [`Line`]: struct.Line.html
+pub fn find_line(lines: &[Line], position: usize) -> usize {
+    lines
+        .iter()
+        .rposition(|line| line.start <= position)
+        .unwrap_or(0)
+}
+
+/// Returns the last position of the [`Line`] with the given index where the
+/// cursor can be placed without jumping to the next [`Line`].
+fn last_column(lines: &[Line], index: usize) -> usize {
+    let line = lines[index];
+
+    match lines.get(index + 1) {
+        Some(next) if next.start == line.end && line.end > line.start => {
+            line.end - 1
+        }
+        _ => line.end,
+    }
+}
+
+fn find_column<Renderer: self::Renderer>(
+    renderer: &Renderer,
+    value: &Value,
+    lines: &[Line],
+    index: usize,
+    font: Renderer::Font,
+    size: u16,
+    target: f32,
+) -> usize {
+    let line = lines[index];
+    let end = last_column(lines, index);
+
+    let measure = |position: usize| {
+        renderer.measure_value(
+            &value.select(line.start, position).to_string(),
+            size,
+            font.clone(),
+        )
+    };
+
+    // Find the last position before the target
+    let (mut left, mut right) = (line.start, end);
+
+    while left < right {
+        let middle = (left + right + 1) / 2;
+
+        if measure(middle) <= target {
+            left = middle;
+        } else {
+            right = middle - 1;
+        }
+    }
+
+    if left < end && measure(left + 1) - target < target - measure(left) {
+        left + 1
+    } else {
+        left
+    }
+}
+
+fn wrap<Renderer: self::Renderer>(
+    renderer: &Renderer,
+    value: &Value,
+    font: Renderer::Font,
+    size: u16,
+    width: f32,
+) -> Vec<Line> {
+    let graphemes: Vec<&str> = value.graphemes().collect();
+
+    let is_line_break =
+        |grapheme: &str| grapheme.chars().all(|c| c == '\n' || c == '\r');
+    let is_whitespace =
+        |grapheme: &str| grapheme.chars().all(char::is_whitespace);
+
+    let measure = |start: usize, end: usize| {
+        renderer.measure_value(
+            &value.select(start, end).to_string(),
+            size,
+            font.clone(),
+        )
+    };
+
+    let mut lines = Vec::new();
+    let mut start = 0;
+
+    loop {
+        let end = graphemes[start..]
+            .iter()
+            .position(|grapheme| is_line_break(grapheme))
+            .map(|i| start + i)
+            .unwrap_or(graphemes.len());
+
+        // Every word is measured along with the whitespace before it, so
+        // the width of a line is the sum of the widths of its words
+        let mut line_start = start;
+        let mut line_width = 0.0;
+        let mut previous_end = start;
+        let mut word_start = start;
+
+        while word_start < end {
+            let mut word_end = word_start;
+
+            while word_end < end && !is_whitespace(graphemes[word_end]) {
+                word_end += 1;
+            }
+
+            let mut next_word = word_end;
+
+            while next_word < end && is_whitespace(graphemes[next_word]) {
+                next_word += 1;
+            }
+
+            let word_width = measure(previous_end, word_end);
+
+            if line_width + word_width <= width {
+                line_width += word_width;
+                previous_end = word_end;
+                word_start = next_word;
+            } else if line_start < word_start {
+                lines.push(Line {
+                    start: line_start,
+                    end: word_start,
+                });
+
+                line_start = word_start;
+                line_width = 0.0;
+                previous_end = word_start;
+            } else {
+                // The word does not fit on its own line, so we break it
+                let mut split = word_start + 1;
+                let mut split_width = measure(word_start, split);
+
+                while split < word_end {
+                    let grapheme_width = measure(split, split + 1);
+
+                    if split_width + grapheme_width > width {
+                        break;
+                    }
+
+                    split_width += grapheme_width;
+                    split += 1;
+                }
+
+                lines.push(Line {
+                    start: line_start,
+                    end: split,
+                });
+
+                line_start = split;
+                line_width = 0.0;
+                previous_end = split;
+                word_start = split;
+            }
+        }
+
+        lines.push(Line {
+            start: line_start,
+            end,
+        });
+
+        if end == graphemes.len() {
+            break;
+        }
+
+        start = end + 1;
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A renderer where every grapheme is 10 units wide.
+    struct Monospace;
+
+    impl crate::Renderer for Monospace {
+        type Output = ();
+        type Defaults = ();
+    }
+
+    impl Renderer for Monospace {
+        type Font = ();
+        type Style = ();
+
+        fn default_size(&self) -> u16 {
+            20
+        }
+
+        fn measure_value(&self, value: &str, _size: u16, _font: ()) -> f32 {
+            Value::new(value).len() as f32 * 10.0
+        }
+
+        fn draw(
+            &mut self,
+            _bounds: Rectangle,
+            _text_bounds: Rectangle,
+            _cursor_position: Point,
+            _font: (),
+            _size: u16,
+            _placeholder: &str,
+            _value: &Value,
+            _lines: &[Line],
+            _state: &State,
+            _style: &(),
+        ) {
+        }
+    }
+
+    fn lines(text: &str, width: f32) -> Vec<(usize, usize)> {
+        wrap(&Monospace, &Value::new(text), (), 20, width)
+            .into_iter()
+            .map(|line| (line.start, line.end))
+            .collect()
+    }
+
+    #[test]
+    fn wrapping_words() {
+        assert_eq!(lines("hello world", 200.0), vec![(0, 11)]);
+        assert_eq!(lines("hello world", 60.0), vec![(0, 6), (6, 11)]);
+        assert_eq!(lines("a b c d", 30.0), vec![(0, 4), (4, 7)]);
+        assert_eq!(lines("  indented", 100.0), vec![(0, 10)]);
+    }
+
+    #[test]
+    fn wrapping_long_words() {
+        assert_eq!(lines("abcdefghij", 40.0), vec![(0, 4), (4, 8), (8, 10)]);
+        assert_eq!(lines("a abcdefgh", 40.0), vec![(0, 2), (2, 6), (6, 10)]);
+    }
+
+    #[test]
+    fn wrapping_line_breaks() {
+        assert_eq!(lines("", 100.0), vec![(0, 0)]);
+        assert_eq!(lines("a\nb", 100.0), vec![(0, 1), (2, 3)]);
+        assert_eq!(lines("a\n", 100.0), vec![(0, 1), (2, 2)]);
+        assert_eq!(lines("\n\n", 100.0), vec![(0, 0), (1, 1), (2, 2)]);
+    }
+
+    #[test]
+    fn finding_lines_and_columns() {
+        let value = Value::new("hello world");
+        let lines = wrap(&Monospace, &value, (), 20, 60.0);
+
+        assert_eq!(find_line(&lines, 0), 0);
+        assert_eq!(find_line(&lines, 5), 0);
+        assert_eq!(find_line(&lines, 6), 1);
+        assert_eq!(find_line(&lines, 11), 1);
+
+        // The end of a wrapped line is the start of the next one
+        assert_eq!(last_column(&lines, 0), 5);
+        assert_eq!(last_column(&lines, 1), 11);
+
+        let column = |index, x| {
+            find_column(&Monospace, &value, &lines, index, (), 20, x)
+        };
+
+        assert_eq!(column(0, 0.0), 0);
+        assert_eq!(column(0, 14.0), 1);
+        assert_eq!(column(0, 16.0), 2);
+        assert_eq!(column(0, 1000.0), 5);
+        assert_eq!(column(1, 26.0), 9);
+        assert_eq!(column(1, 1000.0), 11);
+    }
+
+    #[test]
+    fn caching_lines() {
+        let mut state = State::new();
+        let editor = TextEditor::<'_, (), Monospace>::new(
+            &mut state,
+            "",
+            "hello world",
+            |_| (),
+        );
+
+        assert_eq!(editor.lines(&Monospace, 60.0).len(), 2);
+        assert_eq!(editor.lines(&Monospace, 200.0).len(), 1);
+
+        let wrapped = editor.state.wrapped.borrow();
+        let wrapped = wrapped.as_ref().expect("Cached lines");
+
+        assert_eq!(wrapped.width, 200.0);
+        assert_eq!(wrapped.lines, vec![Line { start: 0, end: 11 }]);
+    }
+}
@@ -8,14 +8,22 @@ mod editor;
 mod value;
 
 pub mod cursor;
+pub mod history;
+pub mod mask;
 
 pub use cursor::Cursor;
+pub use history::History;
+pub use mask::Mask;
 pub use value::Value;
 
-use editor::Editor;
+pub(crate) use editor::Editor;
+
+use editor::Constraints;
+
+use history::Edit;
 
 use crate::{
-    keyboard, layout,
+    event, keyboard, layout,
     mouse::{self, click},
     Clipboard, Element, Event, Hasher, Layout, Length, Point, Rectangle, Size,
     Widget,
@@ -60,6 +68,7 @@ pub struct TextInput<'a, Message, Renderer: self::Renderer> {
     size: Option<u16>,
     on_change: Box<dyn Fn(String) -> Message>,
     on_submit: Option<Message>,
+    constraints: Constraints,
     style: Renderer::Style,
 }
 
@@ -95,6 +104,7 @@ impl<'a, Message, Renderer: self::Renderer> TextInput<'a, Message, Renderer> {
             size: None,
             on_change: Box::new(on_change),
             on_submit: None,
+            constraints: Constraints::default(),
             style: Renderer::Style::default(),
         }
     }
@@ -111,8 +121,8 @@ impl<'a, Message, Renderer: self::Renderer> TextInput<'a, Message, Renderer> {
     ///
     /// [`Text`]: struct.Text.html
     /// [`Font`]: ../../struct.Font.html
-    pub fn font(mut self, font: Renderer::Font) -> Self {
-        self.font = font;
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = font.into();
         self
     }
     /// Sets the width of the [`TextInput`].
@@ -156,6 +166,42 @@ impl<'a, Message, Renderer: self::Renderer> TextInput<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the maximum amount of graphemes of the value of the
+    /// [`TextInput`].
+    ///
+    /// Typed and pasted text that does not fit is discarded.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.constraints.max_length = Some(max_length);
+        self
+    }
+
+    /// Sets a predicate that typed and pasted characters must satisfy to be
+    /// inserted in the [`TextInput`].
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn filter<F>(mut self, filter: F) -> Self
+    where
+        F: 'static + Fn(char) -> bool,
+    {
+        self.constraints.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Sets the [`Mask`] that the value of the [`TextInput`] must follow.
+    ///
+    /// Typed and pasted characters that do not follow the [`Mask`] are
+    /// discarded, and its literals are inserted automatically. The current
+    /// value is expected to follow the [`Mask`] already.
+    ///
+    /// [`Mask`]: mask/struct.Mask.html
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn mask(mut self, mask: Mask) -> Self {
+        self.constraints.mask = Some(mask);
+        self
+    }
+
     /// Sets the style of the [`TextInput`].
     ///
     /// [`TextInput`]: struct.TextInput.html
@@ -207,7 +253,7 @@ where
         messages: &mut Vec<Message>,
         renderer: &Renderer,
         clipboard: Option<&dyn Clipboard>,
-    ) {
+    ) -> event::Status {
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 let is_clicked = layout.bounds().contains(cursor_position);
@@ -232,7 +278,7 @@ where
 
                                 let position = renderer.find_cursor_position(
                                     text_layout.bounds(),
-                                    self.font,
+                                    self.font.clone(),
                                     self.size,
                                     &value,
                                     &self.state,
@@ -250,7 +296,7 @@ where
                             } else {
                                 let position = renderer.find_cursor_position(
                                     text_layout.bounds(),
-                                    self.font,
+                                    self.font.clone(),
                                     self.size,
                                     &self.value,
                                     &self.state,
@@ -269,10 +315,15 @@ where
                     }
 
                     self.state.last_click = Some(click);
+                    self.state.history.seal();
                 }
 
                 self.state.is_dragging = is_clicked;
                 self.state.is_focused = is_clicked;
+
+                if is_clicked {
+                    return event::Status::Captured;
+                }
             }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
                 self.state.is_dragging = false;
@@ -291,7 +342,7 @@ where
 
                         let position = renderer.find_cursor_position(
                             text_layout.bounds(),
-                            self.font,
+                            self.font.clone(),
                             self.size,
                             &value,
                             &self.state,
@@ -303,6 +354,8 @@ where
                             position,
                         );
                     }
+
+                    return event::Status::Captured;
                 }
             }
             Event::Keyboard(keyboard::Event::CharacterReceived(c))
@@ -310,159 +363,258 @@ where
                     && self.state.is_pasting.is_none()
                     && !c.is_control() =>
             {
+                let value = self.value.clone();
+                let cursor = self.state.cursor;
+
                 let mut editor =
-                    Editor::new(&mut self.value, &mut self.state.cursor);
+                    Editor::new(&mut self.value, &mut self.state.cursor)
+                        .constrained(&self.constraints);
+
+                if editor.insert(c) {
+                    let message = (self.on_change)(editor.contents());
+                    messages.push(message);
 
-                editor.insert(c);
+                    self.state.history.record(Edit::Insert, value, cursor);
+                }
 
-                let message = (self.on_change)(editor.contents());
-                messages.push(message);
+                return event::Status::Captured;
             }
             Event::Keyboard(keyboard::Event::KeyPressed {
                 key_code,
                 modifiers,
-            }) if self.state.is_focused => match key_code {
-                keyboard::KeyCode::Enter => {
-                    if let Some(on_submit) = self.on_submit.clone() {
-                        messages.push(on_submit);
-                    }
-                }
-                keyboard::KeyCode::Backspace => {
-                    if platform::is_jump_modifier_pressed(modifiers)
-                        && self.state.cursor.selection().is_none()
-                    {
-                        if self.is_secure {
-                            let cursor_pos = self.state.cursor.end(&self.value);
-                            self.state.cursor.select_range(0, cursor_pos);
-                        } else {
-                            self.state.cursor.select_left_by_words(&self.value);
+            }) if self.state.is_focused => {
+                match key_code {
+                    keyboard::KeyCode::Enter => {
+                        self.state.history.seal();
+
+                        if let Some(on_submit) = self.on_submit.clone() {
+                            messages.push(on_submit);
                         }
                     }
+                    keyboard::KeyCode::Backspace => {
+                        if platform::is_jump_modifier_pressed(modifiers)
+                            && self.state.cursor.selection().is_none()
+                        {
+                            if self.is_secure {
+                                let cursor_pos =
+                                    self.state.cursor.end(&self.value);
+                                self.state.cursor.select_range(0, cursor_pos);
+                            } else {
+                                self.state
+                                    .cursor
+                                    .select_left_by_words(&self.value);
+                            }
+                        }
+
+                        if self.state.cursor.selection().is_some()
+                            || self.state.cursor.start(&self.value) > 0
+                        {
+                            self.state.history.record(
+                                Edit::Delete,
+                                self.value.clone(),
+                                self.state.cursor,
+                            );
+                        }
 
-                    let mut editor =
-                        Editor::new(&mut self.value, &mut self.state.cursor);
+                        let mut editor = Editor::new(
+                            &mut self.value,
+                            &mut self.state.cursor,
+                        );
 
-                    editor.backspace();
+                        editor.backspace();
 
-                    let message = (self.on_change)(editor.contents());
-                    messages.push(message);
-                }
-                keyboard::KeyCode::Delete => {
-                    if platform::is_jump_modifier_pressed(modifiers)
-                        && self.state.cursor.selection().is_none()
-                    {
-                        if self.is_secure {
-                            let cursor_pos = self.state.cursor.end(&self.value);
-                            self.state
-                                .cursor
-                                .select_range(cursor_pos, self.value.len());
-                        } else {
-                            self.state
-                                .cursor
-                                .select_right_by_words(&self.value);
+                        let message = (self.on_change)(editor.contents());
+                        messages.push(message);
+                    }
+                    keyboard::KeyCode::Delete => {
+                        if platform::is_jump_modifier_pressed(modifiers)
+                            && self.state.cursor.selection().is_none()
+                        {
+                            if self.is_secure {
+                                let cursor_pos =
+                                    self.state.cursor.end(&self.value);
+                                self.state
+                                    .cursor
+                                    .select_range(cursor_pos, self.value.len());
+                            } else {
+                                self.state
+                                    .cursor
+                                    .select_right_by_words(&self.value);
+                            }
+                        }
+
+                        if self.state.cursor.selection().is_some()
+                            || self.state.cursor.end(&self.value)
+                                < self.value.len()
+                        {
+                            self.state.history.record(
+                                Edit::Delete,
+                                self.value.clone(),
+                                self.state.cursor,
+                            );
                         }
+
+                        let mut editor = Editor::new(
+                            &mut self.value,
+                            &mut self.state.cursor,
+                        );
+
+                        editor.delete();
+
+                        let message = (self.on_change)(editor.contents());
+                        messages.push(message);
                     }
+                    keyboard::KeyCode::Left | keyboard::KeyCode::Right => {
+                        self.state.history.seal();
+
+                        // Arrows follow the visual order of the text, which
+                        // is reversed in right-to-left runs
+                        let is_rtl = self.state.is_rtl(
+                            &self.value,
+                            self.state.cursor.end(&self.value),
+                        );
 
-                    let mut editor =
-                        Editor::new(&mut self.value, &mut self.state.cursor);
+                        let is_forward =
+                            (key_code == keyboard::KeyCode::Right) != is_rtl;
 
-                    editor.delete();
+                        let cursor = &mut self.state.cursor;
 
-                    let message = (self.on_change)(editor.contents());
-                    messages.push(message);
-                }
-                keyboard::KeyCode::Left => {
-                    if platform::is_jump_modifier_pressed(modifiers)
-                        && !self.is_secure
-                    {
-                        if modifiers.shift {
-                            self.state.cursor.select_left_by_words(&self.value);
+                        if platform::is_jump_modifier_pressed(modifiers)
+                            && !self.is_secure
+                        {
+                            match (is_forward, modifiers.shift) {
+                                (false, true) => {
+                                    cursor.select_left_by_words(&self.value)
+                                }
+                                (false, false) => {
+                                    cursor.move_left_by_words(&self.value)
+                                }
+                                (true, true) => {
+                                    cursor.select_right_by_words(&self.value)
+                                }
+                                (true, false) => {
+                                    cursor.move_right_by_words(&self.value)
+                                }
+                            }
                         } else {
-                            self.state.cursor.move_left_by_words(&self.value);
+                            match (is_forward, modifiers.shift) {
+                                (false, true) => {
+                                    cursor.select_left(&self.value)
+                                }
+                                (false, false) => cursor.move_left(&self.value),
+                                (true, true) => {
+                                    cursor.select_right(&self.value)
+                                }
+                                (true, false) => cursor.move_right(&self.value),
+                            }
                         }
-                    } else if modifiers.shift {
-                        self.state.cursor.select_left(&self.value)
-                    } else {
-                        self.state.cursor.move_left(&self.value);
                     }
-                }
-                keyboard::KeyCode::Right => {
-                    if platform::is_jump_modifier_pressed(modifiers)
-                        && !self.is_secure
-                    {
+                    keyboard::KeyCode::Home => {
+                        self.state.history.seal();
+
                         if modifiers.shift {
-                            self.state
-                                .cursor
-                                .select_right_by_words(&self.value);
+                            self.state.cursor.select_range(
+                                self.state.cursor.start(&self.value),
+                                0,
+                            );
                         } else {
-                            self.state.cursor.move_right_by_words(&self.value);
+                            self.state.cursor.move_to(0);
                         }
-                    } else if modifiers.shift {
-                        self.state.cursor.select_right(&self.value)
-                    } else {
-                        self.state.cursor.move_right(&self.value);
-                    }
-                }
-                keyboard::KeyCode::Home => {
-                    if modifiers.shift {
-                        self.state.cursor.select_range(
-                            self.state.cursor.start(&self.value),
-                            0,
-                        );
-                    } else {
-                        self.state.cursor.move_to(0);
-                    }
-                }
-                keyboard::KeyCode::End => {
-                    if modifiers.shift {
-                        self.state.cursor.select_range(
-                            self.state.cursor.start(&self.value),
-                            self.value.len(),
-                        );
-                    } else {
-                        self.state.cursor.move_to(self.value.len());
                     }
-                }
-                keyboard::KeyCode::V => {
-                    if platform::is_copy_paste_modifier_pressed(modifiers) {
-                        if let Some(clipboard) = clipboard {
-                            let content = match self.state.is_pasting.take() {
-                                Some(content) => content,
-                                None => {
-                                    let content: String = clipboard
-                                        .content()
-                                        .unwrap_or(String::new())
-                                        .chars()
-                                        .filter(|c| !c.is_control())
-                                        .collect();
-
-                                    Value::new(&content)
-                                }
-                            };
+                    keyboard::KeyCode::End => {
+                        self.state.history.seal();
 
-                            let mut editor = Editor::new(
-                                &mut self.value,
-                                &mut self.state.cursor,
+                        if modifiers.shift {
+                            self.state.cursor.select_range(
+                                self.state.cursor.start(&self.value),
+                                self.value.len(),
                             );
+                        } else {
+                            self.state.cursor.move_to(self.value.len());
+                        }
+                    }
+                    keyboard::KeyCode::V => {
+                        if platform::is_copy_paste_modifier_pressed(modifiers) {
+                            if let Some(clipboard) = clipboard {
+                                let content = match self.state.is_pasting.take()
+                                {
+                                    Some(content) => content,
+                                    None => {
+                                        let content: String = clipboard
+                                            .content()
+                                            .unwrap_or(String::new())
+                                            .chars()
+                                            .filter(|c| !c.is_control())
+                                            .collect();
+
+                                        Value::new(&content)
+                                    }
+                                };
 
-                            editor.paste(content.clone());
+                                let value = self.value.clone();
+                                let cursor = self.state.cursor;
+
+                                let mut editor = Editor::new(
+                                    &mut self.value,
+                                    &mut self.state.cursor,
+                                )
+                                .constrained(&self.constraints);
+
+                                if editor.paste(content.clone()) {
+                                    let message =
+                                        (self.on_change)(editor.contents());
+                                    messages.push(message);
+
+                                    self.state.history.record(
+                                        Edit::Paste,
+                                        value,
+                                        cursor,
+                                    );
+                                }
 
-                            let message = (self.on_change)(editor.contents());
-                            messages.push(message);
+                                self.state.is_pasting = Some(content);
+                            }
+                        } else {
+                            self.state.is_pasting = None;
+                        }
+                    }
+                    keyboard::KeyCode::A => {
+                        if platform::is_copy_paste_modifier_pressed(modifiers) {
+                            self.state.cursor.select_all(&self.value);
+                            self.state.history.seal();
+                        }
+                    }
+                    keyboard::KeyCode::Z => {
+                        if platform::is_copy_paste_modifier_pressed(modifiers) {
+                            let is_changed = if modifiers.shift {
+                                self.state.history.redo(
+                                    &mut self.value,
+                                    &mut self.state.cursor,
+                                )
+                            } else {
+                                self.state.history.undo(
+                                    &mut self.value,
+                                    &mut self.state.cursor,
+                                )
+                            };
 
-                            self.state.is_pasting = Some(content);
+                            if is_changed {
+                                let message =
+                                    (self.on_change)(self.value.to_string());
+                                messages.push(message);
+                            }
                         }
-                    } else {
-                        self.state.is_pasting = None;
                     }
-                }
-                keyboard::KeyCode::A => {
-                    if platform::is_copy_paste_modifier_pressed(modifiers) {
-                        self.state.cursor.select_all(&self.value);
+                    keyboard::KeyCode::Tab
+                    | keyboard::KeyCode::Up
+                    | keyboard::KeyCode::Down => {
+                        return event::Status::Ignored;
                     }
+                    _ => {}
                 }
-                _ => {}
-            },
+
+                return event::Status::Captured;
+            }
             Event::Keyboard(keyboard::Event::KeyReleased {
                 key_code, ..
             }) => match key_code {
@@ -473,6 +625,8 @@ where
             },
             _ => {}
         }
+
+        event::Status::Ignored
     }
 
     fn draw(
@@ -490,7 +644,7 @@ where
                 bounds,
                 text_bounds,
                 cursor_position,
-                self.font,
+                self.font.clone(),
                 self.size.unwrap_or(renderer.default_size()),
                 &self.placeholder,
                 &self.value.secure(),
@@ -502,7 +656,7 @@ where
                 bounds,
                 text_bounds,
                 cursor_position,
-                self.font,
+                self.font.clone(),
                 self.size.unwrap_or(renderer.default_size()),
                 &self.placeholder,
                 &self.value,
@@ -535,7 +689,7 @@ pub trait Renderer: crate::Renderer + Sized {
     /// The font type used for [`TextInput`].
     ///
     /// [`TextInput`]: struct.TextInput.html
-    type Font: Default + Copy;
+    type Font: Default + Clone;
 
     /// The style supported by this renderer.
     type Style: Default;
@@ -608,7 +762,8 @@ pub trait Renderer: crate::Renderer + Sized {
     ) -> usize {
         let size = size.unwrap_or(self.default_size());
 
-        let offset = self.offset(text_bounds, font, size, &value, &state);
+        let offset =
+            self.offset(text_bounds, font.clone(), size, &value, &state);
 
         find_cursor_position(
             self,
@@ -645,6 +800,8 @@ pub struct State {
     is_pasting: Option<Value>,
     last_click: Option<mouse::Click>,
     cursor: Cursor,
+    history: History,
+    directions: Option<(Value, Vec<bool>)>,
     // TODO: Add stateful horizontal scrolling offset
 }
 
@@ -666,6 +823,8 @@ impl State {
             is_pasting: None,
             last_click: None,
             cursor: Cursor::default(),
+            history: History::new(),
+            directions: None,
         }
     }
 
@@ -684,6 +843,58 @@ impl State {
         self.cursor
     }
 
+    /// Returns the edit [`History`] of the [`TextInput`].
+    ///
+    /// [`History`]: history/struct.History.html
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn history(&self) -> &History {
+        &self.history
+    }
+
+    /// Forgets the edit [`History`] of the [`TextInput`].
+    ///
+    /// This is useful when the value of the [`TextInput`] is replaced by your
+    /// application, like when a message is sent.
+    ///
+    /// [`History`]: history/struct.History.html
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+    }
+
+    /// Sets the maximum amount of steps kept in the edit [`History`] of the
+    /// [`TextInput`].
+    ///
+    /// [`History`]: history/struct.History.html
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn set_max_history_steps(&mut self, max_steps: usize) {
+        self.history.set_max_steps(max_steps);
+    }
+
+    /// Returns whether the grapheme at the given `index` of the value is
+    /// displayed from right to left.
+    ///
+    /// The directions are only computed again when the value changes. An
+    /// `index` past the end refers to the last grapheme.
+    fn is_rtl(&mut self, value: &Value, index: usize) -> bool {
+        let is_outdated = match &self.directions {
+            Some((cached, _)) => cached != value,
+            None => true,
+        };
+
+        if is_outdated {
+            self.directions = Some((value.clone(), value.rtl_graphemes()));
+        }
+
+        match &self.directions {
+            Some((_, directions)) => directions
+                .get(index.min(directions.len().saturating_sub(1)))
+                .copied()
+                .unwrap_or(false),
+            None => false,
+        }
+    }
+
     /// Moves the [`Cursor`] of the [`TextInput`] to the front of the input text.
     ///
     /// [`Cursor`]: struct.Cursor.html
@@ -727,8 +938,10 @@ fn find_cursor_position<Renderer: self::Renderer>(
         let prev = value.until(start - 1);
         let next = value.until(start);
 
-        let prev_width = renderer.measure_value(&prev.to_string(), size, font);
-        let next_width = renderer.measure_value(&next.to_string(), size, font);
+        let prev_width =
+            renderer.measure_value(&prev.to_string(), size, font.clone());
+        let next_width =
+            renderer.measure_value(&next.to_string(), size, font.clone());
 
         if next_width - target > target - prev_width {
             return start - 1;
@@ -740,7 +953,8 @@ fn find_cursor_position<Renderer: self::Renderer>(
     let index = (end - start) / 2;
     let subvalue = value.until(start + index);
 
-    let width = renderer.measure_value(&subvalue.to_string(), size, font);
+    let width =
+        renderer.measure_value(&subvalue.to_string(), size, font.clone());
 
     if width > target {
         find_cursor_position(
@@ -765,7 +979,7 @@ fn find_cursor_position<Renderer: self::Renderer>(
     }
 }
 
-mod platform {
+pub(crate) mod platform {
     use crate::keyboard;
 
     pub fn is_jump_modifier_pressed(
@@ -1,46 +1,77 @@
-use crate::text_input::{Cursor, Value};
+use crate::text_input::{Cursor, Mask, Value};
 
 pub struct Editor<'a> {
     value: &'a mut Value,
     cursor: &'a mut Cursor,
+    constraints: Option<&'a Constraints>,
+}
+
+/// The rules that inserted text must follow.
+#[derive(Default)]
+pub struct Constraints {
+    pub max_length: Option<usize>,
+    pub filter: Option<Box<dyn Fn(char) -> bool>>,
+    pub mask: Option<Mask>,
 }
 
 impl<'a> Editor<'a> {
     pub fn new(value: &'a mut Value, cursor: &'a mut Cursor) -> Editor<'a> {
-        Editor { value, cursor }
+        Editor {
+            value,
+            cursor,
+            constraints: None,
+        }
+    }
+
+    pub fn constrained(mut self, constraints: &'a Constraints) -> Editor<'a> {
+        self.constraints = Some(constraints);
+        self
     }
 
     pub fn contents(&self) -> String {
         self.value.to_string()
     }
 
-    pub fn insert(&mut self, character: char) {
-        match self.cursor.selection() {
-            Some((left, right)) => {
-                self.cursor.move_left(self.value);
-                self.value.remove_many(left, right);
-            }
-            _ => (),
+    /// Inserts a character, returning whether it was accepted.
+    pub fn insert(&mut self, character: char) -> bool {
+        let content = self.constrain(&character.to_string());
+
+        if content.is_empty() {
+            return false;
+        }
+
+        self.remove_selection();
+
+        // Literals of a mask may be inserted before the character
+        for character in content.chars() {
+            self.value.insert(self.cursor.end(self.value), character);
+            self.cursor.move_right(self.value);
         }
 
-        self.value.insert(self.cursor.end(self.value), character);
-        self.cursor.move_right(self.value);
+        true
     }
 
-    pub fn paste(&mut self, content: Value) {
-        let length = content.len();
+    /// Pastes some content, returning whether any of it was accepted.
+    pub fn paste(&mut self, content: Value) -> bool {
+        let content = if self.constraints.is_some() {
+            Value::new(&self.constrain(&content.to_string()))
+        } else {
+            content
+        };
 
-        match self.cursor.selection() {
-            Some((left, right)) => {
-                self.cursor.move_left(self.value);
-                self.value.remove_many(left, right);
-            }
-            _ => (),
+        if content.len() == 0 && self.constraints.is_some() {
+            return false;
         }
 
+        let length = content.len();
+
+        self.remove_selection();
+
         self.value.insert_many(self.cursor.end(self.value), content);
 
         self.cursor.move_right_by_amount(self.value, length);
+
+        true
     }
 
     pub fn backspace(&mut self) {
@@ -74,4 +105,79 @@ impl<'a> Editor<'a> {
             }
         }
     }
+
+    fn remove_selection(&mut self) {
+        match self.cursor.selection() {
+            Some((left, right)) => {
+                self.cursor.move_left(self.value);
+                self.value.remove_many(left, right);
+            }
+            _ => (),
+        }
+    }
+
+    /// Returns the part of the given content that can be inserted at the
+    /// cursor, replacing the current selection.
+    fn constrain(&self, content: &str) -> String {
+        let constraints = match self.constraints {
+            Some(constraints) => constraints,
+            None => return content.to_string(),
+        };
+
+        let (left, right) = self.cursor.selection().unwrap_or_else(|| {
+            let position = self.cursor.end(self.value);
+
+            (position, position)
+        });
+
+        let before = self.value.until(left).to_string();
+        let after = self.value.select(right, self.value.len()).to_string();
+        let length = self.value.len() - (right - left);
+
+        let mut accepted = String::new();
+
+        for character in content.chars() {
+            if let Some(filter) = &constraints.filter {
+                if !filter(character) {
+                    continue;
+                }
+            }
+
+            let insertion = match &constraints.mask {
+                Some(mask) => {
+                    let prefix = format!("{}{}", before, accepted);
+                    let with = |insertion: &str| {
+                        format!("{}{}{}", prefix, insertion, after)
+                    };
+
+                    let literals = mask.next_literals(&prefix);
+                    let literals_and_character =
+                        format!("{}{}", literals, character);
+
+                    if mask.accepts(&with(&character.to_string())) {
+                        character.to_string()
+                    } else if !literals.is_empty()
+                        && mask.accepts(&with(&literals_and_character))
+                    {
+                        literals_and_character
+                    } else {
+                        continue;
+                    }
+                }
+                None => character.to_string(),
+            };
+
+            if let Some(max_length) = constraints.max_length {
+                let total = format!("{}{}", accepted, insertion);
+
+                if length + Value::new(&total).len() > max_length {
+                    break;
+                }
+            }
+
+            accepted.push_str(&insertion);
+        }
+
+        accepted
+    }
 }
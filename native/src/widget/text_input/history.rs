@@ -0,0 +1,295 @@
+//! Undo and redo the edits of a text input.
+use crate::text_input::{Cursor, Value};
+
+use std::collections::VecDeque;
+
+/// The edit history of a text input.
+///
+/// Consecutive typing and deletion of single graphemes are grouped into a
+/// single step, until the cursor is moved.
+///
+/// Only the latest steps are kept, up to a maximum. By default, the maximum
+/// is [`DEFAULT_MAX_STEPS`].
+///
+/// [`DEFAULT_MAX_STEPS`]: constant.DEFAULT_MAX_STEPS.html
+#[derive(Debug, Clone)]
+pub struct History {
+    undo: VecDeque<Entry>,
+    redo: VecDeque<Entry>,
+    last_edit: Option<Edit>,
+    max_steps: usize,
+}
+
+/// The default maximum amount of steps kept by a [`History`].
+///
+/// [`History`]: struct.History.html
+pub const DEFAULT_MAX_STEPS: usize = 100;
+
+/// A kind of edit recorded in a [`History`].
+///
+/// [`History`]: struct.History.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Edit {
+    Insert,
+    Delete,
+    Paste,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    value: Value,
+    cursor: Cursor,
+}
+
+impl History {
+    /// Creates a new empty [`History`].
+    ///
+    /// [`History`]: struct.History.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new empty [`History`] that keeps up to the given amount of
+    /// steps.
+    ///
+    /// [`History`]: struct.History.html
+    pub fn with_max_steps(max_steps: usize) -> Self {
+        Self {
+            max_steps,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the maximum amount of steps kept by the [`History`].
+    ///
+    /// [`History`]: struct.History.html
+    pub fn max_steps(&self) -> usize {
+        self.max_steps
+    }
+
+    /// Sets the maximum amount of steps kept by the [`History`], forgetting
+    /// the oldest ones if needed.
+    ///
+    /// [`History`]: struct.History.html
+    pub fn set_max_steps(&mut self, max_steps: usize) {
+        self.max_steps = max_steps;
+
+        Self::forget_oldest(&mut self.undo, max_steps);
+        Self::forget_oldest(&mut self.redo, max_steps);
+    }
+
+    /// Returns whether there is an edit that can be undone.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Returns whether there is an undone edit that can be redone.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Returns the amount of steps that can be undone.
+    pub fn undo_steps(&self) -> usize {
+        self.undo.len()
+    }
+
+    /// Returns the amount of steps that can be redone.
+    pub fn redo_steps(&self) -> usize {
+        self.redo.len()
+    }
+
+    /// Forgets every recorded edit.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.last_edit = None;
+    }
+
+    /// Records the [`Value`] and [`Cursor`] before an edit.
+    ///
+    /// [`Value`]: ../struct.Value.html
+    /// [`Cursor`]: ../struct.Cursor.html
+    pub(crate) fn record(&mut self, edit: Edit, value: Value, cursor: Cursor) {
+        let is_grouped = self.last_edit == Some(edit)
+            && edit != Edit::Paste
+            && cursor.selection().is_none();
+
+        if !is_grouped {
+            self.undo.push_back(Entry { value, cursor });
+
+            Self::forget_oldest(&mut self.undo, self.max_steps);
+        }
+
+        self.redo.clear();
+        self.last_edit = Some(edit);
+    }
+
+    /// Ends the current group of edits, so the next edit becomes a new step.
+    pub(crate) fn seal(&mut self) {
+        self.last_edit = None;
+    }
+
+    /// Restores the [`Value`] and [`Cursor`] before the last edit, returning
+    /// whether anything changed.
+    ///
+    /// [`Value`]: ../struct.Value.html
+    /// [`Cursor`]: ../struct.Cursor.html
+    pub(crate) fn undo(
+        &mut self,
+        value: &mut Value,
+        cursor: &mut Cursor,
+    ) -> bool {
+        self.last_edit = None;
+
+        Self::restore(&mut self.undo, &mut self.redo, value, cursor)
+    }
+
+    /// Restores the [`Value`] and [`Cursor`] after the last undone edit,
+    /// returning whether anything changed.
+    ///
+    /// [`Value`]: ../struct.Value.html
+    /// [`Cursor`]: ../struct.Cursor.html
+    pub(crate) fn redo(
+        &mut self,
+        value: &mut Value,
+        cursor: &mut Cursor,
+    ) -> bool {
+        self.last_edit = None;
+
+        Self::restore(&mut self.redo, &mut self.undo, value, cursor)
+    }
+
+    fn restore(
+        from: &mut VecDeque<Entry>,
+        to: &mut VecDeque<Entry>,
+        value: &mut Value,
+        cursor: &mut Cursor,
+    ) -> bool {
+        match from.pop_back() {
+            Some(entry) => {
+                to.push_back(Entry {
+                    value: std::mem::replace(value, entry.value),
+                    cursor: std::mem::replace(cursor, entry.cursor),
+                });
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn forget_oldest(steps: &mut VecDeque<Entry>, max_steps: usize) {
+        while steps.len() > max_steps {
+            let _ = steps.pop_front();
+        }
+    }
+}
+
+impl Default for History {
+    fn default() -> Self {
+        Self {
+            undo: VecDeque::new(),
+            redo: VecDeque::new(),
+            last_edit: None,
+            max_steps: DEFAULT_MAX_STEPS,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edit(
+        history: &mut History,
+        kind: Edit,
+        value: &mut Value,
+        cursor: &mut Cursor,
+        text: &str,
+    ) {
+        history.record(kind, value.clone(), *cursor);
+
+        *value = Value::new(text);
+        cursor.move_to(value.len());
+    }
+
+    #[test]
+    fn typing_is_grouped() {
+        let mut history = History::new();
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+
+        edit(&mut history, Edit::Insert, &mut value, &mut cursor, "a");
+        edit(&mut history, Edit::Insert, &mut value, &mut cursor, "ab");
+        edit(&mut history, Edit::Delete, &mut value, &mut cursor, "a");
+        edit(&mut history, Edit::Delete, &mut value, &mut cursor, "");
+
+        assert_eq!(history.undo_steps(), 2);
+
+        history.seal();
+        edit(&mut history, Edit::Insert, &mut value, &mut cursor, "c");
+
+        assert_eq!(history.undo_steps(), 3);
+    }
+
+    #[test]
+    fn pastes_are_not_grouped() {
+        let mut history = History::new();
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+
+        edit(&mut history, Edit::Paste, &mut value, &mut cursor, "a");
+        edit(&mut history, Edit::Paste, &mut value, &mut cursor, "aa");
+
+        assert_eq!(history.undo_steps(), 2);
+    }
+
+    #[test]
+    fn undo_and_redo() {
+        let mut history = History::new();
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+
+        edit(&mut history, Edit::Insert, &mut value, &mut cursor, "a");
+        history.seal();
+        edit(&mut history, Edit::Paste, &mut value, &mut cursor, "abc");
+
+        assert!(history.undo(&mut value, &mut cursor));
+        assert_eq!(value.to_string(), "a");
+        assert!(history.undo(&mut value, &mut cursor));
+        assert_eq!(value.to_string(), "");
+        assert!(!history.undo(&mut value, &mut cursor));
+
+        assert!(history.redo(&mut value, &mut cursor));
+        assert_eq!(value.to_string(), "a");
+        assert_eq!(history.redo_steps(), 1);
+
+        // A new edit forgets the undone ones
+        edit(&mut history, Edit::Insert, &mut value, &mut cursor, "ab");
+
+        assert!(!history.can_redo());
+    }
+
+    #[test]
+    fn max_steps() {
+        let mut history = History::with_max_steps(2);
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+
+        for text in &["a", "ab", "abc"] {
+            edit(&mut history, Edit::Paste, &mut value, &mut cursor, text);
+        }
+
+        assert_eq!(history.undo_steps(), 2);
+
+        assert!(history.undo(&mut value, &mut cursor));
+        assert!(history.undo(&mut value, &mut cursor));
+        assert_eq!(value.to_string(), "a");
+
+        history.set_max_steps(1);
+
+        assert_eq!(history.redo_steps(), 1);
+        assert!(history.redo(&mut value, &mut cursor));
+        assert_eq!(value.to_string(), "ab");
+    }
+}
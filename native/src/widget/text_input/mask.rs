@@ -0,0 +1,264 @@
+//! Constrain the value of a text input to a format.
+
+/// A format that the value of a text input must follow, like a date or a
+/// phone number.
+///
+/// A [`Mask`] is made of placeholders and literals:
+/// - `0` is a required digit
+/// - `9` is an optional digit
+/// - `L` is a required letter
+/// - `?` is an optional letter
+/// - `A` is a required letter or digit
+/// - `a` is an optional letter or digit
+/// - `\` escapes the next character, so it is treated as a literal
+///
+/// Any other character is a literal. Literals are inserted automatically
+/// while typing.
+///
+/// # Example
+/// ```
+/// # use iced_native::text_input::Mask;
+/// #
+/// let date = Mask::new("00/00/0000");
+/// let phone = Mask::new("(000) 000-0000");
+/// let ip = Mask::new("099.099.099.099");
+///
+/// assert!(ip.accepts("192.168.1"));
+/// assert!(ip.is_complete("192.168.1.1"));
+/// assert!(!date.accepts("12-"));
+/// ```
+///
+/// [`Mask`]: struct.Mask.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mask {
+    tokens: Vec<Token>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+    Literal(char),
+    Placeholder { kind: Kind, is_optional: bool },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Digit,
+    Letter,
+    Alphanumeric,
+}
+
+impl Token {
+    fn is_optional(&self) -> bool {
+        match self {
+            Token::Placeholder { is_optional, .. } => *is_optional,
+            Token::Literal(_) => false,
+        }
+    }
+}
+
+impl Kind {
+    fn matches(self, c: char) -> bool {
+        match self {
+            Kind::Digit => c.is_ascii_digit(),
+            Kind::Letter => c.is_alphabetic(),
+            Kind::Alphanumeric => c.is_alphanumeric(),
+        }
+    }
+}
+
+impl Mask {
+    /// Creates a new [`Mask`] from the given pattern.
+    ///
+    /// [`Mask`]: struct.Mask.html
+    pub fn new(pattern: &str) -> Self {
+        let mut chars = pattern.chars();
+        let mut tokens = Vec::new();
+
+        while let Some(c) = chars.next() {
+            let placeholder =
+                |kind, is_optional| Token::Placeholder { kind, is_optional };
+
+            tokens.push(match c {
+                '0' => placeholder(Kind::Digit, false),
+                '9' => placeholder(Kind::Digit, true),
+                'L' => placeholder(Kind::Letter, false),
+                '?' => placeholder(Kind::Letter, true),
+                'A' => placeholder(Kind::Alphanumeric, false),
+                'a' => placeholder(Kind::Alphanumeric, true),
+                '\\' => match chars.next() {
+                    Some(escaped) => Token::Literal(escaped),
+                    None => break,
+                },
+                _ => Token::Literal(c),
+            });
+        }
+
+        Self { tokens }
+    }
+
+    /// Returns whether the given text follows the [`Mask`], even if some of
+    /// its required placeholders are still missing.
+    ///
+    /// [`Mask`]: struct.Mask.html
+    pub fn accepts(&self, text: &str) -> bool {
+        !self.parse(text).is_empty()
+    }
+
+    /// Returns whether the given text follows the [`Mask`] and fills all of
+    /// its required placeholders.
+    ///
+    /// [`Mask`]: struct.Mask.html
+    pub fn is_complete(&self, text: &str) -> bool {
+        self.skip_optional(self.parse(text))
+            .contains(&self.tokens.len())
+    }
+
+    /// Returns the literals expected after the given text, skipping any
+    /// optional placeholders before them.
+    pub(crate) fn next_literals(&self, text: &str) -> String {
+        self.parse(text)
+            .into_iter()
+            .map(|next| {
+                self.tokens[next..]
+                    .iter()
+                    .skip_while(|token| token.is_optional())
+                    .take_while(|token| match token {
+                        Token::Literal(_) => true,
+                        Token::Placeholder { .. } => false,
+                    })
+                    .filter_map(|token| match token {
+                        Token::Literal(c) => Some(*c),
+                        Token::Placeholder { .. } => None,
+                    })
+                    .collect::<String>()
+            })
+            .find(|literals| !literals.is_empty())
+            .unwrap_or_default()
+    }
+
+    /// Matches the given text against the [`Mask`], returning the indices of
+    /// the tokens that may follow it, in increasing order.
+    ///
+    /// Every optional placeholder may be either filled or skipped, so all
+    /// the possible matches are tracked at once. An empty list means the
+    /// text does not follow the [`Mask`].
+    ///
+    /// [`Mask`]: struct.Mask.html
+    fn parse(&self, text: &str) -> Vec<usize> {
+        let mut next = vec![0];
+
+        for c in text.chars() {
+            next = self
+                .skip_optional(next)
+                .into_iter()
+                .filter(|i| match self.tokens.get(*i) {
+                    Some(Token::Literal(literal)) => c == *literal,
+                    Some(Token::Placeholder { kind, .. }) => kind.matches(c),
+                    None => false,
+                })
+                .map(|i| i + 1)
+                .collect();
+
+            if next.is_empty() {
+                break;
+            }
+        }
+
+        next
+    }
+
+    /// Adds to the given token indices the ones reachable by skipping
+    /// optional placeholders.
+    fn skip_optional(&self, mut indices: Vec<usize>) -> Vec<usize> {
+        let mut i = 0;
+
+        while i < indices.len() {
+            let index = indices[i];
+
+            if self.tokens.get(index).map_or(false, Token::is_optional)
+                && !indices.contains(&(index + 1))
+            {
+                indices.push(index + 1);
+            }
+
+            i += 1;
+        }
+
+        indices.sort_unstable();
+        indices
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn date() {
+        let date = Mask::new("00/00/0000");
+
+        assert!(date.accepts(""));
+        assert!(date.accepts("12/0"));
+        assert!(!date.accepts("12-"));
+        assert!(!date.accepts("1a"));
+        assert!(!date.is_complete("12/05/202"));
+        assert!(date.is_complete("12/05/2020"));
+        assert!(!date.accepts("12/05/20201"));
+
+        assert_eq!(date.next_literals("12"), "/");
+        assert_eq!(date.next_literals("1"), "");
+    }
+
+    #[test]
+    fn phone() {
+        let phone = Mask::new("(000) 000-0000");
+
+        assert!(phone.accepts("(555) 12"));
+        assert!(!phone.accepts("555"));
+        assert!(phone.is_complete("(555) 123-4567"));
+        assert!(!phone.is_complete("(555) 123-456"));
+
+        assert_eq!(phone.next_literals(""), "(");
+        assert_eq!(phone.next_literals("(555"), ") ");
+    }
+
+    #[test]
+    fn ip() {
+        let ip = Mask::new("099.099.099.099");
+
+        assert!(ip.accepts("192.168.1"));
+        assert!(ip.accepts("1.1"));
+        assert!(!ip.accepts("1..1"));
+        assert!(!ip.accepts("1234"));
+        assert!(ip.is_complete("192.168.1.1"));
+        assert!(ip.is_complete("10.0.0.255"));
+        assert!(!ip.is_complete("192.168.1"));
+
+        assert_eq!(ip.next_literals("192"), ".");
+    }
+
+    #[test]
+    fn time() {
+        let time = Mask::new("90:00");
+
+        assert!(time.accepts("5:30"));
+        assert!(time.accepts("12:30"));
+        assert!(!time.accepts("123"));
+        assert!(time.is_complete("5:30"));
+        assert!(time.is_complete("12:30"));
+        assert!(!time.is_complete("5:3"));
+        assert!(!time.is_complete("5"));
+
+        assert_eq!(time.next_literals("5"), ":");
+        assert_eq!(time.next_literals("12"), ":");
+    }
+
+    #[test]
+    fn escaped_literals() {
+        let mask = Mask::new(r"\0-00");
+
+        assert!(mask.accepts("0-1"));
+        assert!(!mask.accepts("1"));
+        assert!(mask.is_complete("0-12"));
+    }
+}
@@ -1,10 +1,11 @@
+use unicode_bidi::BidiInfo;
 use unicode_segmentation::UnicodeSegmentation;
 
 /// The value of a [`TextInput`].
 ///
 /// [`TextInput`]: struct.TextInput.html
 // TODO: Reduce allocations, cache results (?)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Value {
     graphemes: Vec<String>,
 }
@@ -84,6 +85,43 @@ impl Value {
         Self { graphemes }
     }
 
+    /// Returns a new [`Value`] containing the graphemes from `start` until the
+    /// given `end`.
+    ///
+    /// [`Value`]: struct.Value.html
+    pub fn select(&self, start: usize, end: usize) -> Self {
+        let end = end.min(self.len());
+        let graphemes = self.graphemes[start.min(end)..end].to_vec();
+
+        Self { graphemes }
+    }
+
+    /// Returns whether each grapheme of the [`Value`] is displayed from
+    /// right to left, following the Unicode Bidirectional Algorithm.
+    ///
+    /// [`Value`]: struct.Value.html
+    pub fn rtl_graphemes(&self) -> Vec<bool> {
+        let string = self.to_string();
+        let levels = BidiInfo::new(&string, None).levels;
+
+        self.graphemes
+            .iter()
+            .scan(0, |offset, grapheme| {
+                let is_rtl = levels[*offset].is_rtl();
+                *offset += grapheme.len();
+
+                Some(is_rtl)
+            })
+            .collect()
+    }
+
+    /// Returns an iterator over the graphemes of the [`Value`].
+    ///
+    /// [`Value`]: struct.Value.html
+    pub fn graphemes(&self) -> impl Iterator<Item = &str> {
+        self.graphemes.iter().map(String::as_str)
+    }
+
     /// Converts the [`Value`] into a `String`.
     ///
     /// [`Value`]: struct.Value.html
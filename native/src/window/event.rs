@@ -1,4 +1,5 @@
 use std::path::PathBuf;
+use std::time::Instant;
 
 /// A window-related event.
 #[derive(PartialEq, Clone, Debug)]
@@ -29,4 +30,10 @@ pub enum Event {
     /// There will be a single `FilesHoveredLeft` event triggered even if
     /// multiple files were hovered.
     FilesHoveredLeft,
+
+    /// A new frame was requested to continue an animation.
+    ///
+    /// Shells produce this event when a renderer asks for another frame, so
+    /// animated widgets can advance their state to the given instant.
+    RedrawRequested(Instant),
 }
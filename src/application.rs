@@ -1,4 +1,6 @@
-use crate::{window, Command, Element, Executor, Settings, Subscription};
+use crate::{
+    window, Command, Element, Executor, Settings, Subscription, Theme,
+};
 
 /// An interactive cross-platform application.
 ///
@@ -174,6 +176,21 @@ pub trait Application: Sized {
         window::Mode::Windowed
     }
 
+    /// Returns the current [`Theme`] of the [`Application`].
+    ///
+    /// The default styles of all the built-in widgets derive their colors
+    /// from this [`Theme`]. The runtime will automatically redraw your
+    /// application if a new theme is returned.
+    ///
+    /// By default, it returns [`Theme::LIGHT`].
+    ///
+    /// [`Theme`]: theme/struct.Theme.html
+    /// [`Theme::LIGHT`]: theme/struct.Theme.html#associatedconstant.LIGHT
+    /// [`Application`]: trait.Application.html
+    fn theme(&self) -> Theme {
+        Theme::LIGHT
+    }
+
     /// Runs the [`Application`].
     ///
     /// On native platforms, this method will take control of the current thread
@@ -188,8 +205,10 @@ pub trait Application: Sized {
     {
         #[cfg(not(target_arch = "wasm32"))]
         {
+            let mut settings = settings;
+
             let renderer_settings = crate::renderer::Settings {
-                default_font: settings.default_font,
+                default_font: settings.default_font.take(),
                 antialiasing: if settings.antialiasing {
                     Some(crate::renderer::settings::Antialiasing::MSAAx4)
                 } else {
@@ -227,6 +246,10 @@ where
     fn view(&mut self) -> Element<'_, Self::Message> {
         self.0.view()
     }
+
+    fn theme(&self) -> Theme {
+        self.0.theme()
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -253,6 +276,10 @@ where
         }
     }
 
+    fn theme(&self) -> Theme {
+        self.0.theme()
+    }
+
     fn subscription(&self) -> Subscription<Self::Message> {
         self.0.subscription()
     }
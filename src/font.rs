@@ -0,0 +1,40 @@
+//! Describe fonts and load them at runtime.
+pub use crate::runtime::font::{Descriptor, Family, Stretch, Style, Weight};
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::renderer::font::Error;
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::Command;
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::borrow::Cow;
+
+/// Loads the given font bytes at runtime.
+///
+/// The bytes can be borrowed for the whole program or owned, like the ones
+/// of a file read at runtime. They are handed to the renderer as is, without
+/// copying them.
+///
+/// The resulting [`Command`] produces the [`Descriptor`] of the font, read
+/// from its data, if the renderer could load it. Once loaded, the font can be
+/// used with that [`Descriptor`], or any other matching its family and
+/// attributes. It is also used as a fallback for the glyphs missing in other
+/// fonts, and the layout of the application is computed again to account for
+/// it.
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Descriptor`]: struct.Descriptor.html
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load(
+    bytes: impl Into<Cow<'static, [u8]>>,
+) -> Command<Result<Descriptor, Error>> {
+    let bytes = bytes.into();
+
+    Command::with_renderer(move |renderer| {
+        match renderer.downcast_mut::<crate::renderer::Renderer>() {
+            Some(renderer) => renderer.load_font(bytes),
+            None => Err(Error::Unsupported),
+        }
+    })
+}
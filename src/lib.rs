@@ -184,6 +184,7 @@ mod element;
 mod sandbox;
 
 pub mod executor;
+pub mod font;
 pub mod keyboard;
 pub mod mouse;
 pub mod settings;
@@ -230,6 +231,8 @@ pub use sandbox::Sandbox;
 pub use settings::Settings;
 
 pub use runtime::{
-    futures, Align, Background, Color, Command, Font, HorizontalAlignment,
-    Length, Point, Rectangle, Size, Subscription, Vector, VerticalAlignment,
+    futures, gradient, theme, Align, Background, BorderRadius, BorderWidth,
+    Color, Command, ContentFit, Font, Gradient, HorizontalAlignment, Length,
+    LineHeight, Overflow, Point, Rectangle, Shadow, Size, Subscription, Theme,
+    Vector, VerticalAlignment, Wrapping,
 };
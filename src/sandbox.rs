@@ -1,4 +1,6 @@
-use crate::{executor, Application, Command, Element, Settings, Subscription};
+use crate::{
+    executor, Application, Command, Element, Settings, Subscription, Theme,
+};
 
 /// A sandboxed [`Application`].
 ///
@@ -124,6 +126,17 @@ pub trait Sandbox {
     /// [`Sandbox`]: trait.Sandbox.html
     fn view(&mut self) -> Element<'_, Self::Message>;
 
+    /// Returns the current [`Theme`] of the [`Sandbox`].
+    ///
+    /// By default, it returns [`Theme::LIGHT`].
+    ///
+    /// [`Theme`]: theme/struct.Theme.html
+    /// [`Theme::LIGHT`]: theme/struct.Theme.html#associatedconstant.LIGHT
+    /// [`Sandbox`]: trait.Sandbox.html
+    fn theme(&self) -> Theme {
+        Theme::LIGHT
+    }
+
     /// Runs the [`Sandbox`].
     ///
     /// On native platforms, this method will take control of the current thread
@@ -169,4 +182,8 @@ where
     fn view(&mut self) -> Element<'_, T::Message> {
         T::view(self)
     }
+
+    fn theme(&self) -> Theme {
+        T::theme(self)
+    }
 }
@@ -1,8 +1,9 @@
 //! Configure your application.
 use crate::window;
+use std::borrow::Cow;
 
 /// The settings of an application.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct Settings<Flags> {
     /// The window settings.
     ///
@@ -18,9 +19,12 @@ pub struct Settings<Flags> {
 
     /// The bytes of the font that will be used by default.
     ///
+    /// The bytes can be borrowed for the whole program, like the ones of
+    /// `include_bytes!`, or owned, like the ones of a file read at runtime.
+    ///
     /// If `None` is provided, a default system font will be chosen.
     // TODO: Add `name` for web compatibility
-    pub default_font: Option<&'static [u8]>,
+    pub default_font: Option<Cow<'static, [u8]>>,
 
     /// If set to true, the renderer will try to perform antialiasing for some
     /// primitives.
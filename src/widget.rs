@@ -19,8 +19,9 @@
 #[cfg(not(target_arch = "wasm32"))]
 mod platform {
     pub use crate::renderer::widget::{
-        button, checkbox, container, pane_grid, progress_bar, radio,
-        scrollable, slider, text_input, Column, Row, Space, Text,
+        button, checkbox, container, list, number_input, pane_grid,
+        progress_bar, radio, rich_text, scrollable, slider, text_editor,
+        text_input, Column, Row, Space, Text,
     };
 
     #[cfg(any(feature = "canvas", feature = "glow_canvas"))]
@@ -33,7 +34,13 @@ mod platform {
     #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
     pub mod image {
         //! Display images in your user interface.
-        pub use crate::runtime::image::{Handle, Image};
+        pub use crate::runtime::image::{FilterMethod, Handle, Image, Stream};
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+    pub mod animated_image {
+        //! Display animated images, like GIFs, in your user interface.
+        pub use crate::runtime::animated_image::{AnimatedImage, State};
     }
 
     #[cfg_attr(docsrs, doc(cfg(feature = "svg")))]
@@ -44,10 +51,12 @@ mod platform {
 
     #[doc(no_inline)]
     pub use {
-        button::Button, checkbox::Checkbox, container::Container, image::Image,
-        pane_grid::PaneGrid, progress_bar::ProgressBar, radio::Radio,
+        animated_image::AnimatedImage, button::Button, checkbox::Checkbox,
+        container::Container, image::Image, list::List,
+        number_input::NumberInput, pane_grid::PaneGrid,
+        progress_bar::ProgressBar, radio::Radio, rich_text::RichText,
         scrollable::Scrollable, slider::Slider, svg::Svg,
-        text_input::TextInput,
+        text_editor::TextEditor, text_input::TextInput,
     };
 
     #[cfg(feature = "canvas")]
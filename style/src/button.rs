@@ -1,14 +1,16 @@
 //! Allow your users to perform actions by pressing a button.
-use iced_core::{Background, Color, Vector};
+use crate::Theme;
+use iced_core::{Background, BorderRadius, BorderWidth, Color, Shadow, Vector};
 
 /// The appearance of a button.
 #[derive(Debug)]
 pub struct Style {
     pub shadow_offset: Vector,
     pub background: Option<Background>,
-    pub border_radius: u16,
-    pub border_width: u16,
+    pub border_radius: BorderRadius,
+    pub border_width: BorderWidth,
     pub border_color: Color,
+    pub shadow: Shadow,
     pub text_color: Color,
 }
 
@@ -17,9 +19,10 @@ impl std::default::Default for Style {
         Self {
             shadow_offset: Vector::default(),
             background: None,
-            border_radius: 0,
-            border_width: 0,
+            border_radius: 0.into(),
+            border_width: 0.into(),
             border_color: Color::TRANSPARENT,
+            shadow: Shadow::NONE,
             text_color: Color::BLACK,
         }
     }
@@ -27,10 +30,10 @@ impl std::default::Default for Style {
 
 /// A set of rules that dictate the style of a button.
 pub trait StyleSheet {
-    fn active(&self) -> Style;
+    fn active(&self, theme: &Theme) -> Style;
 
-    fn hovered(&self) -> Style {
-        let active = self.active();
+    fn hovered(&self, theme: &Theme) -> Style {
+        let active = self.active(theme);
 
         Style {
             shadow_offset: active.shadow_offset + Vector::new(0.0, 1.0),
@@ -38,23 +41,30 @@ pub trait StyleSheet {
         }
     }
 
-    fn pressed(&self) -> Style {
+    fn pressed(&self, theme: &Theme) -> Style {
         Style {
             shadow_offset: Vector::default(),
-            ..self.active()
+            ..self.active(theme)
         }
     }
 
-    fn disabled(&self) -> Style {
-        let active = self.active();
+    fn disabled(&self, theme: &Theme) -> Style {
+        let active = self.active(theme);
 
         Style {
             shadow_offset: Vector::default(),
+            shadow: Shadow::NONE,
             background: active.background.map(|background| match background {
                 Background::Color(color) => Background::Color(Color {
                     a: color.a * 0.5,
                     ..color
                 }),
+                Background::Gradient(gradient) => {
+                    Background::Gradient(gradient.map_colors(|color| Color {
+                        a: color.a * 0.5,
+                        ..color
+                    }))
+                }
             }),
             text_color: Color {
                 a: active.text_color.a * 0.5,
@@ -68,14 +78,15 @@ pub trait StyleSheet {
 struct Default;
 
 impl StyleSheet for Default {
-    fn active(&self) -> Style {
+    fn active(&self, theme: &Theme) -> Style {
         Style {
             shadow_offset: Vector::new(0.0, 0.0),
-            background: Some(Background::Color([0.87, 0.87, 0.87].into())),
-            border_radius: 2,
-            border_width: 1,
-            border_color: [0.7, 0.7, 0.7].into(),
-            text_color: Color::BLACK,
+            background: Some(Background::Color(theme.shade(0.13))),
+            border_radius: 2.into(),
+            border_width: 1.into(),
+            border_color: theme.shade(0.3),
+            shadow: Shadow::NONE,
+            text_color: theme.palette.text,
         }
     }
 }
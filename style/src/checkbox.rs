@@ -1,40 +1,41 @@
 //! Show toggle controls using checkboxes.
-use iced_core::{Background, Color};
+use crate::Theme;
+use iced_core::{Background, BorderRadius, BorderWidth, Color};
 
 /// The appearance of a checkbox.
 #[derive(Debug)]
 pub struct Style {
     pub background: Background,
     pub checkmark_color: Color,
-    pub border_radius: u16,
-    pub border_width: u16,
+    pub border_radius: BorderRadius,
+    pub border_width: BorderWidth,
     pub border_color: Color,
 }
 
 /// A set of rules that dictate the style of a checkbox.
 pub trait StyleSheet {
-    fn active(&self, is_checked: bool) -> Style;
+    fn active(&self, theme: &Theme, is_checked: bool) -> Style;
 
-    fn hovered(&self, is_checked: bool) -> Style;
+    fn hovered(&self, theme: &Theme, is_checked: bool) -> Style;
 }
 
 struct Default;
 
 impl StyleSheet for Default {
-    fn active(&self, _is_checked: bool) -> Style {
+    fn active(&self, theme: &Theme, _is_checked: bool) -> Style {
         Style {
-            background: Background::Color(Color::from_rgb(0.95, 0.95, 0.95)),
-            checkmark_color: Color::from_rgb(0.3, 0.3, 0.3),
-            border_radius: 5,
-            border_width: 1,
-            border_color: Color::from_rgb(0.6, 0.6, 0.6),
+            background: Background::Color(theme.shade(0.05)),
+            checkmark_color: theme.shade(0.7),
+            border_radius: 5.into(),
+            border_width: 1.into(),
+            border_color: theme.shade(0.4),
         }
     }
 
-    fn hovered(&self, is_checked: bool) -> Style {
+    fn hovered(&self, theme: &Theme, is_checked: bool) -> Style {
         Style {
-            background: Background::Color(Color::from_rgb(0.90, 0.90, 0.90)),
-            ..self.active(is_checked)
+            background: Background::Color(theme.shade(0.1)),
+            ..self.active(theme, is_checked)
         }
     }
 }
@@ -5,8 +5,13 @@
 pub mod button;
 pub mod checkbox;
 pub mod container;
+pub mod number_input;
 pub mod progress_bar;
 pub mod radio;
 pub mod scrollable;
 pub mod slider;
+pub mod text_editor;
 pub mod text_input;
+pub mod theme;
+
+pub use theme::Theme;
@@ -0,0 +1,94 @@
+//! Let your users type or step through numbers.
+use crate::Theme;
+use iced_core::{Background, BorderRadius, BorderWidth, Color};
+
+/// The appearance of the buttons of a number input.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub button_background: Option<Background>,
+    pub button_border_radius: BorderRadius,
+    pub button_border_width: BorderWidth,
+    pub button_border_color: Color,
+    pub icon_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            button_background: None,
+            button_border_radius: 0.into(),
+            button_border_width: 0.into(),
+            button_border_color: Color::TRANSPARENT,
+            icon_color: Color::BLACK,
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a number input.
+pub trait StyleSheet {
+    /// Produces the style of an active button of a number input.
+    fn active(&self, theme: &Theme) -> Style;
+
+    /// Produces the style of an hovered button of a number input.
+    fn hovered(&self, theme: &Theme) -> Style {
+        self.active(theme)
+    }
+
+    /// Produces the style of a button of a number input that cannot step
+    /// the value any further.
+    fn disabled(&self, theme: &Theme) -> Style {
+        let active = self.active(theme);
+
+        Style {
+            icon_color: Color {
+                a: active.icon_color.a * 0.5,
+                ..active.icon_color
+            },
+            ..active
+        }
+    }
+
+    /// Produces the color of the border drawn around a number input while
+    /// its text is not a valid number.
+    fn invalid_color(&self, theme: &Theme) -> Color;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self, theme: &Theme) -> Style {
+        Style {
+            button_background: Some(Background::Color(theme.shade(0.13))),
+            button_border_radius: 2.into(),
+            button_border_width: 1.into(),
+            button_border_color: theme.shade(0.3),
+            icon_color: theme.palette.text,
+        }
+    }
+
+    fn hovered(&self, theme: &Theme) -> Style {
+        Style {
+            button_background: Some(Background::Color(theme.shade(0.2))),
+            ..self.active(theme)
+        }
+    }
+
+    fn invalid_color(&self, theme: &Theme) -> Color {
+        theme.palette.danger
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -1,27 +1,28 @@
 //! Provide progress feedback to your users.
-use iced_core::{Background, Color};
+use crate::Theme;
+use iced_core::{Background, BorderRadius};
 
 /// The appearance of a progress bar.
 #[derive(Debug)]
 pub struct Style {
     pub background: Background,
     pub bar: Background,
-    pub border_radius: u16,
+    pub border_radius: BorderRadius,
 }
 
 /// A set of rules that dictate the style of a progress bar.
 pub trait StyleSheet {
-    fn style(&self) -> Style;
+    fn style(&self, theme: &Theme) -> Style;
 }
 
 struct Default;
 
 impl StyleSheet for Default {
-    fn style(&self) -> Style {
+    fn style(&self, theme: &Theme) -> Style {
         Style {
-            background: Background::Color(Color::from_rgb(0.6, 0.6, 0.6)),
-            bar: Background::Color(Color::from_rgb(0.3, 0.9, 0.3)),
-            border_radius: 5,
+            background: Background::Color(theme.shade(0.4)),
+            bar: Background::Color(theme.palette.primary),
+            border_radius: 5.into(),
         }
     }
 }
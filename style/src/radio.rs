@@ -1,38 +1,39 @@
 //! Create choices using radio buttons.
-use iced_core::{Background, Color};
+use crate::Theme;
+use iced_core::{Background, BorderWidth, Color};
 
 /// The appearance of a radio button.
 #[derive(Debug)]
 pub struct Style {
     pub background: Background,
     pub dot_color: Color,
-    pub border_width: u16,
+    pub border_width: BorderWidth,
     pub border_color: Color,
 }
 
 /// A set of rules that dictate the style of a radio button.
 pub trait StyleSheet {
-    fn active(&self) -> Style;
+    fn active(&self, theme: &Theme) -> Style;
 
-    fn hovered(&self) -> Style;
+    fn hovered(&self, theme: &Theme) -> Style;
 }
 
 struct Default;
 
 impl StyleSheet for Default {
-    fn active(&self) -> Style {
+    fn active(&self, theme: &Theme) -> Style {
         Style {
-            background: Background::Color(Color::from_rgb(0.95, 0.95, 0.95)),
-            dot_color: Color::from_rgb(0.3, 0.3, 0.3),
-            border_width: 1,
-            border_color: Color::from_rgb(0.6, 0.6, 0.6),
+            background: Background::Color(theme.shade(0.05)),
+            dot_color: theme.shade(0.7),
+            border_width: 1.into(),
+            border_color: theme.shade(0.4),
         }
     }
 
-    fn hovered(&self) -> Style {
+    fn hovered(&self, theme: &Theme) -> Style {
         Style {
-            background: Background::Color(Color::from_rgb(0.90, 0.90, 0.90)),
-            ..self.active()
+            background: Background::Color(theme.shade(0.1)),
+            ..self.active(theme)
         }
     }
 }
@@ -1,12 +1,13 @@
 //! Navigate an endless amount of content with a scrollbar.
-use iced_core::{Background, Color};
+use crate::Theme;
+use iced_core::{Background, BorderRadius, BorderWidth, Color};
 
 /// The appearance of a scrollable.
 #[derive(Debug, Clone, Copy)]
 pub struct Scrollbar {
     pub background: Option<Background>,
-    pub border_radius: u16,
-    pub border_width: u16,
+    pub border_radius: BorderRadius,
+    pub border_width: BorderWidth,
     pub border_color: Color,
     pub scroller: Scroller,
 }
@@ -15,47 +16,68 @@ pub struct Scrollbar {
 #[derive(Debug, Clone, Copy)]
 pub struct Scroller {
     pub color: Color,
-    pub border_radius: u16,
-    pub border_width: u16,
+    pub border_radius: BorderRadius,
+    pub border_width: BorderWidth,
     pub border_color: Color,
 }
 
 /// A set of rules that dictate the style of a scrollable.
 pub trait StyleSheet {
     /// Produces the style of an active scrollbar.
-    fn active(&self) -> Scrollbar;
+    fn active(&self, theme: &Theme) -> Scrollbar;
 
     /// Produces the style of an hovered scrollbar.
-    fn hovered(&self) -> Scrollbar;
+    fn hovered(&self, theme: &Theme) -> Scrollbar;
 
     /// Produces the style of a scrollbar that is being dragged.
-    fn dragging(&self) -> Scrollbar {
-        self.hovered()
+    fn dragging(&self, theme: &Theme) -> Scrollbar {
+        self.hovered(theme)
+    }
+
+    /// Produces the style of an active horizontal scrollbar.
+    fn active_horizontal(&self, theme: &Theme) -> Scrollbar {
+        self.active(theme)
+    }
+
+    /// Produces the style of an hovered horizontal scrollbar.
+    fn hovered_horizontal(&self, theme: &Theme) -> Scrollbar {
+        self.hovered(theme)
+    }
+
+    /// Produces the style of a horizontal scrollbar that is being dragged.
+    fn dragging_horizontal(&self, theme: &Theme) -> Scrollbar {
+        self.hovered_horizontal(theme)
     }
 }
 
 struct Default;
 
 impl StyleSheet for Default {
-    fn active(&self) -> Scrollbar {
+    fn active(&self, theme: &Theme) -> Scrollbar {
         Scrollbar {
             background: None,
-            border_radius: 5,
-            border_width: 0,
+            border_radius: 5.into(),
+            border_width: 0.into(),
             border_color: Color::TRANSPARENT,
             scroller: Scroller {
-                color: [0.0, 0.0, 0.0, 0.7].into(),
-                border_radius: 5,
-                border_width: 0,
+                color: Color {
+                    a: 0.7,
+                    ..theme.palette.text
+                },
+                border_radius: 5.into(),
+                border_width: 0.into(),
                 border_color: Color::TRANSPARENT,
             },
         }
     }
 
-    fn hovered(&self) -> Scrollbar {
+    fn hovered(&self, theme: &Theme) -> Scrollbar {
         Scrollbar {
-            background: Some(Background::Color([0.0, 0.0, 0.0, 0.3].into())),
-            ..self.active()
+            background: Some(Background::Color(Color {
+                a: 0.3,
+                ..theme.palette.text
+            })),
+            ..self.active(theme)
         }
     }
 }
@@ -1,5 +1,6 @@
 //! Display an interactive selector of a single value from a range of values.
-use iced_core::Color;
+use crate::Theme;
+use iced_core::{BorderRadius, BorderWidth, Color};
 
 /// The appearance of a slider.
 #[derive(Debug, Clone, Copy)]
@@ -13,65 +14,76 @@ pub struct Style {
 pub struct Handle {
     pub shape: HandleShape,
     pub color: Color,
-    pub border_width: u16,
+    pub border_width: BorderWidth,
     pub border_color: Color,
 }
 
 /// The shape of the handle of a slider.
 #[derive(Debug, Clone, Copy)]
 pub enum HandleShape {
-    Circle { radius: u16 },
-    Rectangle { width: u16, border_radius: u16 },
+    Circle {
+        radius: u16,
+    },
+    Rectangle {
+        width: u16,
+        border_radius: BorderRadius,
+    },
 }
 
 /// A set of rules that dictate the style of a slider.
 pub trait StyleSheet {
     /// Produces the style of an active slider.
-    fn active(&self) -> Style;
+    fn active(&self, theme: &Theme) -> Style;
 
     /// Produces the style of an hovered slider.
-    fn hovered(&self) -> Style;
+    fn hovered(&self, theme: &Theme) -> Style;
 
     /// Produces the style of a slider that is being dragged.
-    fn dragging(&self) -> Style;
+    fn dragging(&self, theme: &Theme) -> Style;
 }
 
 struct Default;
 
 impl StyleSheet for Default {
-    fn active(&self) -> Style {
+    fn active(&self, theme: &Theme) -> Style {
         Style {
-            rail_colors: ([0.6, 0.6, 0.6, 0.5].into(), Color::WHITE),
+            rail_colors: (
+                Color {
+                    a: 0.5,
+                    ..theme.shade(0.4)
+                },
+                theme.palette.background,
+            ),
             handle: Handle {
                 shape: HandleShape::Rectangle {
                     width: 8,
-                    border_radius: 4,
+                    border_radius: 4.into(),
                 },
-                color: Color::from_rgb(0.95, 0.95, 0.95),
-                border_color: Color::from_rgb(0.6, 0.6, 0.6),
-                border_width: 1,
+                color: theme.shade(0.05),
+                border_color: theme.shade(0.4),
+                border_width: 1.into(),
             },
         }
     }
 
-    fn hovered(&self) -> Style {
-        let active = self.active();
+    fn hovered(&self, theme: &Theme) -> Style {
+        let active = self.active(theme);
 
         Style {
             handle: Handle {
-                color: Color::from_rgb(0.90, 0.90, 0.90),
+                color: theme.shade(0.1),
                 ..active.handle
             },
             ..active
         }
     }
 
-    fn dragging(&self) -> Style {
-        let active = self.active();
+    fn dragging(&self, theme: &Theme) -> Style {
+        let active = self.active(theme);
 
         Style {
             handle: Handle {
-                color: Color::from_rgb(0.85, 0.85, 0.85),
+                color: theme.shade(0.15),
                 ..active.handle
             },
             ..active
@@ -0,0 +1,93 @@
+//! Display fields that can be filled with multiple lines of text.
+use crate::theme::{self, Theme};
+use iced_core::{Background, BorderRadius, BorderWidth, Color, Shadow};
+
+/// The appearance of a text editor.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub background: Background,
+    pub border_radius: BorderRadius,
+    pub border_width: BorderWidth,
+    pub border_color: Color,
+    pub shadow: Shadow,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            background: Background::Color(Color::WHITE),
+            border_radius: 0.into(),
+            border_width: 0.into(),
+            border_color: Color::TRANSPARENT,
+            shadow: Shadow::NONE,
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a text editor.
+pub trait StyleSheet {
+    /// Produces the style of an active text editor.
+    fn active(&self, theme: &Theme) -> Style;
+
+    /// Produces the style of a focused text editor.
+    fn focused(&self, theme: &Theme) -> Style;
+
+    fn placeholder_color(&self, theme: &Theme) -> Color;
+
+    fn value_color(&self, theme: &Theme) -> Color;
+
+    fn selection_color(&self, theme: &Theme) -> Color;
+
+    /// Produces the style of an hovered text editor.
+    fn hovered(&self, theme: &Theme) -> Style {
+        self.focused(theme)
+    }
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self, theme: &Theme) -> Style {
+        Style {
+            background: Background::Color(theme.palette.background),
+            border_radius: 5.into(),
+            border_width: 1.into(),
+            border_color: theme.shade(0.3),
+            shadow: Shadow::NONE,
+        }
+    }
+
+    fn focused(&self, theme: &Theme) -> Style {
+        Style {
+            border_color: theme.shade(0.5),
+            ..self.active(theme)
+        }
+    }
+
+    fn placeholder_color(&self, theme: &Theme) -> Color {
+        theme.shade(0.3)
+    }
+
+    fn value_color(&self, theme: &Theme) -> Color {
+        theme.shade(0.7)
+    }
+
+    fn selection_color(&self, theme: &Theme) -> Color {
+        theme::mix(theme.palette.background, theme.palette.primary, 0.3)
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -1,22 +1,25 @@
 //! Display fields that can be filled with text.
-use iced_core::{Background, Color};
+use crate::theme::{self, Theme};
+use iced_core::{Background, BorderRadius, BorderWidth, Color, Shadow};
 
 /// The appearance of a text input.
 #[derive(Debug, Clone, Copy)]
 pub struct Style {
     pub background: Background,
-    pub border_radius: u16,
-    pub border_width: u16,
+    pub border_radius: BorderRadius,
+    pub border_width: BorderWidth,
     pub border_color: Color,
+    pub shadow: Shadow,
 }
 
 impl std::default::Default for Style {
     fn default() -> Self {
         Self {
             background: Background::Color(Color::WHITE),
-            border_radius: 0,
-            border_width: 0,
+            border_radius: 0.into(),
+            border_width: 0.into(),
             border_color: Color::TRANSPARENT,
+            shadow: Shadow::NONE,
         }
     }
 }
@@ -24,52 +27,53 @@ impl std::default::Default for Style {
 /// A set of rules that dictate the style of a text input.
 pub trait StyleSheet {
     /// Produces the style of an active text input.
-    fn active(&self) -> Style;
+    fn active(&self, theme: &Theme) -> Style;
 
     /// Produces the style of a focused text input.
-    fn focused(&self) -> Style;
+    fn focused(&self, theme: &Theme) -> Style;
 
-    fn placeholder_color(&self) -> Color;
+    fn placeholder_color(&self, theme: &Theme) -> Color;
 
-    fn value_color(&self) -> Color;
+    fn value_color(&self, theme: &Theme) -> Color;
 
-    fn selection_color(&self) -> Color;
+    fn selection_color(&self, theme: &Theme) -> Color;
 
     /// Produces the style of an hovered text input.
-    fn hovered(&self) -> Style {
-        self.focused()
+    fn hovered(&self, theme: &Theme) -> Style {
+        self.focused(theme)
     }
 }
 
 struct Default;
 
 impl StyleSheet for Default {
-    fn active(&self) -> Style {
+    fn active(&self, theme: &Theme) -> Style {
         Style {
-            background: Background::Color(Color::WHITE),
-            border_radius: 5,
-            border_width: 1,
-            border_color: Color::from_rgb(0.7, 0.7, 0.7),
+            background: Background::Color(theme.palette.background),
+            border_radius: 5.into(),
+            border_width: 1.into(),
+            border_color: theme.shade(0.3),
+            shadow: Shadow::NONE,
         }
     }
 
-    fn focused(&self) -> Style {
+    fn focused(&self, theme: &Theme) -> Style {
         Style {
-            border_color: Color::from_rgb(0.5, 0.5, 0.5),
-            ..self.active()
+            border_color: theme.shade(0.5),
+            ..self.active(theme)
         }
     }
 
-    fn placeholder_color(&self) -> Color {
-        Color::from_rgb(0.7, 0.7, 0.7)
+    fn placeholder_color(&self, theme: &Theme) -> Color {
+        theme.shade(0.3)
     }
 
-    fn value_color(&self) -> Color {
-        Color::from_rgb(0.3, 0.3, 0.3)
+    fn value_color(&self, theme: &Theme) -> Color {
+        theme.shade(0.7)
     }
 
-    fn selection_color(&self) -> Color {
-        Color::from_rgb(0.8, 0.8, 1.0)
+    fn selection_color(&self, theme: &Theme) -> Color {
+        theme::mix(theme.palette.background, theme.palette.primary, 0.3)
     }
 }
 
@@ -0,0 +1,115 @@
+//! Use the built-in themes or create your own.
+use iced_core::Color;
+
+/// A set of colors that every default style sheet derives its colors from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Palette {
+    /// The background color of the application.
+    pub background: Color,
+
+    /// The color of text.
+    pub text: Color,
+
+    /// The color of primary, interactive elements.
+    pub primary: Color,
+
+    /// The color used to signal success.
+    pub success: Color,
+
+    /// The color used to signal danger.
+    pub danger: Color,
+}
+
+/// The colors of an application.
+///
+/// The default style sheets of every widget produce their styles from the
+/// active [`Theme`].
+///
+/// [`Theme`]: struct.Theme.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    /// The [`Palette`] of the [`Theme`].
+    ///
+    /// [`Palette`]: struct.Palette.html
+    /// [`Theme`]: struct.Theme.html
+    pub palette: Palette,
+}
+
+impl Theme {
+    /// The built-in light [`Theme`].
+    ///
+    /// [`Theme`]: struct.Theme.html
+    pub const LIGHT: Theme = Theme {
+        palette: Palette {
+            background: Color::WHITE,
+            text: Color::BLACK,
+            primary: Color::from_rgb(0.36, 0.49, 0.89),
+            success: Color::from_rgb(0.3, 0.9, 0.3),
+            danger: Color::from_rgb(0.8, 0.2, 0.2),
+        },
+    };
+
+    /// The built-in dark [`Theme`].
+    ///
+    /// [`Theme`]: struct.Theme.html
+    pub const DARK: Theme = Theme {
+        palette: Palette {
+            background: Color::from_rgb(
+                0x36 as f32 / 255.0,
+                0x39 as f32 / 255.0,
+                0x3F as f32 / 255.0,
+            ),
+            text: Color::from_rgb(0.9, 0.9, 0.9),
+            primary: Color::from_rgb(
+                0x72 as f32 / 255.0,
+                0x89 as f32 / 255.0,
+                0xDA as f32 / 255.0,
+            ),
+            success: Color::from_rgb(
+                0x3B as f32 / 255.0,
+                0xA5 as f32 / 255.0,
+                0x5C as f32 / 255.0,
+            ),
+            danger: Color::from_rgb(
+                0xC3 as f32 / 255.0,
+                0x42 as f32 / 255.0,
+                0x3F as f32 / 255.0,
+            ),
+        },
+    };
+
+    /// Creates a new [`Theme`] with the given [`Palette`].
+    ///
+    /// [`Theme`]: struct.Theme.html
+    /// [`Palette`]: struct.Palette.html
+    pub const fn new(palette: Palette) -> Theme {
+        Theme { palette }
+    }
+
+    /// Returns a color between the background and the text colors of the
+    /// [`Theme`].
+    ///
+    /// A `factor` of `0.0` produces the background color, while a `factor` of
+    /// `1.0` produces the text color.
+    ///
+    /// [`Theme`]: struct.Theme.html
+    pub fn shade(&self, factor: f32) -> Color {
+        mix(self.palette.background, self.palette.text, factor)
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Theme {
+        Theme::LIGHT
+    }
+}
+
+/// Linearly interpolates between two colors.
+pub(crate) fn mix(a: Color, b: Color, factor: f32) -> Color {
+    Color {
+        r: a.r + (b.r - a.r) * factor,
+        g: a.g + (b.g - a.g) * factor,
+        b: a.b + (b.b - a.b) * factor,
+        a: a.a + (b.a - a.a) * factor,
+    }
+}
@@ -1,5 +1,8 @@
 //! Style your widgets.
-use crate::{bumpalo, Align, Background, Color, Length};
+use crate::{
+    bumpalo, font, Align, Background, BorderRadius, BorderWidth, Color, Font,
+    Gradient, Length, Shadow, Theme,
+};
 
 use std::collections::BTreeMap;
 
@@ -78,18 +81,29 @@ impl Rule {
 #[derive(Debug)]
 pub struct Css<'a> {
     rules: BTreeMap<String, &'a str>,
+    theme: Theme,
 }
 
 impl<'a> Css<'a> {
-    /// Creates an empty style [`Sheet`].
+    /// Creates an empty style [`Sheet`] for the given [`Theme`].
     ///
     /// [`Sheet`]: struct.Sheet.html
-    pub fn new() -> Self {
+    /// [`Theme`]: ../theme/struct.Theme.html
+    pub fn new(theme: Theme) -> Self {
         Css {
             rules: BTreeMap::new(),
+            theme,
         }
     }
 
+    /// Returns the [`Theme`] of the style [`Sheet`].
+    ///
+    /// [`Theme`]: ../theme/struct.Theme.html
+    /// [`Sheet`]: struct.Sheet.html
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
     /// Inserts the [`rule`] in the [`Sheet`], if it was not previously
     /// inserted.
     ///
@@ -117,7 +131,14 @@ impl<'a> Css<'a> {
 
         declarations.push(text("html { height: 100% }"));
         declarations.push(text(
-            "body { height: 100%; margin: 0; padding: 0; font-family: sans-serif }",
+            bumpalo::format!(
+                in bump,
+                "body {{ height: 100%; margin: 0; padding: 0; \
+                 font-family: sans-serif; background: {}; color: {} }}",
+                color(self.theme.palette.background),
+                color(self.theme.palette.text)
+            )
+            .into_bump_str(),
         ));
         declarations.push(text("* { margin: 0; padding: 0 }"));
         declarations.push(text(
@@ -163,6 +184,40 @@ pub fn min_length(units: u32) -> String {
     }
 }
 
+/// Returns the style declarations for the given [`Font`].
+///
+/// [`Font`]: ../enum.Font.html
+pub fn font(font: Font) -> String {
+    match font {
+        Font::Default => String::from("font-family: inherit"),
+        Font::External { name, .. } => format!("font-family: '{}'", name),
+        Font::Descriptor(descriptor) => {
+            let family = match descriptor.family {
+                font::Family::Name(name) => format!("'{}'", name),
+                font::Family::Serif => String::from("serif"),
+                font::Family::SansSerif => String::from("sans-serif"),
+                font::Family::Monospace => String::from("monospace"),
+                font::Family::Cursive => String::from("cursive"),
+                font::Family::Fantasy => String::from("fantasy"),
+            };
+
+            let style = match descriptor.style {
+                font::Style::Normal => "normal",
+                font::Style::Italic => "italic",
+                font::Style::Oblique => "oblique",
+            };
+
+            format!(
+                "font-family: {}; font-weight: {}; font-style: {}; font-stretch: {}%",
+                family,
+                descriptor.weight.to_number(),
+                style,
+                descriptor.stretch.to_percentage()
+            )
+        }
+    }
+}
+
 /// Returns the style value for the given [`Color`].
 ///
 /// [`Color`]: ../struct.Color.html
@@ -176,6 +231,71 @@ pub fn color(Color { r, g, b, a }: Color) -> String {
 pub fn background(background: Background) -> String {
     match background {
         Background::Color(c) => color(c),
+        Background::Gradient(g) => gradient(g),
+    }
+}
+
+/// Returns the style value for the given [`Gradient`].
+///
+/// [`Gradient`]: ../enum.Gradient.html
+pub fn gradient(gradient: Gradient) -> String {
+    match gradient {
+        Gradient::Linear(linear) => {
+            let stops: Vec<String> = linear
+                .stops()
+                .map(|stop| {
+                    format!("{} {}%", color(stop.color), stop.offset * 100.0)
+                })
+                .collect();
+
+            // CSS angles start pointing upwards, while ours start pointing
+            // to the right
+            format!(
+                "linear-gradient({}deg, {})",
+                linear.angle.to_degrees() + 90.0,
+                stops.join(", ")
+            )
+        }
+    }
+}
+
+/// Returns the style value for the given [`BorderRadius`].
+///
+/// [`BorderRadius`]: ../struct.BorderRadius.html
+pub fn border_radius(radius: BorderRadius) -> String {
+    format!(
+        "{}px {}px {}px {}px",
+        radius.top_left,
+        radius.top_right,
+        radius.bottom_right,
+        radius.bottom_left
+    )
+}
+
+/// Returns the style value for the given [`BorderWidth`].
+///
+/// [`BorderWidth`]: ../struct.BorderWidth.html
+pub fn border_width(width: BorderWidth) -> String {
+    format!(
+        "{}px {}px {}px {}px",
+        width.top, width.right, width.bottom, width.left
+    )
+}
+
+/// Returns the style value for the given [`Shadow`].
+///
+/// [`Shadow`]: ../struct.Shadow.html
+pub fn shadow(shadow: Shadow) -> String {
+    if shadow.is_visible() {
+        format!(
+            "{}px {}px {}px {}",
+            shadow.offset.x,
+            shadow.offset.y,
+            shadow.blur_radius,
+            color(shadow.color)
+        )
+    } else {
+        String::from("none")
     }
 }
 
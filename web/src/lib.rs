@@ -74,10 +74,13 @@ pub use dodrio;
 pub use element::Element;
 pub use hasher::Hasher;
 pub use iced_core::{
-    keyboard, mouse, Align, Background, Color, Font, HorizontalAlignment,
-    Length, Point, Rectangle, Size, Vector, VerticalAlignment,
+    font, gradient, keyboard, mouse, Align, Background, BorderRadius,
+    BorderWidth, Color, ContentFit, Font, Gradient, HorizontalAlignment,
+    Length, LineHeight, Overflow, Point, Rectangle, Shadow, Size, Vector,
+    VerticalAlignment, Wrapping,
 };
 pub use iced_futures::{executor, futures, Command};
+pub use iced_style::{theme, Theme};
 pub use subscription::Subscription;
 
 #[doc(no_inline)]
@@ -154,6 +157,16 @@ pub trait Application {
     /// [`Application`]: trait.Application.html
     fn view(&mut self) -> Element<'_, Self::Message>;
 
+    /// Returns the current [`Theme`] of the [`Application`].
+    ///
+    /// By default, it returns the light theme.
+    ///
+    /// [`Theme`]: theme/struct.Theme.html
+    /// [`Application`]: trait.Application.html
+    fn theme(&self) -> Theme {
+        Theme::default()
+    }
+
     /// Returns the event [`Subscription`] for the current state of the
     /// application.
     ///
@@ -252,8 +265,9 @@ where
         use dodrio::builder::*;
 
         let mut ui = self.application.borrow_mut();
+        let theme = ui.theme();
         let element = ui.view();
-        let mut css = Css::new();
+        let mut css = Css::new(theme);
 
         let node = element.widget.node(bump, &self.bus, &mut css);
 
@@ -4,7 +4,7 @@
 //!
 //! [`Button`]: struct.Button.html
 //! [`State`]: struct.State.html
-use crate::{css, Background, Bus, Css, Element, Length, Widget};
+use crate::{css, Bus, Css, Element, Length, Widget};
 
 pub use iced_style::button::{Style, StyleSheet};
 
@@ -142,16 +142,14 @@ where
         use dodrio::builder::*;
 
         // TODO: State-based styling
-        let style = self.style.active();
+        let style = self.style.active(style_sheet.theme());
 
         let padding_class =
             style_sheet.insert(bump, css::Rule::Padding(self.padding));
 
         let background = match style.background {
             None => String::from("none"),
-            Some(background) => match background {
-                Background::Color(color) => css::color(color),
-            },
+            Some(background) => css::background(background),
         };
 
         let mut node = button(bump)
@@ -163,9 +161,10 @@ where
                 "style",
                 bumpalo::format!(
                     in bump,
-                    "background: {}; border-radius: {}px; width:{}; min-width: {}; color: {}",
+                    "background: {}; border-radius: {}; box-shadow: {}; width:{}; min-width: {}; color: {}",
                     background,
-                    style.border_radius,
+                    css::border_radius(style.border_radius),
+                    css::shadow(style.shadow),
                     css::length(self.width),
                     css::min_length(self.min_width),
                     css::color(style.text_color)
@@ -126,7 +126,7 @@ where
         let padding_class =
             style_sheet.insert(bump, css::Rule::Padding(self.padding));
 
-        let style = self.style_sheet.style();
+        let style = self.style_sheet.style(style_sheet.theme());
 
         let node = div(bump)
             .attr(
@@ -137,7 +137,7 @@ where
                 "style",
                 bumpalo::format!(
                     in bump,
-                    "width: {}; height: {}; max-width: {}; align-items: {}; justify-content: {}; background: {}; color: {}; border-width: {}px; border-color: {}; border-radius: {}px",
+                    "width: {}; height: {}; max-width: {}; align-items: {}; justify-content: {}; background: {}; color: {}; border-width: {}; border-color: {}; border-radius: {}; box-shadow: {}",
                     css::length(self.width),
                     css::length(self.height),
                     css::max_length(self.max_width),
@@ -145,9 +145,10 @@ where
                     css::align(self.vertical_alignment),
                     style.background.map(css::background).unwrap_or(String::from("initial")),
                     style.text_color.map(css::color).unwrap_or(String::from("inherit")),
-                    style.border_width,
+                    css::border_width(style.border_width),
                     css::color(style.border_color),
-                    style.border_radius
+                    css::border_radius(style.border_radius),
+                    css::shadow(style.shadow)
                 )
                 .into_bump_str(),
             )
@@ -1,5 +1,5 @@
 //! Display images in your user interface.
-use crate::{Bus, Css, Element, Hasher, Length, Widget};
+use crate::{Bus, ContentFit, Css, Element, Hasher, Length, Widget};
 
 use dodrio::bumpalo;
 use std::{
@@ -8,7 +8,7 @@ use std::{
     sync::Arc,
 };
 
-/// A frame that displays an image while keeping aspect ratio.
+/// A frame that displays an image.
 ///
 /// # Example
 ///
@@ -27,6 +27,12 @@ pub struct Image {
 
     /// The height of the image
     pub height: Length,
+
+    /// The strategy used to fit the image into its boundaries
+    pub content_fit: ContentFit,
+
+    /// The filtering method used to sample the image when scaled
+    pub filter_method: FilterMethod,
 }
 
 impl Image {
@@ -38,6 +44,8 @@ impl Image {
             handle: handle.into(),
             width: Length::Shrink,
             height: Length::Shrink,
+            content_fit: ContentFit::default(),
+            filter_method: FilterMethod::default(),
         }
     }
 
@@ -56,6 +64,24 @@ impl Image {
         self.height = height;
         self
     }
+
+    /// Sets the [`ContentFit`] of the [`Image`].
+    ///
+    /// [`ContentFit`]: ../../enum.ContentFit.html
+    /// [`Image`]: struct.Image.html
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the [`FilterMethod`] used to sample the [`Image`] when scaled.
+    ///
+    /// [`FilterMethod`]: enum.FilterMethod.html
+    /// [`Image`]: struct.Image.html
+    pub fn filter_method(mut self, filter_method: FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
 }
 
 impl<Message> Widget<Message> for Image {
@@ -86,12 +112,65 @@ impl<Message> Widget<Message> for Image {
             }
         }
 
-        // TODO: Complete styling
+        match self.height {
+            Length::Shrink => {}
+            Length::Fill | Length::FillPortion(_) => {
+                image = image.attr("height", "100%");
+            }
+            Length::Units(px) => {
+                image = image.attr(
+                    "height",
+                    bumpalo::format!(in bump, "{}px", px).into_bump_str(),
+                );
+            }
+        }
+
+        let object_fit = match self.content_fit {
+            ContentFit::Contain => "contain",
+            ContentFit::Cover => "cover",
+            ContentFit::Fill => "fill",
+            ContentFit::None => "none",
+            ContentFit::ScaleDown => "scale-down",
+        };
+
+        let image_rendering = match self.filter_method {
+            FilterMethod::Linear => "auto",
+            FilterMethod::Nearest => "pixelated",
+        };
+
+        image = image.attr(
+            "style",
+            bumpalo::format!(
+                in bump,
+                "object-fit: {}; image-rendering: {}",
+                object_fit,
+                image_rendering
+            )
+            .into_bump_str(),
+        );
 
         image.finish()
     }
 }
 
+/// The filtering method used to sample an [`Image`] when it is scaled.
+///
+/// [`Image`]: struct.Image.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FilterMethod {
+    /// Bilinear interpolation. Smooth, but blurry when magnified.
+    Linear,
+
+    /// Nearest-neighbor interpolation. Keeps pixel art crisp.
+    Nearest,
+}
+
+impl Default for FilterMethod {
+    fn default() -> Self {
+        FilterMethod::Linear
+    }
+}
+
 impl<'a, Message> From<Image> for Element<'a, Message> {
     fn from(image: Image) -> Element<'a, Message> {
         Element::new(image)
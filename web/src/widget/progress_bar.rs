@@ -74,7 +74,7 @@ impl<Message> Widget<Message> for ProgressBar {
         &self,
         bump: &'b bumpalo::Bump,
         _bus: &Bus<Message>,
-        _style_sheet: &mut Css<'b>,
+        style_sheet: &mut Css<'b>,
     ) -> dodrio::Node<'b> {
         use dodrio::builder::*;
 
@@ -82,7 +82,7 @@ impl<Message> Widget<Message> for ProgressBar {
         let amount_filled =
             (self.value - range_start) / (range_end - range_start).max(1.0);
 
-        let style = self.style.style();
+        let style = self.style.style(style_sheet.theme());
 
         let bar = div(bump)
             .attr(
@@ -101,11 +101,11 @@ impl<Message> Widget<Message> for ProgressBar {
             "style",
             bumpalo::format!(
                 in bump,
-                "width: {}; height: {}; background: {}; border-radius: {}px; overflow: hidden;",
+                "width: {}; height: {}; background: {}; border-radius: {}; overflow: hidden;",
                 css::length(self.width),
                 css::length(self.height.unwrap_or(Length::Units(30))),
                 css::background(style.background),
-                style.border_radius
+                css::border_radius(style.border_radius)
             )
             .into_bump_str(),
         ).children(vec![bar]);
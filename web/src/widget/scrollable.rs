@@ -3,10 +3,11 @@ use crate::{bumpalo, css, Align, Bus, Column, Css, Element, Length, Widget};
 
 pub use iced_style::scrollable::{Scrollbar, Scroller, StyleSheet};
 
-/// A widget that can vertically display an infinite amount of content with a
-/// scrollbar.
+/// A widget that can display an infinite amount of content with
+/// scrollbars, vertically, horizontally or in both directions.
 #[allow(missing_debug_implementations)]
 pub struct Scrollable<'a, Message> {
+    direction: Direction,
     width: Length,
     height: Length,
     max_height: u32,
@@ -23,6 +24,7 @@ impl<'a, Message> Scrollable<'a, Message> {
         use std::u32;
 
         Scrollable {
+            direction: Direction::Vertical,
             width: Length::Fill,
             height: Length::Shrink,
             max_height: u32::MAX,
@@ -49,6 +51,17 @@ impl<'a, Message> Scrollable<'a, Message> {
         self
     }
 
+    /// Sets the [`Direction`] in which the [`Scrollable`] can be scrolled.
+    ///
+    /// By default, a [`Scrollable`] only scrolls vertically.
+    ///
+    /// [`Direction`]: enum.Direction.html
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
     /// Sets the width of the [`Scrollable`].
     ///
     /// [`Scrollable`]: struct.Scrollable.html
@@ -124,6 +137,12 @@ where
         let width = css::length(self.width);
         let height = css::length(self.height);
 
+        let (overflow_x, overflow_y) = match self.direction {
+            Direction::Vertical => ("hidden", "auto"),
+            Direction::Horizontal => ("auto", "hidden"),
+            Direction::Both => ("auto", "auto"),
+        };
+
         // TODO: Scrollbar styling
 
         let node = div(bump)
@@ -131,10 +150,12 @@ where
                 "style",
                 bumpalo::format!(
                     in bump,
-                    "width: {}; height: {}; max-height: {}px; overflow: auto",
+                    "width: {}; height: {}; max-height: {}px; overflow-x: {}; overflow-y: {}",
                     width,
                     height,
-                    self.max_height
+                    self.max_height,
+                    overflow_x,
+                    overflow_y
                 )
                 .into_bump_str(),
             )
@@ -153,6 +174,27 @@ where
     }
 }
 
+/// The directions in which a [`Scrollable`] can be scrolled.
+///
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Scroll vertically only.
+    Vertical,
+
+    /// Scroll horizontally only.
+    Horizontal,
+
+    /// Scroll both vertically and horizontally.
+    Both,
+}
+
+impl Default for Direction {
+    fn default() -> Self {
+        Direction::Vertical
+    }
+}
+
 /// The local state of a [`Scrollable`].
 ///
 /// [`Scrollable`]: struct.Scrollable.html
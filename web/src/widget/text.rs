@@ -1,6 +1,6 @@
 use crate::{
     css, Bus, Color, Css, Element, Font, HorizontalAlignment, Length,
-    VerticalAlignment, Widget,
+    LineHeight, Overflow, VerticalAlignment, Widget, Wrapping,
 };
 use dodrio::bumpalo;
 
@@ -24,6 +24,9 @@ pub struct Text {
     height: Length,
     horizontal_alignment: HorizontalAlignment,
     vertical_alignment: VerticalAlignment,
+    line_height: LineHeight,
+    wrapping: Wrapping,
+    overflow: Overflow,
 }
 
 impl Text {
@@ -40,6 +43,9 @@ impl Text {
             height: Length::Shrink,
             horizontal_alignment: HorizontalAlignment::Left,
             vertical_alignment: VerticalAlignment::Top,
+            line_height: LineHeight::default(),
+            wrapping: Wrapping::default(),
+            overflow: Overflow::default(),
         }
     }
 
@@ -64,8 +70,8 @@ impl Text {
     ///
     /// [`Text`]: struct.Text.html
     /// [`Font`]: ../../struct.Font.html
-    pub fn font(mut self, font: Font) -> Self {
-        self.font = font;
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = font.into();
         self
     }
 
@@ -105,6 +111,35 @@ impl Text {
         self.vertical_alignment = alignment;
         self
     }
+
+    /// Sets the [`LineHeight`] of the [`Text`].
+    ///
+    /// A plain number sets a line height relative to the size of the text.
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`LineHeight`]: enum.LineHeight.html
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the [`Wrapping`] strategy of the [`Text`].
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`Wrapping`]: enum.Wrapping.html
+    pub fn wrapping(mut self, wrapping: Wrapping) -> Self {
+        self.wrapping = wrapping;
+        self
+    }
+
+    /// Sets the [`Overflow`] strategy of the [`Text`].
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`Overflow`]: enum.Overflow.html
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
 }
 
 impl<'a, Message> Widget<Message> for Text {
@@ -131,18 +166,35 @@ impl<'a, Message> Widget<Message> for Text {
             HorizontalAlignment::Right => "right",
         };
 
+        let line_height = match self.line_height {
+            LineHeight::Natural => String::from("normal"),
+            LineHeight::Relative(factor) => format!("{}", factor),
+            LineHeight::Absolute(height) => format!("{}px", height),
+        };
+
+        let wrapping = match self.wrapping {
+            Wrapping::Word => "white-space: normal",
+            Wrapping::Glyph => "white-space: normal; word-break: break-all",
+            Wrapping::None => "white-space: nowrap",
+        };
+
+        let text_overflow = match self.overflow {
+            Overflow::Clip => "clip",
+            Overflow::Ellipsis => "ellipsis",
+        };
+
         let style = bumpalo::format!(
             in bump,
-            "width: {}; height: {}; font-size: {}px; color: {}; text-align: {}; font-family: {}",
+            "width: {}; height: {}; font-size: {}px; color: {}; text-align: {}; {}; line-height: {}; {}; overflow: hidden; text-overflow: {}",
             width,
             height,
             self.size.unwrap_or(20),
             color,
             text_align,
-            match self.font {
-                Font::Default => "inherit",
-                Font::External { name, .. } => name,
-            }
+            css::font(self.font.clone()),
+            line_height,
+            wrapping,
+            text_overflow
         );
 
         // TODO: Complete styling
@@ -158,7 +158,7 @@ where
         let on_submit = self.on_submit.clone();
         let input_event_bus = bus.clone();
         let submit_event_bus = bus.clone();
-        let style = self.style_sheet.active();
+        let style = self.style_sheet.active(style_sheet.theme());
 
         input(bump)
             .attr(
@@ -169,15 +169,16 @@ where
                 "style",
                 bumpalo::format!(
                     in bump,
-                    "width: {}; max-width: {}; font-size: {}px; background: {}; border-width: {}px; border-color: {}; border-radius: {}px; color: {}",
+                    "width: {}; max-width: {}; font-size: {}px; background: {}; border-width: {}; border-color: {}; border-radius: {}; box-shadow: {}; color: {}",
                     css::length(self.width),
                     css::max_length(self.max_width),
                     self.size.unwrap_or(20),
                     css::background(style.background),
-                    style.border_width,
+                    css::border_width(style.border_width),
                     css::color(style.border_color),
-                    style.border_radius,
-                    css::color(self.style_sheet.value_color())
+                    css::border_radius(style.border_radius),
+                    css::shadow(style.shadow),
+                    css::color(self.style_sheet.value_color(style_sheet.theme()))
                 )
                 .into_bump_str(),
             )
@@ -5,9 +5,14 @@ use crate::{Settings, Transformation};
 use iced_graphics::backend;
 use iced_graphics::font;
 use iced_graphics::layer::Layer;
-use iced_graphics::{Primitive, Viewport};
+use iced_graphics::text::Cluster;
+use iced_graphics::{Primitive, Span, Viewport};
 use iced_native::mouse;
-use iced_native::{Font, HorizontalAlignment, Size, VerticalAlignment};
+use iced_native::{
+    Font, HorizontalAlignment, LineHeight, Point, Rectangle, Size,
+    VerticalAlignment, Wrapping,
+};
+use std::borrow::Cow;
 
 #[cfg(any(feature = "image", feature = "svg"))]
 use crate::image;
@@ -158,10 +163,10 @@ impl Backend {
             }
         }
 
-        if !layer.text.is_empty() {
+        if !layer.text.is_empty() || !layer.rich_text.is_empty() {
             for text in layer.text.iter() {
                 // Target physical coordinates directly to avoid blurry text
-                let text = wgpu_glyph::Section {
+                let section = wgpu_glyph::Section {
                     // TODO: We `round` here to avoid rerasterizing text when
                     // its position changes slightly. This can make text feel a
                     // bit "jumpy". We may be able to do better once we improve
@@ -189,39 +194,66 @@ impl Backend {
                             x: text.size * scale_factor,
                             y: text.size * scale_factor,
                         },
-                        font_id: self.text_pipeline.find_font(text.font),
+                        font_id: self.text_pipeline.find_font(&text.font),
                         extra: wgpu_glyph::Extra {
                             color: text.color,
                             z: 0.0,
                         },
                     }],
-                    layout: wgpu_glyph::Layout::default()
-                        .h_align(match text.horizontal_alignment {
-                            HorizontalAlignment::Left => {
-                                wgpu_glyph::HorizontalAlign::Left
-                            }
-                            HorizontalAlignment::Center => {
-                                wgpu_glyph::HorizontalAlign::Center
-                            }
-                            HorizontalAlignment::Right => {
-                                wgpu_glyph::HorizontalAlign::Right
-                            }
+                    ..Default::default()
+                };
+
+                let paragraph = text::Paragraph {
+                    wrapping: text.wrapping,
+                    overflow: text.overflow,
+                    line_height: text
+                        .line_height
+                        .to_absolute(text.size)
+                        .map(|line_height| line_height * scale_factor),
+                    ..text::Paragraph::new(
+                        text.horizontal_alignment,
+                        text.vertical_alignment,
+                    )
+                };
+
+                self.text_pipeline.queue(section, &paragraph);
+            }
+
+            for text in layer.rich_text.iter() {
+                let section = wgpu_glyph::Section {
+                    screen_position: (
+                        (text.bounds.x * scale_factor).round(),
+                        (text.bounds.y * scale_factor).round(),
+                    ),
+                    bounds: (
+                        (text.bounds.width * scale_factor).ceil(),
+                        (text.bounds.height * scale_factor).ceil(),
+                    ),
+                    text: text
+                        .spans
+                        .iter()
+                        .map(|span| wgpu_glyph::Text {
+                            text: &span.content,
+                            scale: wgpu_glyph::ab_glyph::PxScale {
+                                x: span.size * scale_factor,
+                                y: span.size * scale_factor,
+                            },
+                            font_id: self.text_pipeline.find_font(&span.font),
+                            extra: wgpu_glyph::Extra {
+                                color: span.color.into_linear(),
+                                z: 0.0,
+                            },
                         })
-                        .v_align(match text.vertical_alignment {
-                            VerticalAlignment::Top => {
-                                wgpu_glyph::VerticalAlign::Top
-                            }
-                            VerticalAlignment::Center => {
-                                wgpu_glyph::VerticalAlign::Center
-                            }
-                            VerticalAlignment::Bottom => {
-                                wgpu_glyph::VerticalAlign::Bottom
-                            }
-                        }),
+                        .collect(),
                     ..Default::default()
                 };
 
-                self.text_pipeline.queue(text);
+                let paragraph = text::Paragraph::new(
+                    text.horizontal_alignment,
+                    text.vertical_alignment,
+                );
+
+                self.text_pipeline.queue(section, &paragraph);
             }
 
             self.text_pipeline.draw_queued(
@@ -255,9 +287,50 @@ impl backend::Text for Backend {
         contents: &str,
         size: f32,
         font: Font,
+        line_height: LineHeight,
+        wrapping: Wrapping,
         bounds: Size,
     ) -> (f32, f32) {
-        self.text_pipeline.measure(contents, size, font, bounds)
+        self.text_pipeline.measure(
+            contents,
+            size,
+            font,
+            line_height,
+            wrapping,
+            bounds,
+        )
+    }
+
+    fn measure_spans(&self, spans: &[Span], bounds: Size) -> (f32, f32) {
+        self.text_pipeline.measure_spans(spans, bounds)
+    }
+
+    fn hit_test(
+        &self,
+        spans: &[Span],
+        bounds: Rectangle,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+        point: Point,
+    ) -> Option<usize> {
+        self.text_pipeline.hit_test(
+            spans,
+            bounds,
+            horizontal_alignment,
+            vertical_alignment,
+            point,
+        )
+    }
+
+    fn clusters(&self, contents: &str, size: f32, font: Font) -> Vec<Cluster> {
+        self.text_pipeline.clusters(contents, size, font)
+    }
+
+    fn load_font(
+        &mut self,
+        bytes: Cow<'static, [u8]>,
+    ) -> Result<iced_native::font::Descriptor, font::Error> {
+        self.text_pipeline.load_font(bytes)
     }
 }
 
@@ -266,6 +339,13 @@ impl backend::Image for Backend {
     fn dimensions(&self, handle: &iced_native::image::Handle) -> (u32, u32) {
         self.image_pipeline.dimensions(handle)
     }
+
+    fn frame_delays(
+        &self,
+        handle: &iced_native::image::Handle,
+    ) -> Vec<std::time::Duration> {
+        self.image_pipeline.frame_delays(handle)
+    }
 }
 
 #[cfg(feature = "svg")]
@@ -1,23 +1,27 @@
 mod atlas;
 
-#[cfg(feature = "image")]
-mod raster;
-
-#[cfg(feature = "svg")]
-mod vector;
-
 use crate::Transformation;
 use atlas::Atlas;
 
 use iced_graphics::layer;
+use iced_native::image::FilterMethod;
 use iced_native::Rectangle;
 use std::cell::RefCell;
 use std::mem;
 use zerocopy::AsBytes;
 
+#[cfg(feature = "image")]
+use iced_graphics::image::raster;
+
 #[cfg(feature = "image")]
 use iced_native::image;
 
+#[cfg(feature = "image")]
+use std::time::Duration;
+
+#[cfg(feature = "svg")]
+use iced_graphics::image::vector;
+
 #[cfg(feature = "svg")]
 use iced_native::svg;
 
@@ -33,26 +37,33 @@ pub struct Pipeline {
     indices: wgpu::Buffer,
     instances: wgpu::Buffer,
     constants: wgpu::BindGroup,
+    nearest_constants: wgpu::BindGroup,
     texture: wgpu::BindGroup,
     texture_version: usize,
     texture_layout: wgpu::BindGroupLayout,
     texture_atlas: Atlas,
+    atlas_texture: atlas::Texture,
 }
 
 impl Pipeline {
     pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
-        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
-            address_mode_u: wgpu::AddressMode::ClampToEdge,
-            address_mode_v: wgpu::AddressMode::ClampToEdge,
-            address_mode_w: wgpu::AddressMode::ClampToEdge,
-            mag_filter: wgpu::FilterMode::Linear,
-            min_filter: wgpu::FilterMode::Linear,
-            mipmap_filter: wgpu::FilterMode::Linear,
-            lod_min_clamp: -100.0,
-            lod_max_clamp: 100.0,
-            compare: Some(wgpu::CompareFunction::Always),
-            ..Default::default()
-        });
+        let create_sampler = |filter| {
+            device.create_sampler(&wgpu::SamplerDescriptor {
+                address_mode_u: wgpu::AddressMode::ClampToEdge,
+                address_mode_v: wgpu::AddressMode::ClampToEdge,
+                address_mode_w: wgpu::AddressMode::ClampToEdge,
+                mag_filter: filter,
+                min_filter: filter,
+                mipmap_filter: filter,
+                lod_min_clamp: -100.0,
+                lod_max_clamp: 100.0,
+                compare: Some(wgpu::CompareFunction::Always),
+                ..Default::default()
+            })
+        };
+
+        let sampler = create_sampler(wgpu::FilterMode::Linear);
+        let nearest_sampler = create_sampler(wgpu::FilterMode::Nearest);
 
         let constant_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
@@ -82,7 +93,7 @@ impl Pipeline {
             wgpu::BufferUsage::UNIFORM | wgpu::BufferUsage::COPY_DST,
         );
 
-        let constant_bind_group =
+        let create_constant_bind_group = |sampler| {
             device.create_bind_group(&wgpu::BindGroupDescriptor {
                 label: None,
                 layout: &constant_layout,
@@ -97,10 +108,15 @@ impl Pipeline {
                     },
                     wgpu::Binding {
                         binding: 1,
-                        resource: wgpu::BindingResource::Sampler(&sampler),
+                        resource: wgpu::BindingResource::Sampler(sampler),
                     },
                 ],
-            });
+            })
+        };
+
+        let constant_bind_group = create_constant_bind_group(&sampler);
+        let nearest_constant_bind_group =
+            create_constant_bind_group(&nearest_sampler);
 
         let texture_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
@@ -235,7 +251,7 @@ impl Pipeline {
             mapped_at_creation: false,
         });
 
-        let texture_atlas = Atlas::new(device);
+        let atlas_texture = atlas::Texture::new(device);
 
         let texture = device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: None,
@@ -243,7 +259,7 @@ impl Pipeline {
             bindings: &[wgpu::Binding {
                 binding: 0,
                 resource: wgpu::BindingResource::TextureView(
-                    &texture_atlas.view(),
+                    &atlas_texture.view(),
                 ),
             }],
         });
@@ -261,10 +277,12 @@ impl Pipeline {
             indices,
             instances,
             constants: constant_bind_group,
+            nearest_constants: nearest_constant_bind_group,
             texture,
-            texture_version: texture_atlas.layer_count(),
+            texture_version: atlas_texture.layer_count(),
             texture_layout,
-            texture_atlas,
+            texture_atlas: Atlas::new(),
+            atlas_texture,
         }
     }
 
@@ -276,6 +294,14 @@ impl Pipeline {
         memory.dimensions()
     }
 
+    #[cfg(feature = "image")]
+    pub fn frame_delays(&self, handle: &image::Handle) -> Vec<Duration> {
+        let mut cache = self.raster_cache.borrow_mut();
+        let memory = cache.load(&handle);
+
+        memory.frame_delays()
+    }
+
     #[cfg(feature = "svg")]
     pub fn viewport_dimensions(&self, handle: &svg::Handle) -> (u32, u32) {
         let mut cache = self.vector_cache.borrow_mut();
@@ -294,7 +320,8 @@ impl Pipeline {
         target: &wgpu::TextureView,
         _scale: f32,
     ) {
-        let instances: &mut Vec<Instance> = &mut Vec::new();
+        let mut instances: Vec<Instance> = Vec::new();
+        let mut batches: Vec<Batch> = Vec::new();
 
         #[cfg(feature = "image")]
         let mut raster_cache = self.raster_cache.borrow_mut();
@@ -302,21 +329,34 @@ impl Pipeline {
         #[cfg(feature = "svg")]
         let mut vector_cache = self.vector_cache.borrow_mut();
 
+        let mut uploader = self.atlas_texture.uploader(device, encoder);
+
         for image in images {
             match &image {
                 #[cfg(feature = "image")]
-                layer::Image::Raster { handle, bounds } => {
+                layer::Image::Raster {
+                    handle,
+                    frame,
+                    filter_method,
+                    bounds,
+                } => {
                     if let Some(atlas_entry) = raster_cache.upload(
                         handle,
-                        device,
-                        encoder,
+                        *frame,
                         &mut self.texture_atlas,
+                        &mut uploader,
                     ) {
                         add_instances(
                             [bounds.x, bounds.y],
                             [bounds.width, bounds.height],
                             atlas_entry,
-                            instances,
+                            &mut instances,
+                        );
+
+                        add_batch(
+                            &mut batches,
+                            *filter_method,
+                            instances.len(),
                         );
                     }
                 }
@@ -324,22 +364,32 @@ impl Pipeline {
                 layer::Image::Raster { .. } => {}
 
                 #[cfg(feature = "svg")]
-                layer::Image::Vector { handle, bounds } => {
+                layer::Image::Vector {
+                    handle,
+                    color,
+                    bounds,
+                } => {
                     let size = [bounds.width, bounds.height];
 
                     if let Some(atlas_entry) = vector_cache.upload(
                         handle,
+                        *color,
                         size,
                         _scale,
-                        device,
-                        encoder,
                         &mut self.texture_atlas,
+                        &mut uploader,
                     ) {
                         add_instances(
                             [bounds.x, bounds.y],
                             size,
                             atlas_entry,
-                            instances,
+                            &mut instances,
+                        );
+
+                        add_batch(
+                            &mut batches,
+                            FilterMethod::Linear,
+                            instances.len(),
                         );
                     }
                 }
@@ -352,7 +402,7 @@ impl Pipeline {
             return;
         }
 
-        let texture_version = self.texture_atlas.layer_count();
+        let texture_version = self.atlas_texture.layer_count();
 
         if self.texture_version != texture_version {
             log::info!("Atlas has grown. Recreating bind group...");
@@ -364,7 +414,7 @@ impl Pipeline {
                     bindings: &[wgpu::Binding {
                         binding: 0,
                         resource: wgpu::BindingResource::TextureView(
-                            &self.texture_atlas.view(),
+                            &self.atlas_texture.view(),
                         ),
                     }],
                 });
@@ -388,6 +438,38 @@ impl Pipeline {
             std::mem::size_of::<Uniforms>() as u64,
         );
 
+        // Batches are drawn in order, so overlapping images keep the order
+        // of their primitives
+        let mut start = 0;
+
+        for batch in &batches {
+            let constants = match batch.filter_method {
+                FilterMethod::Linear => &self.constants,
+                FilterMethod::Nearest => &self.nearest_constants,
+            };
+
+            self.render(
+                device,
+                encoder,
+                &instances[start..batch.end],
+                constants,
+                bounds,
+                target,
+            );
+
+            start = batch.end;
+        }
+    }
+
+    fn render(
+        &self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        instances: &[Instance],
+        constants: &wgpu::BindGroup,
+        bounds: Rectangle<u32>,
+        target: &wgpu::TextureView,
+    ) {
         let instances_buffer = device.create_buffer_with_data(
             instances.as_bytes(),
             wgpu::BufferUsage::COPY_SRC,
@@ -428,7 +510,7 @@ impl Pipeline {
                 });
 
             render_pass.set_pipeline(&self.pipeline);
-            render_pass.set_bind_group(0, &self.constants, &[]);
+            render_pass.set_bind_group(0, constants, &[]);
             render_pass.set_bind_group(1, &self.texture, &[]);
             render_pass.set_index_buffer(self.indices.slice(..));
             render_pass.set_vertex_buffer(0, self.vertices.slice(..));
@@ -497,6 +579,14 @@ impl Instance {
     pub const MAX: usize = 1_000;
 }
 
+/// A range of consecutive instances sampled with the same filter, ending at
+/// `end`.
+#[derive(Debug, Clone, Copy)]
+struct Batch {
+    filter_method: FilterMethod,
+    end: usize,
+}
+
 #[repr(C)]
 #[derive(Debug, Clone, Copy, AsBytes)]
 struct Uniforms {
@@ -540,6 +630,19 @@ fn add_instances(
     }
 }
 
+fn add_batch(
+    batches: &mut Vec<Batch>,
+    filter_method: FilterMethod,
+    end: usize,
+) {
+    match batches.last_mut() {
+        Some(batch) if batch.filter_method == filter_method => {
+            batch.end = end;
+        }
+        _ => batches.push(Batch { filter_method, end }),
+    }
+}
+
 #[inline]
 fn add_instance(
     position: [f32; 2],
@@ -124,22 +124,62 @@ impl Pipeline {
                                 wgpu::VertexAttributeDescriptor {
                                     shader_location: 3,
                                     format: wgpu::VertexFormat::Float4,
-                                    offset: 4 * (2 + 2),
+                                    offset: 4 * 4,
                                 },
                                 wgpu::VertexAttributeDescriptor {
                                     shader_location: 4,
                                     format: wgpu::VertexFormat::Float4,
-                                    offset: 4 * (2 + 2 + 4),
+                                    offset: 4 * 8,
                                 },
                                 wgpu::VertexAttributeDescriptor {
                                     shader_location: 5,
-                                    format: wgpu::VertexFormat::Float,
-                                    offset: 4 * (2 + 2 + 4 + 4),
+                                    format: wgpu::VertexFormat::Float4,
+                                    offset: 4 * 12,
                                 },
                                 wgpu::VertexAttributeDescriptor {
                                     shader_location: 6,
+                                    format: wgpu::VertexFormat::Float4,
+                                    offset: 4 * 16,
+                                },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 7,
+                                    format: wgpu::VertexFormat::Float4,
+                                    offset: 4 * 20,
+                                },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 8,
+                                    format: wgpu::VertexFormat::Float4,
+                                    offset: 4 * 24,
+                                },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 9,
+                                    format: wgpu::VertexFormat::Float4,
+                                    offset: 4 * 28,
+                                },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 10,
+                                    format: wgpu::VertexFormat::Float4,
+                                    offset: 4 * 32,
+                                },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 11,
+                                    format: wgpu::VertexFormat::Float,
+                                    offset: 4 * 36,
+                                },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 12,
+                                    format: wgpu::VertexFormat::Float4,
+                                    offset: 4 * 37,
+                                },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 13,
+                                    format: wgpu::VertexFormat::Float2,
+                                    offset: 4 * 41,
+                                },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 14,
                                     format: wgpu::VertexFormat::Float,
-                                    offset: 4 * (2 + 2 + 4 + 4 + 1),
+                                    offset: 4 * 43,
                                 },
                             ],
                         },
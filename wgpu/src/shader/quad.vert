@@ -3,40 +3,79 @@
 layout(location = 0) in vec2 v_Pos;
 layout(location = 1) in vec2 i_Pos;
 layout(location = 2) in vec2 i_Scale;
-layout(location = 3) in vec4 i_Color;
-layout(location = 4) in vec4 i_BorderColor;
-layout(location = 5) in float i_BorderRadius;
-layout(location = 6) in float i_BorderWidth;
+layout(location = 3) in vec4 i_Color0;
+layout(location = 4) in vec4 i_Color1;
+layout(location = 5) in vec4 i_Color2;
+layout(location = 6) in vec4 i_Color3;
+layout(location = 7) in vec4 i_Offsets;
+layout(location = 8) in vec4 i_BorderColor;
+layout(location = 9) in vec4 i_BorderRadius;
+layout(location = 10) in vec4 i_BorderWidth;
+layout(location = 11) in float i_Angle;
+layout(location = 12) in vec4 i_ShadowColor;
+layout(location = 13) in vec2 i_ShadowOffset;
+layout(location = 14) in float i_ShadowBlurRadius;
 
 layout (set = 0, binding = 0) uniform Globals {
     mat4 u_Transform;
     float u_Scale;
 };
 
-layout(location = 0) out vec4 o_Color;
-layout(location = 1) out vec4 o_BorderColor;
-layout(location = 2) out vec2 o_Pos;
-layout(location = 3) out vec2 o_Scale;
-layout(location = 4) out float o_BorderRadius;
-layout(location = 5) out float o_BorderWidth;
+layout(location = 0) out vec4 o_Color0;
+layout(location = 1) out vec4 o_Color1;
+layout(location = 2) out vec4 o_Color2;
+layout(location = 3) out vec4 o_Color3;
+layout(location = 4) out vec4 o_Offsets;
+layout(location = 5) out vec4 o_BorderColor;
+layout(location = 6) out vec2 o_Pos;
+layout(location = 7) out vec2 o_Scale;
+layout(location = 8) out vec4 o_BorderRadius;
+layout(location = 9) out vec4 o_BorderWidth;
+layout(location = 10) out float o_Angle;
+layout(location = 11) out vec4 o_ShadowColor;
+layout(location = 12) out vec2 o_ShadowOffset;
+layout(location = 13) out float o_ShadowBlurRadius;
 
 void main() {
     vec2 p_Pos = i_Pos * u_Scale;
     vec2 p_Scale = i_Scale  * u_Scale;
+    vec2 p_ShadowOffset = i_ShadowOffset * u_Scale;
+    float p_ShadowBlurRadius = i_ShadowBlurRadius * u_Scale;
+
+    // Grow the drawn area to fit the shadow, if visible
+    vec2 p_Min = p_Pos;
+    vec2 p_Max = p_Pos + p_Scale;
+
+    if(i_ShadowColor.a > 0.0) {
+        vec2 shadow_spread = vec2(max(p_ShadowBlurRadius, 0.5));
+
+        p_Min = min(p_Min, p_Pos + p_ShadowOffset - shadow_spread);
+        p_Max = max(p_Max, p_Pos + p_Scale + p_ShadowOffset + shadow_spread);
+    }
+
+    vec2 p_Size = p_Max - p_Min;
 
     mat4 i_Transform = mat4(
-        vec4(p_Scale.x + 1.0, 0.0, 0.0, 0.0),
-        vec4(0.0, p_Scale.y + 1.0, 0.0, 0.0),
+        vec4(p_Size.x + 1.0, 0.0, 0.0, 0.0),
+        vec4(0.0, p_Size.y + 1.0, 0.0, 0.0),
         vec4(0.0, 0.0, 1.0, 0.0),
-        vec4(p_Pos - vec2(0.5, 0.5), 0.0, 1.0)
+        vec4(p_Min - vec2(0.5, 0.5), 0.0, 1.0)
     );
 
-    o_Color = i_Color;
+    o_Color0 = i_Color0;
+    o_Color1 = i_Color1;
+    o_Color2 = i_Color2;
+    o_Color3 = i_Color3;
+    o_Offsets = i_Offsets;
     o_BorderColor = i_BorderColor;
     o_Pos = p_Pos;
     o_Scale = p_Scale;
     o_BorderRadius = i_BorderRadius * u_Scale;
     o_BorderWidth = i_BorderWidth * u_Scale;
+    o_Angle = i_Angle;
+    o_ShadowColor = i_ShadowColor;
+    o_ShadowOffset = p_ShadowOffset;
+    o_ShadowBlurRadius = p_ShadowBlurRadius;
 
     gl_Position = u_Transform * i_Transform * vec4(v_Pos, 0.0, 1.0);
 }
@@ -12,6 +12,7 @@ use crate::Renderer;
 pub mod button;
 pub mod checkbox;
 pub mod container;
+pub mod list;
 pub mod pane_grid;
 pub mod progress_bar;
 pub mod radio;
@@ -26,6 +27,8 @@ pub use checkbox::Checkbox;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use list::List;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use progress_bar::ProgressBar;
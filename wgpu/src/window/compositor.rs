@@ -72,7 +72,7 @@ impl Compositor {
     /// [`Compositor`]: struct.Compositor.html
     /// [`Backend`]: struct.Backend.html
     pub fn create_backend(&self) -> Backend {
-        Backend::new(&self.device, self.settings)
+        Backend::new(&self.device, self.settings.clone())
     }
 }
 
@@ -133,6 +133,8 @@ impl iced_graphics::window::Compositor for Compositor {
             &wgpu::CommandEncoderDescriptor { label: None },
         );
 
+        let [r, g, b, a] = renderer.theme().palette.background.into_linear();
+
         let _ = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
             color_attachments: &[wgpu::RenderPassColorAttachmentDescriptor {
                 attachment: &frame.output.view,
@@ -140,10 +142,10 @@ impl iced_graphics::window::Compositor for Compositor {
                 load_op: wgpu::LoadOp::Clear,
                 store_op: wgpu::StoreOp::Store,
                 clear_color: wgpu::Color {
-                    r: 1.0,
-                    g: 1.0,
-                    b: 1.0,
-                    a: 1.0,
+                    r: f64::from(r),
+                    g: f64::from(g),
+                    b: f64::from(b),
+                    a: f64::from(a),
                 },
             }],
             depth_stencil_attachment: None,
@@ -3,10 +3,16 @@ use crate::{
     conversion, mouse, Clipboard, Command, Debug, Executor, Mode, Proxy,
     Runtime, Settings, Size, Subscription,
 };
-use iced_graphics::window;
+use iced_graphics::theme::{Theme, Themed};
+use iced_graphics::window::{self, Redraw};
 use iced_graphics::Viewport;
 use iced_native::program::{self, Program};
 
+use std::time::{Duration, Instant};
+
+/// The minimum time between the frames drawn while a widget is animating.
+pub const ANIMATION_FRAME: Duration = Duration::from_millis(16);
+
 /// An interactive, native cross-platform application.
 ///
 /// This trait is the main entrypoint of Iced. Once implemented, you can run
@@ -73,6 +79,19 @@ pub trait Application: Program {
     fn mode(&self) -> Mode {
         Mode::Windowed
     }
+
+    /// Returns the current [`Theme`] of the [`Application`].
+    ///
+    /// The runtime will automatically redraw your application if a new theme
+    /// is returned.
+    ///
+    /// By default, it returns the light theme.
+    ///
+    /// [`Theme`]: ../theme/struct.Theme.html
+    /// [`Application`]: trait.Application.html
+    fn theme(&self) -> Theme {
+        Theme::default()
+    }
 }
 
 /// Runs an [`Application`] with an executor, compositor, and the provided
@@ -86,6 +105,7 @@ pub fn run<A, E, C>(
     A: Application + 'static,
     E: Executor + 'static,
     C: window::Compositor<Renderer = A::Renderer> + 'static,
+    A::Renderer: Themed + Redraw,
 {
     use winit::{
         event,
@@ -105,13 +125,13 @@ pub fn run<A, E, C>(
 
     let flags = settings.flags;
     let (application, init_command) = runtime.enter(|| A::new(flags));
-    runtime.spawn(init_command);
 
     let subscription = application.subscription();
     runtime.track(subscription);
 
     let mut title = application.title();
     let mut mode = application.mode();
+    let mut theme = application.theme();
 
     let window = settings
         .window
@@ -131,6 +151,11 @@ pub fn run<A, E, C>(
     let mut resized = false;
 
     let (mut compositor, mut renderer) = C::new(compositor_settings);
+    renderer.set_theme(theme);
+
+    // The initial command is run once the renderer exists, so it can
+    // perform actions with it
+    runtime.spawn(init_command.perform_actions(&mut renderer));
 
     let surface = compositor.create_surface(&window);
 
@@ -148,55 +173,102 @@ pub fn run<A, E, C>(
     );
     debug.startup_finished();
 
+    let mut next_frame: Option<Instant> = None;
+
     event_loop.run(move |event, _, control_flow| match event {
         event::Event::MainEventsCleared => {
             if state.is_queue_empty() {
                 return;
             }
 
-            let command = runtime.enter(|| {
-                state.update(
-                    clipboard.as_ref().map(|c| c as _),
-                    viewport.logical_size(),
-                    &mut renderer,
-                    &mut debug,
-                )
-            });
+            // The user interface is updated again when the theme changes,
+            // so it is redrawn with the new theme
+            loop {
+                let mut is_theme_changed = false;
+
+                let (events, command) = runtime.enter(|| {
+                    state.update(
+                        clipboard.as_ref().map(|c| c as _),
+                        viewport.logical_size(),
+                        &mut renderer,
+                        &mut debug,
+                    )
+                });
+
+                for event in events {
+                    runtime.broadcast(event);
+                }
 
-            // If the application was updated
-            if let Some(command) = command {
-                runtime.spawn(command);
+                // If the application was updated
+                if let Some(command) = command {
+                    // Actions performed with the renderer, like loading a
+                    // font, may change the layout of the widgets
+                    if command.has_actions() {
+                        state.invalidate_layout();
+                    }
 
-                let program = state.program();
+                    runtime.spawn(command.perform_actions(&mut renderer));
 
-                // Update subscriptions
-                let subscription = program.subscription();
-                runtime.track(subscription);
+                    let program = state.program();
 
-                // Update window title
-                let new_title = program.title();
+                    // Update subscriptions
+                    let subscription = program.subscription();
+                    runtime.track(subscription);
 
-                if title != new_title {
-                    window.set_title(&new_title);
+                    // Update window title
+                    let new_title = program.title();
 
-                    title = new_title;
-                }
+                    if title != new_title {
+                        window.set_title(&new_title);
 
-                // Update window mode
-                let new_mode = program.mode();
+                        title = new_title;
+                    }
 
-                if mode != new_mode {
-                    window.set_fullscreen(conversion::fullscreen(
-                        window.current_monitor(),
-                        new_mode,
-                    ));
+                    // Update window mode
+                    let new_mode = program.mode();
 
-                    mode = new_mode;
+                    if mode != new_mode {
+                        window.set_fullscreen(conversion::fullscreen(
+                            window.current_monitor(),
+                            new_mode,
+                        ));
+
+                        mode = new_mode;
+                    }
+
+                    // Update theme
+                    let new_theme = program.theme();
+
+                    if theme != new_theme {
+                        renderer.set_theme(new_theme);
+
+                        theme = new_theme;
+                        is_theme_changed = true;
+                    }
+                }
+
+                if !is_theme_changed {
+                    break;
                 }
             }
 
+            // Keep drawing frames while a widget is animating, when it asks
+            // for them but no more often than every animation frame
+            next_frame = renderer.take_redraw_request().map(|requested| {
+                requested.max(Instant::now() + ANIMATION_FRAME)
+            });
+
             window.request_redraw();
         }
+        event::Event::NewEvents(event::StartCause::ResumeTimeReached {
+            ..
+        }) => {
+            if next_frame.take().is_some() {
+                state.queue_event(iced_native::Event::Window(
+                    iced_native::window::Event::RedrawRequested(Instant::now()),
+                ));
+            }
+        }
         event::Event::UserEvent(message) => {
             state.queue_message(message);
         }
@@ -232,9 +304,6 @@ pub fn run<A, E, C>(
 
                 mouse_interaction = new_mouse_interaction;
             }
-
-            // TODO: Handle animations!
-            // Maybe we can use `ControlFlow::WaitUntil` for this.
         }
         event::Event::WindowEvent {
             event: window_event,
@@ -255,12 +324,14 @@ pub fn run<A, E, C>(
                 viewport.scale_factor(),
                 modifiers,
             ) {
-                state.queue_event(event.clone());
-                runtime.broadcast(event);
+                state.queue_event(event);
             }
         }
         _ => {
-            *control_flow = ControlFlow::Wait;
+            *control_flow = match next_frame {
+                Some(next_frame) => ControlFlow::WaitUntil(next_frame),
+                None => ControlFlow::Wait,
+            };
         }
     })
 }
@@ -292,6 +363,9 @@ pub fn handle_window_event(
         WindowEvent::ModifiersChanged(new_modifiers) => {
             *modifiers = *new_modifiers;
         }
+        WindowEvent::Focused(false) => {
+            *modifiers = winit::event::ModifiersState::default();
+        }
         #[cfg(target_os = "macos")]
         WindowEvent::KeyboardInput {
             input:
@@ -39,4 +39,4 @@ pub use mode::Mode;
 pub use proxy::Proxy;
 pub use settings::Settings;
 
-pub use iced_graphics::Viewport;
+pub use iced_graphics::{theme, Theme, Viewport};